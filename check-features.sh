@@ -17,3 +17,42 @@ cargo check --lib --bins --tests --no-default-features --features binomial
 
 echo "***** --features wilcoxon"
 cargo check --lib --bins --tests --no-default-features --features wilcoxon
+
+echo "***** --features combine"
+cargo check --lib --bins --tests --no-default-features --features combine
+
+echo "***** --features contingency"
+cargo check --lib --bins --tests --no-default-features --features contingency
+
+echo "***** --features multinomial"
+cargo check --lib --bins --tests --no-default-features --features multinomial
+
+echo "***** --features poisson"
+cargo check --lib --bins --tests --no-default-features --features poisson
+
+echo "***** --features sequential"
+cargo check --lib --bins --tests --no-default-features --features sequential
+
+echo "***** --features bayes"
+cargo check --lib --bins --tests --no-default-features --features bayes
+
+echo "***** --features normality"
+cargo check --lib --bins --tests --no-default-features --features normality
+
+echo "***** --features ks"
+cargo check --lib --bins --tests --no-default-features --features ks
+
+echo "***** --features bootstrap"
+cargo check --lib --bins --tests --no-default-features --features bootstrap
+
+echo "***** --features permutation"
+cargo check --lib --bins --tests --no-default-features --features permutation
+
+echo "***** --features jackknife"
+cargo check --lib --bins --tests --no-default-features --features jackknife
+
+echo "***** --features robust"
+cargo check --lib --bins --tests --no-default-features --features robust
+
+echo "***** --features outliers"
+cargo check --lib --bins --tests --no-default-features --features outliers
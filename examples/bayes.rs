@@ -0,0 +1,52 @@
+use basic_stats::{
+    bayes::{BetaDist, JZS_R_MEDIUM, NormalInvGammaDist, jzs_2samp_bf},
+    core::SampleMoments,
+};
+
+const ALPHA: f64 = 0.05;
+
+fn main() {
+    // Conversions of variants A and B of a checkout page, with a uniform prior.
+    let (n_a, n_s_a) = (1000, 40);
+    let (n_b, n_s_b) = (1000, 55);
+
+    let post_a = BetaDist::UNIFORM.posterior(n_a, n_s_a).unwrap();
+    let post_b = BetaDist::UNIFORM.posterior(n_b, n_s_b).unwrap();
+
+    let mean_b = post_b.mean();
+    println!("posterior mean conversion rate of B: {mean_b}");
+    // posterior mean conversion rate of B: 0.05588822355289421
+
+    let ci = post_b.equal_tailed_ci(ALPHA).unwrap();
+    println!("equal-tailed credible interval for B: {ci:?}");
+    // equal-tailed credible interval for B: Ci(0.04253273815321929, 0.07092119713919665)
+
+    let hpd = post_b.hpd_ci(ALPHA).unwrap();
+    println!("HPD credible interval for B: {hpd:?}");
+    // HPD credible interval for B: Ci(0.041991868291883616, 0.07028565919471486)
+
+    let prob = post_b.prob_greater(&post_a);
+    assert!(prob > 0.9);
+    println!("probability that B converts better than A: {prob}");
+    // probability that B converts better than A: 0.941887344180577
+
+    // Benchmark timings (ms) of the old and new builds, summarized as sample moments.
+    let moments_old = SampleMoments::from_slice(&[10.2, 9.8, 10.5, 10.1, 9.9, 10.4, 10.0, 10.3]);
+    let moments_new = SampleMoments::from_slice(&[9.6, 9.9, 9.5, 9.8, 9.7, 9.4, 9.9, 9.6]);
+
+    let post_old = NormalInvGammaDist::reference_posterior(&moments_old).unwrap();
+    let post_new = NormalInvGammaDist::reference_posterior(&moments_new).unwrap();
+
+    let mean_ci = post_new.mean_ci(ALPHA).unwrap();
+    println!("credible interval for mean timing of new build: {mean_ci:?}");
+    // credible interval for mean timing of new build: Ci(9.521820002877204, 9.828179997122794)
+
+    let prob_faster = post_old.prob_mean_diff_greater(&post_new, 0.25);
+    println!("probability that new build is faster by more than 0.25 ms: {prob_faster}");
+    // probability that new build is faster by more than 0.25 ms: 0.9613957596092217
+
+    let bf = jzs_2samp_bf(&moments_old, &moments_new, JZS_R_MEDIUM).unwrap();
+    assert!(bf > 10.);
+    println!("Bayes factor in favor of a difference: {bf}");
+    // Bayes factor in favor of a difference: 43.07964818990194
+}
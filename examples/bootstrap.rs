@@ -0,0 +1,85 @@
+use basic_stats::bootstrap::{BootstrapCiMethod, bootstrap_2samp_ci, bootstrap_ci};
+
+const ALPHA: f64 = 0.05;
+const N_RESAMPLES: usize = 9999;
+const SEED: u64 = 42;
+
+fn median(dataset: &[f64]) -> f64 {
+    let mut sorted = dataset.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let n = sorted.len();
+    if n % 2 == 1 {
+        sorted[n / 2]
+    } else {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2.
+    }
+}
+
+fn main() {
+    // Request latencies (ms) of the previous and current releases.
+    let latencies_prev = [
+        12.1, 11.8, 13.5, 12.9, 14.2, 11.2, 12.4, 15.9, 13.3, 12.7, 11.9, 13.8, 12.2, 14.6, 12.0,
+        13.1, 17.2, 12.5, 13.0, 11.6,
+    ];
+    let latencies_curr = [
+        11.5, 11.1, 12.6, 12.0, 13.4, 10.9, 11.8, 14.8, 12.4, 11.7, 11.3, 12.9, 11.6, 13.9, 11.4,
+        12.2, 16.5, 11.9, 12.1, 10.8,
+    ];
+
+    // 90th percentile latency, by the nearest-rank method.
+    let p90 = |dataset: &[f64]| {
+        let mut sorted = dataset.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        sorted[(0.9 * sorted.len() as f64).ceil() as usize - 1]
+    };
+    println!(
+        "p90 latency of the current release: {}",
+        p90(&latencies_curr)
+    );
+    // p90 latency of the current release: 13.9
+
+    let ci = bootstrap_ci(
+        &latencies_curr,
+        p90,
+        BootstrapCiMethod::Percentile,
+        N_RESAMPLES,
+        SEED,
+        ALPHA,
+    )
+    .unwrap();
+    println!("percentile confidence interval for the p90 latency: {ci:?}");
+    // percentile confidence interval for the p90 latency: Ci(12.4, 16.5)
+
+    let ratio = |x: &[f64], y: &[f64]| median(x) / median(y);
+    println!(
+        "ratio of median latencies: {}",
+        ratio(&latencies_curr, &latencies_prev)
+    );
+    // ratio of median latencies: 0.9335937499999999
+
+    let ci = bootstrap_2samp_ci(
+        &latencies_curr,
+        &latencies_prev,
+        ratio,
+        BootstrapCiMethod::Bca,
+        N_RESAMPLES,
+        SEED,
+        ALPHA,
+    )
+    .unwrap();
+    println!("BCa confidence interval for the ratio of medians: {ci:?}");
+    // BCa confidence interval for the ratio of medians: Ci(0.876865671641791, 0.9959016393442622)
+
+    // The same seed reproduces the same interval.
+    let ci_again = bootstrap_2samp_ci(
+        &latencies_curr,
+        &latencies_prev,
+        ratio,
+        BootstrapCiMethod::Bca,
+        N_RESAMPLES,
+        SEED,
+        ALPHA,
+    )
+    .unwrap();
+    assert_eq!(ci, ci_again);
+}
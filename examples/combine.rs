@@ -0,0 +1,31 @@
+use basic_stats::{
+    combine::{fisher_combined_test, hmp_combined_p, stouffer_combined_test},
+    core::{AltHyp, Hyp},
+};
+
+const ALPHA: f64 = 0.05;
+
+fn main() {
+    // One-sided p-values from the same benchmark repeated on five machines.
+    let p_values = [0.01, 0.2, 0.3, 0.04, 0.5];
+
+    {
+        let test_res = fisher_combined_test(&p_values, ALPHA).unwrap();
+        assert_eq!(Hyp::Alt(AltHyp::Ne), test_res.accepted());
+        println!("Fisher test result: {test_res:?}");
+        // Fisher test result: HypTestResult { p: 0.012068611249286166, alpha: 0.05, alt_hyp: Ne, accepted: Alt(Ne) }
+    }
+
+    {
+        let test_res = stouffer_combined_test(&p_values, ALPHA).unwrap();
+        assert_eq!(Hyp::Alt(AltHyp::Gt), test_res.accepted());
+        println!("Stouffer test result: {test_res:?}");
+        // Stouffer test result: HypTestResult { p: 0.0074621976121828654, alpha: 0.05, alt_hyp: Gt, accepted: Alt(Gt) }
+    }
+
+    {
+        let p = hmp_combined_p(&p_values).unwrap();
+        println!("harmonic mean p-value combined p: {p}");
+        // harmonic mean p-value combined p: 0.044531997782340405
+    }
+}
@@ -0,0 +1,36 @@
+use basic_stats::{
+    contingency::{ContingencyTable, fisher_exact_ci, fisher_exact_odds_ratio, fisher_exact_test},
+    core::{AltHyp, Hyp},
+};
+
+const ALPHA: f64 = 0.05;
+
+fn main() {
+    // Rows: old build, new build. Columns: failed runs, passed runs.
+    let table = [[2, 15], [10, 3]];
+
+    let test_res = fisher_exact_test(table, AltHyp::Ne, ALPHA).unwrap();
+    assert_eq!(Hyp::Alt(AltHyp::Ne), test_res.accepted());
+    println!("test result: {test_res:?}");
+    // test result: HypTestResult { p: 0.0005367241191434372, alpha: 0.05, alt_hyp: Ne, accepted: Alt(Ne) }
+
+    let odds_ratio = fisher_exact_odds_ratio(table).unwrap();
+    println!("conditional MLE of odds ratio: {odds_ratio}");
+    // conditional MLE of odds ratio: 0.046936639049679964
+
+    let ci = fisher_exact_ci(table, ALPHA).unwrap();
+    println!("confidence interval for odds ratio: {ci:?}");
+    // confidence interval for odds ratio: Ci(0.0033171639506573564, 0.3631896023566808)
+
+    // Rows: linux, mac, windows. Columns: crash, timeout, assertion failure.
+    let failures = ContingencyTable::from_counts(&[[12, 30, 18], [9, 14, 7], [20, 11, 9]]).unwrap();
+
+    let chi2_res = failures.chi2_test(false, ALPHA).unwrap();
+    assert_eq!(Hyp::Alt(AltHyp::Ne), chi2_res.accepted());
+    println!("chi-square test result: {chi2_res:?}");
+    // chi-square test result: HypTestResult { p: 0.03198285313465168, alpha: 0.05, alt_hyp: Ne, accepted: Alt(Ne) }
+
+    let cramers_v = failures.cramers_v();
+    println!("Cramér's V: {cramers_v}");
+    // Cramér's V: 0.2015308947400271
+}
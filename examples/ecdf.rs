@@ -0,0 +1,25 @@
+use basic_stats::core::{Ci, Ecdf};
+
+fn main() {
+    // Response times (ms) of a service.
+    let dat = [
+        112., 98., 105., 131., 98., 120., 101., 99., 250., 108., 115., 103.,
+    ];
+    let ecdf = Ecdf::from_slice(&dat).unwrap();
+
+    let f = ecdf.eval(110.);
+    println!("fraction of responses within 110 ms: {f}");
+    // fraction of responses within 110 ms: 0.5833333333333334
+
+    let median = ecdf.quantile(0.5).unwrap();
+    println!("median: {median}");
+    // median: 105
+
+    let p90 = ecdf.quantile(0.9).unwrap();
+    println!("90th percentile: {p90}");
+    // 90th percentile: 131
+
+    let Ci(lo, hi) = ecdf.dkw_band(110., 0.05).unwrap();
+    println!("95% confidence band for the CDF at 110 ms: [{lo}, {hi}]");
+    // 95% confidence band for the CDF at 110 ms: [0.19128319548349065, 0.9753834711831761]
+}
@@ -0,0 +1,42 @@
+use basic_stats::{
+    core::SampleMoments,
+    jackknife::{jackknife, jackknife_delete_d, jackknife_moments},
+};
+
+fn main() {
+    // Request latencies (ms).
+    let latencies = [
+        12.1, 11.8, 13.5, 12.9, 14.2, 11.2, 12.4, 15.9, 13.3, 12.7, 11.9, 13.8,
+    ];
+
+    // Coefficient of variation, computed from the sample moments.
+    let cv = |m: &SampleMoments| m.stdev().unwrap_or(f64::NAN) / m.mean().unwrap_or(f64::NAN);
+    let est = jackknife_moments(&latencies, cv).unwrap();
+    println!("coefficient of variation: {}", est.estimate());
+    // coefficient of variation: 0.09854198932538537
+    println!("jackknife standard error: {}", est.se());
+    // jackknife standard error: 0.026999208691674288
+    println!("bias-corrected estimate: {}", est.bias_corrected());
+    // bias-corrected estimate: 0.10292887370983424
+
+    // The same estimates, recomputing the statistic from each leave-one-out subsample.
+    let est_slice = jackknife(&latencies, |s| cv(&SampleMoments::from_slice(s))).unwrap();
+    assert!((est.se() - est_slice.se()).abs() < 1e-12);
+
+    // The median is not smooth, so use the delete-d jackknife with d close to sqrt(n).
+    let median = |s: &[f64]| {
+        let mut sorted = s.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let n = sorted.len();
+        if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2.
+        }
+    };
+    let est = jackknife_delete_d(&latencies, median, 4, 1000, 42).unwrap();
+    println!("median: {}", est.estimate());
+    // median: 12.8
+    println!("delete-4 jackknife standard error: {}", est.se());
+    // delete-4 jackknife standard error: 0.4315947794725893
+}
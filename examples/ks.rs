@@ -0,0 +1,34 @@
+use basic_stats::{
+    core::{AltHyp, Hyp},
+    ks::{KsTwoSample, ks_1samp_test},
+};
+
+const ALPHA: f64 = 0.05;
+
+fn main() {
+    // Sorted request latencies (ms) of the previous and current releases.
+    let latencies_prev = [
+        11.2, 11.8, 12.1, 12.4, 12.9, 13.3, 13.8, 14.0, 14.6, 15.1, 15.9, 17.2,
+    ];
+    let latencies_curr = [
+        11.5, 12.0, 12.6, 13.5, 14.4, 15.6, 17.8, 19.4, 21.7, 24.3, 28.9, 35.2,
+    ];
+
+    let ks = KsTwoSample::from_slices(&latencies_curr, &latencies_prev).unwrap();
+    let d = ks.d(AltHyp::Gt).unwrap();
+    println!("D- statistic: {d}");
+    // D- statistic: 0.5
+
+    let test_res = ks.test(AltHyp::Gt, ALPHA).unwrap();
+    assert_eq!(Hyp::Alt(AltHyp::Gt), test_res.accepted());
+    println!("two-sample test result: {test_res:?}");
+    // two-sample test result: HypTestResult { p: 0.04977375565610861, alpha: 0.05, alt_hyp: Gt, accepted: Alt(Gt) }
+
+    // Are inter-arrival times (s) exponential with mean 2 s?
+    let inter_arrivals = [0.4, 3.1, 1.2, 0.9, 5.6, 2.2, 0.3, 1.8, 4.0, 0.7, 2.6, 1.1];
+    let exp_cdf = |t: f64| 1. - (-t / 2.).exp();
+    let test_res = ks_1samp_test(&inter_arrivals, exp_cdf, AltHyp::Ne, ALPHA).unwrap();
+    assert_eq!(Hyp::Null, test_res.accepted());
+    println!("one-sample test result: {test_res:?}");
+    // one-sample test result: HypTestResult { p: 0.9492312335944223, alpha: 0.05, alt_hyp: Ne, accepted: Null }
+}
@@ -0,0 +1,28 @@
+use basic_stats::{
+    core::{AltHyp, Hyp},
+    multinomial::{exact_multinomial_test, multinomial_chi2_test},
+};
+
+const ALPHA: f64 = 0.05;
+
+fn main() {
+    // Requests routed to four shards by a load balancer with configured weights 40%, 30%, 20%, 10%.
+    let probs = [0.4, 0.3, 0.2, 0.1];
+
+    {
+        let counts = [412, 291, 206, 91];
+        let test_res = multinomial_chi2_test(&counts, &probs, ALPHA).unwrap();
+        assert_eq!(Hyp::Null, test_res.accepted());
+        println!("chi-square test result: {test_res:?}");
+        // chi-square test result: HypTestResult { p: 0.6548635043934949, alpha: 0.05, alt_hyp: Ne, accepted: Null }
+    }
+
+    {
+        // Few requests, so use the exact test.
+        let counts = [2, 3, 9, 1];
+        let test_res = exact_multinomial_test(&counts, &probs, ALPHA).unwrap();
+        assert_eq!(Hyp::Alt(AltHyp::Ne), test_res.accepted());
+        println!("exact test result: {test_res:?}");
+        // exact test result: HypTestResult { p: 0.0043587377891360045, alpha: 0.05, alt_hyp: Ne, accepted: Alt(Ne) }
+    }
+}
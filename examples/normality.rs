@@ -0,0 +1,50 @@
+use basic_stats::{
+    core::{AltHyp, HigherMoments, Hyp},
+    normality::{anderson_darling_test, jarque_bera_test, shapiro_wilk_test, shapiro_wilk_w},
+};
+
+const ALPHA: f64 = 0.05;
+
+fn main() {
+    // Fuel consumption (miles per gallon) of 32 cars.
+    let mpg = [
+        21.0, 21.0, 22.8, 21.4, 18.7, 18.1, 14.3, 24.4, 22.8, 19.2, 17.8, 16.4, 17.3, 15.2, 10.4,
+        10.4, 14.7, 32.4, 30.4, 33.9, 21.5, 15.5, 15.2, 13.3, 19.2, 27.3, 26.0, 30.4, 15.8, 19.7,
+        15.0, 21.4,
+    ];
+
+    let w = shapiro_wilk_w(&mpg).unwrap();
+    println!("Shapiro-Wilk W: {w}");
+    // Shapiro-Wilk W: 0.9475647264792736
+
+    let test_res = shapiro_wilk_test(&mpg, ALPHA).unwrap();
+    assert_eq!(Hyp::Null, test_res.accepted());
+    println!("test result: {test_res:?}");
+    // test result: HypTestResult { p: 0.12288135854534842, alpha: 0.05, alt_hyp: Ne, accepted: Null }
+
+    // Weights (lb) of 11 men, from Shapiro and Wilk's paper.
+    let weights = [
+        148., 154., 158., 160., 161., 162., 166., 170., 182., 195., 236.,
+    ];
+
+    let test_res = shapiro_wilk_test(&weights, ALPHA).unwrap();
+    assert_eq!(Hyp::Alt(AltHyp::Ne), test_res.accepted());
+    println!("test result: {test_res:?}");
+    // test result: HypTestResult { p: 0.006703814056217278, alpha: 0.05, alt_hyp: Ne, accepted: Alt(Ne) }
+
+    let test_res = anderson_darling_test(&weights, ALPHA).unwrap();
+    assert_eq!(Hyp::Alt(AltHyp::Ne), test_res.accepted());
+    println!("Anderson-Darling test result: {test_res:?}");
+    // Anderson-Darling test result: HypTestResult { p: 0.01045402400098327, alpha: 0.05, alt_hyp: Ne, accepted: Alt(Ne) }
+
+    // Telemetry values accumulated one at a time, without storing the sample.
+    let mut moments = HigherMoments::default();
+    for i in 0..10_000 {
+        let u = (i as f64 + 0.5) / 10_000.;
+        moments.collect_value(-(1. - u).ln());
+    }
+    let test_res = jarque_bera_test(&moments, ALPHA).unwrap();
+    assert_eq!(Hyp::Alt(AltHyp::Ne), test_res.accepted());
+    println!("Jarque-Bera test result: {test_res:?}");
+    // Jarque-Bera test result: HypTestResult { p: 0.0, alpha: 0.05, alt_hyp: Ne, accepted: Alt(Ne) }
+}
@@ -0,0 +1,63 @@
+use basic_stats::{
+    core::{AltHyp, SampleMoments},
+    outliers::{
+        MODIFIED_Z_THRESHOLD, TUKEY_K_OUTSIDE, dixon_outliers, generalized_esd_outliers,
+        grubbs_outliers, modified_z_outliers, tukey_outliers,
+    },
+};
+
+fn main() {
+    // Benchmark timings (ms); two runs were disturbed by a background task.
+    let timings = [
+        12.1, 11.8, 13.5, 12.9, 14.2, 11.2, 12.4, 25.9, 13.3, 12.7, 11.9, 13.8, 12.6, 24.8, 12.2,
+    ];
+
+    let tukey = tukey_outliers(&timings, TUKEY_K_OUTSIDE).unwrap();
+    println!(
+        "Tukey's fences flag {:?} at indices {:?}",
+        tukey.values(),
+        tukey.indices()
+    );
+    // Tukey's fences flag [25.9, 24.8] at indices [7, 13]
+
+    let modz = modified_z_outliers(&timings, MODIFIED_Z_THRESHOLD).unwrap();
+    println!("modified z-scores of flagged items: {:?}", modz.stats());
+    // modified z-scores of flagged items: [11.129080878235362, 10.20165747171575]
+
+    // Iterated two-sided Grubbs' test: the two outliers mask each other.
+    let grubbs = grubbs_outliers(&timings, AltHyp::Ne, 0.05, 3).unwrap();
+    println!(
+        "Grubbs' test flags {} items; G = {:?}",
+        grubbs.len(),
+        grubbs.stats()
+    );
+    // Grubbs' test flags 0 items; G = [2.5433409775219933]
+
+    // The generalized ESD test is not subject to masking.
+    let esd = generalized_esd_outliers(&timings, 0.05, 3).unwrap();
+    println!("ESD test flags {:?}", esd.values());
+    // ESD test flags [25.9, 24.8]
+    println!("R = {:?}", esd.stats());
+    // R = [2.5433409775219933, 3.366728452180059, 1.7873417394083075]
+    println!("critical values = {:?}", esd.crit_values());
+    // critical values = [2.5483077717433464, 2.5073208525788337, 2.462032868542699]
+
+    // Exclude the outliers before computing the sample moments.
+    let moments = SampleMoments::from_slice(esd.inliers());
+    println!(
+        "mean without outliers: {}, with outliers: {}",
+        moments.mean().unwrap(),
+        SampleMoments::from_slice(&timings).mean().unwrap()
+    );
+    // mean without outliers: 12.661538461538461, with outliers: 14.353333333333333
+
+    // Dixon's Q test, for a small sample.
+    let dixon = dixon_outliers(&[10.4, 10.1, 10.3, 12.2, 10.2], 0.05).unwrap();
+    println!(
+        "Dixon's Q = {:?}, critical value = {:?}, flagged = {:?}",
+        dixon.stats(),
+        dixon.crit_values(),
+        dixon.values()
+    );
+    // Dixon's Q = [0.8571428571428568], critical value = [0.71], flagged = [12.2]
+}
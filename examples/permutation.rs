@@ -0,0 +1,60 @@
+use basic_stats::{
+    core::{AltHyp, Hyp},
+    permutation::{mean_diff, permutation_test},
+};
+
+const ALPHA: f64 = 0.05;
+const N_RESAMPLES: u64 = 9999;
+const SEED: u64 = 42;
+
+fn main() {
+    // Build times (s) with the old and new compiler flags.
+    let times_old = [19.8, 21.3, 20.4, 23.9, 22.1, 20.9, 24.6];
+    let times_new = [18.2, 19.9, 20.1, 17.6, 21.0, 18.8];
+
+    // Few enough reassignments (1716) to enumerate them all.
+    let res = permutation_test(
+        &times_old,
+        &times_new,
+        mean_diff,
+        AltHyp::Gt,
+        N_RESAMPLES,
+        SEED,
+        ALPHA,
+    )
+    .unwrap();
+    assert!(res.is_exact());
+    assert_eq!(Hyp::Alt(AltHyp::Gt), res.test_result().accepted());
+    println!("exact test result: {:?}", res.test_result());
+    // exact test result: HypTestResult { p: 0.00641025641025641, alpha: 0.05, alt_hyp: Gt, accepted: Alt(Gt) }
+
+    // Larger samples, compared by a trimmed statistic: the difference of the 25% trimmed means.
+    let trimmed_mean = |s: &[f64]| {
+        let mut sorted = s.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let k = sorted.len() / 4;
+        let kept = &sorted[k..sorted.len() - k];
+        kept.iter().sum::<f64>() / kept.len() as f64
+    };
+    let times_old = [
+        19.8, 21.3, 20.4, 23.9, 22.1, 20.9, 24.6, 20.2, 35.7, 21.8, 22.4, 20.6,
+    ];
+    let times_new = [
+        18.2, 19.9, 20.1, 17.6, 21.0, 18.8, 19.4, 33.1, 20.7, 18.9, 19.6, 21.4,
+    ];
+    let res = permutation_test(
+        &times_old,
+        &times_new,
+        |x, y| trimmed_mean(x) - trimmed_mean(y),
+        AltHyp::Ne,
+        N_RESAMPLES,
+        SEED,
+        ALPHA,
+    )
+    .unwrap();
+    assert!(!res.is_exact());
+    println!("Monte Carlo test result: {:?}", res.test_result());
+    // Monte Carlo test result: HypTestResult { p: 0.0172, alpha: 0.05, alt_hyp: Ne, accepted: Alt(Ne) }
+    println!("standard error of the p-value: {}", res.p_se());
+    // standard error of the p-value: 0.0018468235096260745
+}
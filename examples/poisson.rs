@@ -0,0 +1,48 @@
+use basic_stats::{
+    core::{AltHyp, Hyp},
+    poisson::{
+        exact_poisson_test, poisson_garwood_ci, poisson_rate_hat, poisson_rate_ratio_ci,
+        poisson_rate_ratio_test,
+    },
+};
+
+const ALPHA: f64 = 0.05;
+
+fn main() {
+    // Crashes observed over 250 hours of operation, against a budget of 0.1 crashes per hour.
+    let (crashes, hours) = (38, 250.);
+
+    let rate_hat = poisson_rate_hat(crashes, hours).unwrap();
+    println!("crash rate estimate: {rate_hat}");
+    // crash rate estimate: 0.152
+
+    let ci = poisson_garwood_ci(crashes, hours, ALPHA).unwrap();
+    println!("confidence interval for crash rate: {ci:?}");
+    // confidence interval for crash rate: Ci(0.10756424658078434, 0.20863187567703997)
+
+    let test_res = exact_poisson_test(crashes, hours, 0.1, AltHyp::Gt, ALPHA).unwrap();
+    assert_eq!(Hyp::Alt(AltHyp::Gt), test_res.accepted());
+    println!("test result: {test_res:?}");
+    // test result: HypTestResult { p: 0.009210611590257595, alpha: 0.05, alt_hyp: Gt, accepted: Alt(Gt) }
+
+    // Crashes of the new build (first) versus the old build (second).
+    let (new_crashes, new_hours) = (9, 180.);
+
+    let ratio_res = poisson_rate_ratio_test(
+        new_crashes,
+        new_hours,
+        crashes,
+        hours,
+        1.,
+        AltHyp::Lt,
+        ALPHA,
+    )
+    .unwrap();
+    assert_eq!(Hyp::Alt(AltHyp::Lt), ratio_res.accepted());
+    println!("rate ratio test result: {ratio_res:?}");
+    // rate ratio test result: HypTestResult { p: 0.0008661189136159616, alpha: 0.05, alt_hyp: Lt, accepted: Alt(Lt) }
+
+    let ratio_ci = poisson_rate_ratio_ci(new_crashes, new_hours, crashes, hours, ALPHA).unwrap();
+    println!("confidence interval for rate ratio: {ratio_ci:?}");
+    // confidence interval for rate ratio: Ci(0.1398684016118693, 0.6921469917593714)
+}
@@ -0,0 +1,62 @@
+use basic_stats::{
+    core::{AltHyp, Hyp, SampleMoments},
+    normal::welch_test,
+    robust::{
+        HUBER_K_95, MAD_NORMAL_CONSTANT, huber_location, mad, median, qn, trimmed_mean,
+        trimmed_mean_ci, yuen_ci, yuen_test,
+    },
+};
+
+const ALPHA: f64 = 0.05;
+
+fn main() {
+    // Benchmark timings (ms), with two runs hit by GC pauses.
+    let timings = [
+        10.2, 9.8, 10.5, 10.1, 9.9, 10.4, 48.7, 10.0, 10.3, 10.6, 9.7, 10.2, 10.0, 35.2, 10.1, 10.4,
+    ];
+
+    let moments = SampleMoments::from_slice(&timings);
+    println!("mean: {}", moments.mean().unwrap());
+    // mean: 14.131249999999998
+    println!("standard deviation: {}", moments.stdev().unwrap());
+    // standard deviation: 11.138265499319607
+
+    println!("median: {}", median(&timings).unwrap());
+    // median: 10.2
+    println!("20% trimmed mean: {}", trimmed_mean(&timings, 0.2).unwrap());
+    // 20% trimmed mean: 10.220000000000002
+    println!(
+        "Huber location: {}",
+        huber_location(&timings, HUBER_K_95).unwrap()
+    );
+    // Huber location: 10.22499999999991
+
+    println!("MAD: {}", mad(&timings, MAD_NORMAL_CONSTANT).unwrap());
+    // MAD: 0.29652044370112196
+    println!("Qn: {}", qn(&timings).unwrap());
+    // Qn: 0.35909494949495147
+
+    let ci = trimmed_mean_ci(&timings, 0.2, ALPHA).unwrap();
+    println!("confidence interval for the 20% trimmed mean: {ci:?}");
+    // confidence interval for the 20% trimmed mean: Ci(10.023130130953628, 10.416869869046376)
+
+    // Timings of a new build, also with a GC pause. Is it faster?
+    let timings_new = [
+        9.6, 9.9, 9.5, 9.8, 9.7, 31.4, 9.9, 9.6, 9.5, 9.8, 9.6, 9.3, 9.9, 9.5,
+    ];
+    let moments_new = SampleMoments::from_slice(&timings_new);
+
+    let welch_res = welch_test(&moments, &moments_new, AltHyp::Gt, ALPHA).unwrap();
+    assert_eq!(Hyp::Null, welch_res.accepted());
+    println!("Welch test result: {welch_res:?}");
+    // Welch test result: HypTestResult { p: 0.18485179881202563, alpha: 0.05, alt_hyp: Gt, accepted: Null }
+
+    let yuen_res = yuen_test(&timings, &timings_new, 0.2, AltHyp::Gt, ALPHA).unwrap();
+    assert_eq!(Hyp::Alt(AltHyp::Gt), yuen_res.accepted());
+    println!("Yuen test result: {yuen_res:?}");
+    // Yuen test result: HypTestResult { p: 6.142012719003818e-5, alpha: 0.05, alt_hyp: Gt, accepted: Alt(Gt) }
+
+    let ci = yuen_ci(&timings, &timings_new, 0.2, ALPHA).unwrap();
+    println!("confidence interval for the difference of 20% trimmed means: {ci:?}");
+    // confidence interval for the difference of 20% trimmed means: Ci(0.30468656510281533, 0.7553134348971869)
+}
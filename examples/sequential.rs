@@ -0,0 +1,67 @@
+use basic_stats::{
+    core::{AltHyp, Hyp, SampleMoments},
+    sequential::{BernoulliSprt, MixtureSprt, SprtDecision},
+};
+
+fn main() {
+    // Is a test flaky (failure rate 0.2 or more) or healthy (failure rate 0.02 or less)?
+    let mut sprt = BernoulliSprt::new(0.02, 0.2, 0.05, 0.05).unwrap();
+
+    // Outcomes of consecutive reruns of the test, `true` for a failure.
+    let reruns = [
+        false, false, true, false, false, false, false, true, false, false, true, false,
+    ];
+
+    for failed in reruns {
+        if sprt.push(failed) != SprtDecision::Continue {
+            break;
+        }
+    }
+    assert_eq!(SprtDecision::AcceptAlt, sprt.decision());
+    println!("decision after {} reruns: {:?}", sprt.n(), sprt.decision());
+    // decision after 8 reruns: AcceptAlt
+
+    let asn = sprt.asn(0.02).unwrap();
+    println!("expected number of reruns for a healthy test: {asn}");
+    // expected number of reruns for a healthy test: 17.33945849291175
+
+    let oc = sprt.oc(0.1).unwrap();
+    println!("probability of declaring healthy a test with failure rate 0.1: {oc}");
+    // probability of declaring healthy a test with failure rate 0.1: 0.3627887711623483
+
+    // Benchmark timings (ms) of the old and new builds, checked after every batch of 4 runs.
+    let batches_old = [
+        [10.2, 9.8, 10.5, 10.1],
+        [9.9, 10.4, 10.0, 10.3],
+        [10.6, 9.7, 10.2, 10.0],
+        [10.1, 10.3, 9.9, 10.4],
+    ];
+    let batches_new = [
+        [9.6, 9.9, 9.5, 9.8],
+        [9.7, 9.4, 9.9, 9.6],
+        [9.5, 9.8, 9.6, 9.3],
+        [9.9, 9.5, 9.7, 9.6],
+    ];
+
+    let mut moments_old = SampleMoments::default();
+    let mut moments_new = SampleMoments::default();
+    let mut msprt = MixtureSprt::new(0., 0.5, 0.05).unwrap();
+    for (batch_old, batch_new) in batches_old.iter().zip(batches_new.iter()) {
+        batch_old.iter().for_each(|&v| moments_old.collect_value(v));
+        batch_new.iter().for_each(|&v| moments_new.collect_value(v));
+        let res = msprt.update(&moments_old, &moments_new).unwrap();
+        if res.accepted() != Hyp::Null {
+            break;
+        }
+    }
+    assert_eq!(Hyp::Alt(AltHyp::Ne), msprt.test_result().accepted());
+    println!("runs per build: {}", moments_old.n());
+    // runs per build: 8
+
+    println!("always-valid test result: {:?}", msprt.test_result());
+    // always-valid test result: HypTestResult { p: 0.00047130466918707145, alpha: 0.05, alt_hyp: Ne, accepted: Alt(Ne) }
+
+    let ci = msprt.ci().unwrap();
+    println!("always-valid confidence interval for the speedup: {ci:?}");
+    // always-valid confidence interval for the speedup: Ci(0.1412193924102394, 0.8087806075897634)
+}
@@ -1,6 +1,6 @@
 use basic_stats::{
     core::{AltHyp, Hyp, SampleMoments},
-    normal::{welch_ci, welch_test},
+    normal::{TTestDesign, t_test_sample_size, welch_ci, welch_test},
 };
 
 const ALPHA: f64 = 0.05;
@@ -30,4 +30,15 @@ fn main() {
         println!("confidence interval for difference of means: {ci:?}");
         // confidence interval for difference of means: Ci(7.570179981992128, 22.496486684674537)
     }
+
+    {
+        // Number of observations per sample needed to detect a difference of means of 5 with power 0.9,
+        // assuming the standard deviations observed above.
+        let sd_x = moments_x.stdev().unwrap();
+        let sd_ratio = moments_y.stdev().unwrap() / sd_x;
+        let design = TTestDesign::Welch { sd_ratio };
+        let n = t_test_sample_size(design, 5., sd_x, AltHyp::Gt, ALPHA, 0.9).unwrap();
+        println!("sample size for power 0.9: {n}");
+        // sample size for power 0.9: 24
+    }
 }
@@ -16,8 +16,12 @@ use super::{
     core::{AltHyp, Ci, HypTestResult},
     normal::{z_alpha, z_to_p},
 };
-use crate::core::{AsStatsResult, StatsError, StatsResult, check_alpha_in_open_0_1};
+use crate::core::{
+    AsStatsResult, StatsError, StatsResult, check_alpha_in_open_0_1, find_root, first_true,
+    tail_bounds_ci,
+};
 use statrs::distribution::{Beta, Binomial, ContinuousCDF, Discrete, DiscreteCDF};
+use std::f64::consts::FRAC_PI_2;
 
 fn check_p0_in_open_0_1(p0: f64) -> StatsResult<()> {
     if 0.0 < p0 && p0 < 1.0 {
@@ -185,6 +189,9 @@ pub fn binomial_ws_ci(n: u64, n_s: u64, alpha: f64) -> StatsResult<Ci> {
 ///
 /// See also [Confidence Intervals for One Proportion](https://www.ncss.com/wp-content/themes/ncss/pdf/Procedures/PASS/Confidence_Intervals_for_One_Proportion.pdf).
 ///
+/// This is the interval that matches [`exact_binomial_test`]. For the intervals that match its mid-p and Blaker
+/// variants, use [`exact_binomial_method_alt_hyp_ci`] with an [`ExactBinomialMethod`] option.
+///
 /// # Errors
 ///
 /// Returns an error in any of these conditions:
@@ -252,6 +259,9 @@ pub fn binomial_cp_alt_hyp_ci(n: u64, n_s: u64, alt_hyp: AltHyp, alpha: f64) ->
 ///
 /// See also [Confidence Intervals for One Proportion](https://www.ncss.com/wp-content/themes/ncss/pdf/Procedures/PASS/Confidence_Intervals_for_One_Proportion.pdf).
 ///
+/// This is the interval that matches [`exact_binomial_test`]. For the intervals that match its mid-p and Blaker
+/// variants, use [`exact_binomial_method_ci`] with an [`ExactBinomialMethod`] option.
+///
 /// # Errors
 ///
 /// Returns an error in any of these conditions:
@@ -261,88 +271,678 @@ pub fn binomial_cp_ci(n: u64, n_s: u64, alpha: f64) -> StatsResult<Ci> {
     binomial_cp_alt_hyp_ci(n, n_s, AltHyp::Ne, alpha)
 }
 
-/// p-value for the [one-sample proportion test](exact_binomial_test) (Bernoulli distribution).
+/// Tolerance used for root finding in confidence interval computations.
+const CI_ROOT_TOL: f64 = 1e-14;
+
+/// Binomial proportion confidence interval
+/// ([Agresti–Coull](https://en.wikipedia.org/wiki/Binomial_proportion_confidence_interval#Agresti%E2%80%93Coull_interval)).
+/// It is the Wald interval centered at `(n_s + z^2 / 2) / (n + z^2)`, truncated to the interval `[0, 1]`.
 ///
 /// Arguments:
 /// - `n`: number of trials.
 /// - `n_s`: number of successes (`1`s) observed.
-/// - `p0`: probability of success under null hypothesis.
 /// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
 ///
 /// # Errors
 ///
 /// Returns an error in any of these conditions:
 /// - `n == 0` or `n < n_s`.
-/// - `p0` is not in interval `[0, 1]`.
-pub fn exact_binomial_p(n: u64, n_s: u64, p0: f64, alt_hyp: AltHyp) -> StatsResult<f64> {
-    if n == 0 {
-        return Err(StatsError("arg `n` must be positive."));
-    }
-    if n < n_s {
-        return Err(StatsError("arg `n` must not be less than arg `n_s`."));
-    }
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_ac_alt_hyp_ci(n: u64, n_s: u64, alt_hyp: AltHyp, alpha: f64) -> StatsResult<Ci> {
+    bernoulli_p_hat(n, n_s)?;
+    let bound = |tail_alpha: f64, sign: f64| -> StatsResult<f64> {
+        let z = z_alpha(tail_alpha)?;
+        let n_tilde = n as f64 + z.powi(2);
+        let p_tilde = (n_s as f64 + z.powi(2) / 2.) / n_tilde;
+        let bound = p_tilde + sign * z * (p_tilde * (1. - p_tilde) / n_tilde).sqrt();
+        Ok(bound.clamp(0., 1.))
+    };
+    tail_bounds_ci(alt_hyp, alpha, 1., |a| bound(a, -1.), |a| bound(a, 1.))
+}
 
-    let binomial = Binomial::new(p0, n).stats_result("arg `p0` must be in interval [0, 1]")?;
+/// Binomial proportion confidence interval
+/// ([Agresti–Coull](https://en.wikipedia.org/wiki/Binomial_proportion_confidence_interval#Agresti%E2%80%93Coull_interval)),
+/// with the alternative hypothesis of inequality (two-sided).
+///
+/// See [`binomial_ac_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_ac_ci(n: u64, n_s: u64, alpha: f64) -> StatsResult<Ci> {
+    binomial_ac_alt_hyp_ci(n, n_s, AltHyp::Ne, alpha)
+}
 
-    let prob_le = || binomial.cdf(n_s);
+/// Binomial proportion confidence interval
+/// ([Jeffreys](https://en.wikipedia.org/wiki/Binomial_proportion_confidence_interval#Jeffreys_interval)).
+/// It is the equal-tailed Bayesian credible interval under the Jeffreys prior `Beta(0.5, 0.5)`, with the
+/// modification that the low end is `0` if `n_s == 0` and the high end is `1` if `n_s == n`.
+///
+/// Reference: L.D. Brown, T.T. Cai, and A. DasGupta, "Interval estimation for a binomial proportion",
+/// Statistical Science 16(2), 2001.
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_jeffreys_alt_hyp_ci(
+    n: u64,
+    n_s: u64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    bernoulli_p_hat(n, n_s)?;
+    let beta = Beta::new(n_s as f64 + 0.5, (n - n_s) as f64 + 0.5)
+        .stats_result("invalid arg `n` or `n_s`")?;
+    tail_bounds_ci(
+        alt_hyp,
+        alpha,
+        1.,
+        |a| Ok(if n_s == 0 { 0. } else { beta.inverse_cdf(a) }),
+        |a| {
+            Ok(if n_s == n {
+                1.
+            } else {
+                beta.inverse_cdf(1. - a)
+            })
+        },
+    )
+}
 
-    let prob_ge = || {
-        let prob_lt = if n_s == 0 { 0. } else { binomial.cdf(n_s - 1) };
-        1. - prob_lt
+/// Binomial proportion confidence interval
+/// ([Jeffreys](https://en.wikipedia.org/wiki/Binomial_proportion_confidence_interval#Jeffreys_interval)),
+/// with the alternative hypothesis of inequality (two-sided).
+///
+/// See [`binomial_jeffreys_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_jeffreys_ci(n: u64, n_s: u64, alpha: f64) -> StatsResult<Ci> {
+    binomial_jeffreys_alt_hyp_ci(n, n_s, AltHyp::Ne, alpha)
+}
+
+/// Binomial proportion confidence interval
+/// ([Wald](https://en.wikipedia.org/wiki/Binomial_proportion_confidence_interval#Problems_with_using_a_normal_approximation_or_%22Wald_interval%22)),
+/// truncated to the interval `[0, 1]`.
+///
+/// This interval has poor coverage for small `n` or for `n_s` close to `0` or `n`. In particular, it
+/// degenerates to a single point if `n_s == 0` or `n_s == n`.
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_wald_alt_hyp_ci(n: u64, n_s: u64, alt_hyp: AltHyp, alpha: f64) -> StatsResult<Ci> {
+    let p_hat = bernoulli_p_hat(n, n_s)?;
+    let se = (p_hat * (1. - p_hat) / n as f64).sqrt();
+    tail_bounds_ci(
+        alt_hyp,
+        alpha,
+        1.,
+        |a| Ok((p_hat - z_alpha(a)? * se).max(0.)),
+        |a| Ok((p_hat + z_alpha(a)? * se).min(1.)),
+    )
+}
+
+/// Binomial proportion confidence interval
+/// ([Wald](https://en.wikipedia.org/wiki/Binomial_proportion_confidence_interval#Problems_with_using_a_normal_approximation_or_%22Wald_interval%22)),
+/// with the alternative hypothesis of inequality (two-sided).
+///
+/// See [`binomial_wald_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_wald_ci(n: u64, n_s: u64, alpha: f64) -> StatsResult<Ci> {
+    binomial_wald_alt_hyp_ci(n, n_s, AltHyp::Ne, alpha)
+}
+
+/// Binomial proportion confidence interval
+/// ([arcsine](https://en.wikipedia.org/wiki/Binomial_proportion_confidence_interval#Arcsine_transformation)),
+/// based on the variance-stabilizing transformation `asin(sqrt(p))` of Anscombe's adjusted proportion
+/// `(n_s + 3/8) / (n + 3/4)`. Equivalent to method "arcsine" of the `R` package `binom`.
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_arcsine_alt_hyp_ci(
+    n: u64,
+    n_s: u64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    bernoulli_p_hat(n, n_s)?;
+    let p_tilde = (n_s as f64 + 0.375) / (n as f64 + 0.75);
+    let angle = p_tilde.sqrt().asin();
+    let delta = |a: f64| -> StatsResult<f64> { Ok(z_alpha(a)? / (2. * (n as f64).sqrt())) };
+    tail_bounds_ci(
+        alt_hyp,
+        alpha,
+        1.,
+        |a| Ok((angle - delta(a)?).max(0.).sin().powi(2)),
+        |a| Ok((angle + delta(a)?).min(FRAC_PI_2).sin().powi(2)),
+    )
+}
+
+/// Binomial proportion confidence interval
+/// ([arcsine](https://en.wikipedia.org/wiki/Binomial_proportion_confidence_interval#Arcsine_transformation)),
+/// with the alternative hypothesis of inequality (two-sided).
+///
+/// See [`binomial_arcsine_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_arcsine_ci(n: u64, n_s: u64, alpha: f64) -> StatsResult<Ci> {
+    binomial_arcsine_alt_hyp_ci(n, n_s, AltHyp::Ne, alpha)
+}
+
+/// Numerically stable `ln(1 + exp(t))`.
+fn softplus(t: f64) -> f64 {
+    if t > 0. {
+        t + (-t).exp().ln_1p()
+    } else {
+        t.exp().ln_1p()
+    }
+}
+
+/// Binomial proportion confidence interval (likelihood ratio). It consists of the values `p` for which the
+/// likelihood-ratio test of the hypothesis that the probability of success is `p` is not rejected, using the
+/// signed square root of the likelihood-ratio statistic for one-sided intervals.
+/// Equivalent to method "lrt" of the `R` package `binom`.
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_lr_alt_hyp_ci(n: u64, n_s: u64, alt_hyp: AltHyp, alpha: f64) -> StatsResult<Ci> {
+    let p_hat = bernoulli_p_hat(n, n_s)?;
+    let (n_s, n_f) = (n_s as f64, (n - n_s) as f64);
+
+    // Log-likelihood as a function of the log-odds `t` of the probability of success.
+    // The log-odds scale keeps the log-likelihood finite near the ends of the interval `(0, 1)`.
+    let ln_lik = |t: f64| -n_s * softplus(-t) - n_f * softplus(t);
+    let ln_lik_max = |p: f64| {
+        let term = |k: f64, q: f64| if k == 0. { 0. } else { k * q.ln() };
+        term(n_s, p) + term(n_f, 1. - p)
+    };
+    let ln_lik_max = ln_lik_max(p_hat);
+    let logistic = |t: f64| 1. / (1. + (-t).exp());
+
+    // Log-odds far enough from `0` for the log-likelihood-ratio to exceed any relevant threshold.
+    const T_MAX: f64 = 700.;
+
+    let bound = |a: f64, t_beyond: f64, bound_if_degenerate: f64| -> StatsResult<f64> {
+        let z = z_alpha(a)?;
+        let f = |t: f64| 2. * (ln_lik_max - ln_lik(t)) - z.powi(2);
+        let t_hat = (p_hat / (1. - p_hat)).ln().clamp(-T_MAX, T_MAX);
+        let bound = find_root(f, t_hat, t_beyond, CI_ROOT_TOL)
+            .map(logistic)
+            .unwrap_or(bound_if_degenerate);
+        Ok(bound)
     };
 
-    // Sum the probabilities of all values with probability lower or equal to `n_s`'s.
-    // Based on https://github.com/SurajGupta/r-source/blob/master/src/library/stats/R/binom.test.R
-    // code for PVAL.
-    let prob_ne = || {
-        if p0 == 0. {
-            (n_s == 0) as u64 as f64
-        } else if p0 == 1. {
-            (n_s == n) as u64 as f64
-        } else {
-            let rel_err = 1. + 1e-7;
-            let prob_n_s = binomial.pmf(n_s);
-            let mode = n as f64 * p0;
+    tail_bounds_ci(
+        alt_hyp,
+        alpha,
+        1.,
+        |a| {
+            if n_s == 0. {
+                Ok(0.)
+            } else {
+                bound(a, -T_MAX, 0.)
+            }
+        },
+        |a| {
+            if n_f == 0. {
+                Ok(1.)
+            } else {
+                bound(a, T_MAX, 1.)
+            }
+        },
+    )
+}
 
-            match (n_s as f64).total_cmp(&mode) {
-                Ordering::Equal => {
-                    // By definition of mode, all other values have prob less than `n_s`'s.
-                    1.
-                }
+/// Binomial proportion confidence interval (likelihood ratio), with the alternative hypothesis of
+/// inequality (two-sided).
+///
+/// See [`binomial_lr_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_lr_ci(n: u64, n_s: u64, alpha: f64) -> StatsResult<Ci> {
+    binomial_lr_alt_hyp_ci(n, n_s, AltHyp::Ne, alpha)
+}
 
-                Ordering::Less => {
-                    let mut sum_prob = 0.;
-                    let imode = mode.ceil() as u64;
-                    for i in (imode..=n).rev() {
-                        let prob_i = binomial.pmf(i);
-                        if prob_i <= prob_n_s * rel_err {
-                            sum_prob += prob_i;
-                        } else {
-                            break;
-                        }
-                    }
-                    prob_le() + sum_prob
-                }
+/// Binomial proportion confidence interval (mid-p). It is the Clopper–Pearson interval with the probability of the
+/// observed number of successes counted with weight `0.5` in the tail probabilities. It is less conservative
+/// than the Clopper–Pearson interval.
+///
+/// Reference: A. Agresti and A. Gottard, "Comment: Randomized confidence intervals and the mid-p approach",
+/// Statistical Science 20(4), 2005.
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_midp_alt_hyp_ci(n: u64, n_s: u64, alt_hyp: AltHyp, alpha: f64) -> StatsResult<Ci> {
+    bernoulli_p_hat(n, n_s)?;
+    let binomial = |p: f64| Binomial::new(p, n).expect("`p` should be in interval [0, 1]");
+
+    // Mid-p tail probabilities of `n_s`: `P(X > n_s) + P(X = n_s) / 2` and `P(X < n_s) + P(X = n_s) / 2`.
+    let midp_gt = |p: f64| {
+        let b = binomial(p);
+        b.sf(n_s) + b.pmf(n_s) / 2.
+    };
+    let midp_lt = |p: f64| {
+        let b = binomial(p);
+        b.cdf(n_s) - b.pmf(n_s) / 2.
+    };
 
-                Ordering::Greater => {
-                    let mut sum_prob = 0.;
-                    let imode = mode.floor() as u64;
-                    for i in 0..=imode {
-                        let prob_i = binomial.pmf(i);
-                        if prob_i <= prob_n_s * rel_err {
-                            sum_prob += prob_i;
-                        } else {
-                            break;
-                        }
-                    }
-                    prob_ge() + sum_prob
-                }
+    tail_bounds_ci(
+        alt_hyp,
+        alpha,
+        1.,
+        |a| {
+            if n_s == 0 {
+                Ok(0.)
+            } else {
+                Ok(find_root(|p| midp_gt(p) - a, 0., 1., CI_ROOT_TOL).unwrap_or(1.))
             }
+        },
+        |a| {
+            if n_s == n {
+                Ok(1.)
+            } else {
+                Ok(find_root(|p| midp_lt(p) - a, 0., 1., CI_ROOT_TOL).unwrap_or(0.))
+            }
+        },
+    )
+}
+
+/// Binomial proportion confidence interval (mid-p), with the alternative hypothesis of inequality (two-sided).
+///
+/// See [`binomial_midp_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_midp_ci(n: u64, n_s: u64, alpha: f64) -> StatsResult<Ci> {
+    binomial_midp_alt_hyp_ci(n, n_s, AltHyp::Ne, alpha)
+}
+
+/// Blaker's acceptability function: the probability, under the hypothesis that the probability of success is `p`,
+/// of the tail containing `n_s` with the smaller probability plus the largest opposite tail whose probability does not
+/// exceed it.
+fn blaker_acceptability(n: u64, n_s: u64, p: f64) -> f64 {
+    let binomial = Binomial::new(p, n).expect("`p` should be in interval [0, 1]");
+    let rel_err = 1. + 1e-7;
+
+    let prob_le = binomial.cdf(n_s);
+    let prob_ge = if n_s == 0 { 1. } else { binomial.sf(n_s - 1) };
+
+    // Largest `k` in `lo..=hi` for which `pred(k)` holds, given that `pred` is monotonically non-increasing.
+    let partition_point = |lo: u64, hi: u64, pred: &dyn Fn(u64) -> bool| -> Option<u64> {
+        if !pred(lo) {
+            return None;
+        }
+        let (mut lo, mut hi) = (lo, hi);
+        while lo < hi {
+            let mid = lo + (hi - lo).div_ceil(2);
+            if pred(mid) { lo = mid } else { hi = mid - 1 }
         }
+        Some(lo)
     };
 
-    let p_value = match alt_hyp {
-        AltHyp::Lt => prob_le(),
+    let acceptability = if prob_ge <= prob_le {
+        // Add the largest lower tail `P(X <= k)` not exceeding `prob_ge`.
+        let threshold = prob_ge * rel_err;
+        let other_tail = match n_s {
+            0 => None,
+            _ => partition_point(0, n_s - 1, &|k| binomial.cdf(k) <= threshold),
+        }
+        .map(|k| binomial.cdf(k))
+        .unwrap_or(0.);
+        prob_ge + other_tail
+    } else {
+        // Add the largest upper tail `P(X >= k)` not exceeding `prob_le`.
+        let threshold = prob_le * rel_err;
+        // The tail `P(X >= n - i)` is `binomial.sf(n - i - 1)`.
+        let other_tail = match n - n_s {
+            0 => None,
+            d => partition_point(0, d - 1, &|i| binomial.sf(n - i - 1) <= threshold),
+        }
+        .map(|i| binomial.sf(n - i - 1))
+        .unwrap_or(0.);
+        prob_le + other_tail
+    };
+
+    acceptability.min(1.)
+}
+
+/// Binomial proportion confidence interval
+/// ([Blaker](https://doi.org/10.2307/3315951)). It is the shortest exact interval nested within the
+/// Clopper–Pearson interval, obtained by inverting the test whose p-value is Blaker's acceptability function.
+///
+/// Because Blaker's interval is inherently two-sided, the one-sided intervals (for `alt_hyp` other than
+/// [`AltHyp::Ne`]) are the same as the one-sided [Clopper–Pearson intervals](binomial_cp_alt_hyp_ci).
+///
+/// Reference: H. Blaker, "Confidence curves and improved exact confidence intervals for discrete distributions",
+/// Canadian Journal of Statistics 28(4), 2000.
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_blaker_alt_hyp_ci(
+    n: u64,
+    n_s: u64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    if alt_hyp != AltHyp::Ne {
+        return binomial_cp_alt_hyp_ci(n, n_s, alt_hyp, alpha);
+    }
+    let p_hat = bernoulli_p_hat(n, n_s)?;
+    let Ci(cp_lo, cp_hi) = binomial_cp_alt_hyp_ci(n, n_s, AltHyp::Ne, alpha)?;
+
+    // The acceptability function is discontinuous, so scan from the Clopper–Pearson bound towards `p_hat`
+    // for the first accepted value, then refine it by bisection.
+    const N_STEPS: u32 = 1000;
+    const N_BISECTIONS: u32 = 60;
+    let accepted = |p: f64| blaker_acceptability(n, n_s, p) >= alpha;
+    let bound = |from: f64| {
+        let mut prev = from;
+        for i in 1..=N_STEPS {
+            let curr = from + (p_hat - from) * i as f64 / N_STEPS as f64;
+            if accepted(curr) {
+                let (mut out, mut inn) = (prev, curr);
+                for _ in 0..N_BISECTIONS {
+                    let mid = (out + inn) / 2.;
+                    if accepted(mid) { inn = mid } else { out = mid }
+                }
+                return inn;
+            }
+            prev = curr;
+        }
+        p_hat
+    };
+
+    let lo = if n_s == 0 { 0. } else { bound(cp_lo) };
+    let hi = if n_s == n { 1. } else { bound(cp_hi) };
+    Ok(Ci(lo, hi))
+}
+
+/// Binomial proportion confidence interval ([Blaker](https://doi.org/10.2307/3315951)),
+/// with the alternative hypothesis of inequality (two-sided).
+///
+/// See [`binomial_blaker_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_blaker_ci(n: u64, n_s: u64, alpha: f64) -> StatsResult<Ci> {
+    binomial_blaker_alt_hyp_ci(n, n_s, AltHyp::Ne, alpha)
+}
+
+/// Method used to compute a binomial proportion confidence interval, for selection at runtime.
+///
+/// See [`binomial_alt_hyp_ci`] and [`binomial_ci`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BinomialCiMethod {
+    /// Wilson score. See [`binomial_ws_alt_hyp_ci`].
+    WilsonScore,
+    /// Clopper–Pearson. See [`binomial_cp_alt_hyp_ci`].
+    ClopperPearson,
+    /// Agresti–Coull. See [`binomial_ac_alt_hyp_ci`].
+    AgrestiCoull,
+    /// Jeffreys. See [`binomial_jeffreys_alt_hyp_ci`].
+    Jeffreys,
+    /// Wald. See [`binomial_wald_alt_hyp_ci`].
+    Wald,
+    /// Arcsine. See [`binomial_arcsine_alt_hyp_ci`].
+    Arcsine,
+    /// Likelihood ratio. See [`binomial_lr_alt_hyp_ci`].
+    LikelihoodRatio,
+    /// Mid-p. See [`binomial_midp_alt_hyp_ci`].
+    MidP,
+    /// Blaker. See [`binomial_blaker_alt_hyp_ci`].
+    Blaker,
+}
+
+/// Binomial proportion confidence interval computed with the given `method`.
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `method`: method used to compute the interval.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_alt_hyp_ci(
+    n: u64,
+    n_s: u64,
+    method: BinomialCiMethod,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    let ci_fn = match method {
+        BinomialCiMethod::WilsonScore => binomial_ws_alt_hyp_ci,
+        BinomialCiMethod::ClopperPearson => binomial_cp_alt_hyp_ci,
+        BinomialCiMethod::AgrestiCoull => binomial_ac_alt_hyp_ci,
+        BinomialCiMethod::Jeffreys => binomial_jeffreys_alt_hyp_ci,
+        BinomialCiMethod::Wald => binomial_wald_alt_hyp_ci,
+        BinomialCiMethod::Arcsine => binomial_arcsine_alt_hyp_ci,
+        BinomialCiMethod::LikelihoodRatio => binomial_lr_alt_hyp_ci,
+        BinomialCiMethod::MidP => binomial_midp_alt_hyp_ci,
+        BinomialCiMethod::Blaker => binomial_blaker_alt_hyp_ci,
+    };
+    ci_fn(n, n_s, alt_hyp, alpha)
+}
+
+/// Binomial proportion confidence interval computed with the given `method`,
+/// with the alternative hypothesis of inequality (two-sided).
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `method`: method used to compute the interval.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn binomial_ci(n: u64, n_s: u64, method: BinomialCiMethod, alpha: f64) -> StatsResult<Ci> {
+    binomial_alt_hyp_ci(n, n_s, method, AltHyp::Ne, alpha)
+}
+
+/// p-value for the [one-sample proportion test](exact_binomial_test) (Bernoulli distribution).
+///
+/// For the mid-p and Blaker p-values, use [`exact_binomial_method_p`] with an [`ExactBinomialMethod`] option;
+/// this function is equivalent to it with [`ExactBinomialMethod::Standard`].
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `p0`: probability of success under null hypothesis.
+/// - `alt_hyp`: alternative hypothesis.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `p0` is not in interval `[0, 1]`.
+pub fn exact_binomial_p(n: u64, n_s: u64, p0: f64, alt_hyp: AltHyp) -> StatsResult<f64> {
+    if n == 0 {
+        return Err(StatsError("arg `n` must be positive."));
+    }
+    if n < n_s {
+        return Err(StatsError("arg `n` must not be less than arg `n_s`."));
+    }
+
+    let binomial = Binomial::new(p0, n).stats_result("arg `p0` must be in interval [0, 1]")?;
+
+    let prob_le = || binomial.cdf(n_s);
+
+    let prob_ge = || {
+        let prob_lt = if n_s == 0 { 0. } else { binomial.cdf(n_s - 1) };
+        1. - prob_lt
+    };
+
+    // Sum the probabilities of all values with probability lower or equal to `n_s`'s.
+    // Based on https://github.com/SurajGupta/r-source/blob/master/src/library/stats/R/binom.test.R
+    // code for PVAL.
+    let prob_ne = || {
+        if p0 == 0. {
+            (n_s == 0) as u64 as f64
+        } else if p0 == 1. {
+            (n_s == n) as u64 as f64
+        } else {
+            let rel_err = 1. + 1e-7;
+            let prob_n_s = binomial.pmf(n_s);
+            let mode = n as f64 * p0;
+
+            match (n_s as f64).total_cmp(&mode) {
+                Ordering::Equal => {
+                    // By definition of mode, all other values have prob less than `n_s`'s.
+                    1.
+                }
+
+                Ordering::Less => {
+                    let mut sum_prob = 0.;
+                    let imode = mode.ceil() as u64;
+                    for i in (imode..=n).rev() {
+                        let prob_i = binomial.pmf(i);
+                        if prob_i <= prob_n_s * rel_err {
+                            sum_prob += prob_i;
+                        } else {
+                            break;
+                        }
+                    }
+                    prob_le() + sum_prob
+                }
+
+                Ordering::Greater => {
+                    let mut sum_prob = 0.;
+                    let imode = mode.floor() as u64;
+                    for i in 0..=imode {
+                        let prob_i = binomial.pmf(i);
+                        if prob_i <= prob_n_s * rel_err {
+                            sum_prob += prob_i;
+                        } else {
+                            break;
+                        }
+                    }
+                    prob_ge() + sum_prob
+                }
+            }
+        }
+    };
+
+    let p_value = match alt_hyp {
+        AltHyp::Lt => prob_le(),
         AltHyp::Gt => prob_ge(),
         AltHyp::Ne => prob_ne(),
     };
@@ -350,33 +950,1218 @@ pub fn exact_binomial_p(n: u64, n_s: u64, p0: f64, alt_hyp: AltHyp) -> StatsResu
     Ok(p_value)
 }
 
-/// One-sample proportion test (Bernoulli distribution).
+/// One-sample proportion test (Bernoulli distribution).
+///
+/// For the mid-p and Blaker variants of the test, use [`exact_binomial_method_test`] with an
+/// [`ExactBinomialMethod`] option; this function is equivalent to it with [`ExactBinomialMethod::Standard`].
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `p0`: probability of success under null hypothesis.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `p0` is not in interval `[0, 1]`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn exact_binomial_test(
+    n: u64,
+    n_s: u64,
+    p0: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+
+    let p_value = exact_binomial_p(n, n_s, p0, alt_hyp)?;
+    let test_res = HypTestResult::new(p_value, alpha, alt_hyp);
+    Ok(test_res)
+}
+
+/// Method used to compute the p-value of the exact binomial test, for selection at runtime.
+///
+/// See [`exact_binomial_method_p`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ExactBinomialMethod {
+    /// The method of [`exact_binomial_p`]. The two-sided p-value is the sum of the probabilities of all values
+    /// with probability not greater than that of the observed value, as in `R`'s `binom.test` function.
+    Standard,
+    /// Mid-p: the probability of the observed value is counted with weight `0.5` in the tail probabilities,
+    /// and the two-sided p-value is twice the smaller one-sided p-value, capped at `1`.
+    MidP,
+    /// Blaker: the two-sided p-value is Blaker's acceptability function, i.e., the smaller tail probability of the
+    /// observed value plus the largest opposite tail probability not exceeding it. Because Blaker's method is
+    /// inherently two-sided, one-sided p-values are the same as for [`ExactBinomialMethod::Standard`].
+    Blaker,
+}
+
+/// Exact binomial test p-value, computed with the given `method`.
+///
+/// The confidence intervals that invert the tests are given by [`exact_binomial_method_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `p0`: probability of success under null hypothesis.
+/// - `method`: method used to compute the p-value.
+/// - `alt_hyp`: alternative hypothesis.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `p0` is not in interval `[0, 1]`.
+pub fn exact_binomial_method_p(
+    n: u64,
+    n_s: u64,
+    p0: f64,
+    method: ExactBinomialMethod,
+    alt_hyp: AltHyp,
+) -> StatsResult<f64> {
+    let p_value = match (method, alt_hyp) {
+        (ExactBinomialMethod::Standard, _)
+        | (ExactBinomialMethod::Blaker, AltHyp::Lt | AltHyp::Gt) => {
+            exact_binomial_p(n, n_s, p0, alt_hyp)?
+        }
+
+        (ExactBinomialMethod::MidP, _) => {
+            bernoulli_p_hat(n, n_s)?;
+            let binomial =
+                Binomial::new(p0, n).stats_result("arg `p0` must be in interval [0, 1]")?;
+            let half_prob_n_s = binomial.pmf(n_s) / 2.;
+            let prob_lt = || binomial.cdf(n_s) - half_prob_n_s;
+            let prob_gt = || binomial.sf(n_s) + half_prob_n_s;
+            match alt_hyp {
+                AltHyp::Lt => prob_lt(),
+                AltHyp::Gt => prob_gt(),
+                AltHyp::Ne => (2. * prob_lt().min(prob_gt())).min(1.),
+            }
+        }
+
+        (ExactBinomialMethod::Blaker, AltHyp::Ne) => {
+            bernoulli_p_hat(n, n_s)?;
+            if !(0. ..=1.).contains(&p0) {
+                return Err(StatsError("arg `p0` must be in interval [0, 1]"));
+            }
+            blaker_acceptability(n, n_s, p0)
+        }
+    };
+
+    Ok(p_value)
+}
+
+/// Exact binomial test (Bernoulli distribution), with the p-value computed with the given `method`.
+///
+/// See [`exact_binomial_method_p`].
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `p0`: probability of success under null hypothesis.
+/// - `method`: method used to compute the p-value.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `p0` is not in interval `[0, 1]`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn exact_binomial_method_test(
+    n: u64,
+    n_s: u64,
+    p0: f64,
+    method: ExactBinomialMethod,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+
+    let p_value = exact_binomial_method_p(n, n_s, p0, method, alt_hyp)?;
+    let test_res = HypTestResult::new(p_value, alpha, alt_hyp);
+    Ok(test_res)
+}
+
+/// Binomial proportion confidence interval consisting of the values of `p0` not rejected by the exact binomial test
+/// with the given `method`:
+/// - [`ExactBinomialMethod::Standard`]: the [Clopper–Pearson interval](binomial_cp_alt_hyp_ci). It inverts the
+///   one-sided tests exactly; the two-sided interval inverts the test that doubles the smaller one-sided p-value,
+///   so it does not exactly correspond to the two-sided standard test.
+/// - [`ExactBinomialMethod::MidP`]: the [mid-p interval](binomial_midp_alt_hyp_ci).
+/// - [`ExactBinomialMethod::Blaker`]: the [Blaker interval](binomial_blaker_alt_hyp_ci).
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `method`: method used to compute the p-value of the test.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn exact_binomial_method_alt_hyp_ci(
+    n: u64,
+    n_s: u64,
+    method: ExactBinomialMethod,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    match method {
+        ExactBinomialMethod::Standard => binomial_cp_alt_hyp_ci(n, n_s, alt_hyp, alpha),
+        ExactBinomialMethod::MidP => binomial_midp_alt_hyp_ci(n, n_s, alt_hyp, alpha),
+        ExactBinomialMethod::Blaker => binomial_blaker_alt_hyp_ci(n, n_s, alt_hyp, alpha),
+    }
+}
+
+/// Binomial proportion confidence interval consisting of the values of `p0` not rejected by the exact binomial test
+/// with the given `method`, with the alternative hypothesis of inequality (two-sided).
+///
+/// See [`exact_binomial_method_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `n_s`: number of successes (`1`s) observed.
+/// - `method`: method used to compute the p-value of the test.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_s`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn exact_binomial_method_ci(
+    n: u64,
+    n_s: u64,
+    method: ExactBinomialMethod,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    exact_binomial_method_alt_hyp_ci(n, n_s, method, AltHyp::Ne, alpha)
+}
+
+/// Yates continuity correction for the two-proportion z-test, as used by `R`'s `prop.test` function.
+fn two_proportion_yates(n_x: u64, n_y: u64, d_p_hat: f64, continuity_correction: bool) -> f64 {
+    if continuity_correction {
+        let sum_inv_n = 1. / n_x as f64 + 1. / n_y as f64;
+        (d_p_hat.abs() / sum_inv_n).min(0.5)
+    } else {
+        0.
+    }
+}
+
+/// Normal approximation z-value for the difference between the sample proportions of two Bernoulli distributions
+/// under the hypothesis that the two probabilities of success are equal, using the pooled proportion.
+///
+/// The square of this value is the chi-square statistic computed by `R`'s `prop.test` function for two samples.
+///
+/// Arguments:
+/// - `n_x`: number of trials in the first sample.
+/// - `n_s_x`: number of successes (`1`s) observed in the first sample.
+/// - `n_y`: number of trials in the second sample.
+/// - `n_s_y`: number of successes (`1`s) observed in the second sample.
+/// - `continuity_correction`: whether to apply Yates' continuity correction.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n_x == 0` or `n_x < n_s_x`.
+/// - `n_y == 0` or `n_y < n_s_y`.
+/// - All trials in both samples are successes or all trials in both samples are failures.
+pub fn two_proportion_z(
+    n_x: u64,
+    n_s_x: u64,
+    n_y: u64,
+    n_s_y: u64,
+    continuity_correction: bool,
+) -> StatsResult<f64> {
+    let p_hat_x = bernoulli_p_hat(n_x, n_s_x)?;
+    let p_hat_y = bernoulli_p_hat(n_y, n_s_y)?;
+    let p_pooled = bernoulli_p_hat(n_x + n_y, n_s_x + n_s_y)?;
+    if p_pooled == 0. || p_pooled == 1. {
+        return Err(StatsError(
+            "samples must not consist only of successes or only of failures",
+        ));
+    }
+
+    let d_p_hat = p_hat_x - p_hat_y;
+    let sum_inv_n = 1. / n_x as f64 + 1. / n_y as f64;
+    let yates = two_proportion_yates(n_x, n_y, d_p_hat, continuity_correction);
+    let numerator = d_p_hat.signum() * (d_p_hat.abs() - yates * sum_inv_n);
+    let ret = numerator / (p_pooled * (1. - p_pooled) * sum_inv_n).sqrt();
+    Ok(ret)
+}
+
+/// Normal approximation p-value for the difference between the sample proportions of two Bernoulli distributions
+/// under the hypothesis that the two probabilities of success are equal.
+///
+/// Arguments:
+/// - `n_x`: number of trials in the first sample.
+/// - `n_s_x`: number of successes (`1`s) observed in the first sample.
+/// - `n_y`: number of trials in the second sample.
+/// - `n_s_y`: number of successes (`1`s) observed in the second sample.
+/// - `continuity_correction`: whether to apply Yates' continuity correction.
+/// - `alt_hyp`: alternative hypothesis.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n_x == 0` or `n_x < n_s_x`.
+/// - `n_y == 0` or `n_y < n_s_y`.
+/// - All trials in both samples are successes or all trials in both samples are failures.
+pub fn two_proportion_z_p(
+    n_x: u64,
+    n_s_x: u64,
+    n_y: u64,
+    n_s_y: u64,
+    continuity_correction: bool,
+    alt_hyp: AltHyp,
+) -> StatsResult<f64> {
+    let z = two_proportion_z(n_x, n_s_x, n_y, n_s_y, continuity_correction)?;
+    Ok(z_to_p(z, alt_hyp))
+}
+
+/// Two-sample proportion test (Bernoulli distributions) using the pooled Normal approximation.
+/// Equivalent to `R`'s `prop.test` function for two samples.
+///
+/// Arguments:
+/// - `n_x`: number of trials in the first sample.
+/// - `n_s_x`: number of successes (`1`s) observed in the first sample.
+/// - `n_y`: number of trials in the second sample.
+/// - `n_s_y`: number of successes (`1`s) observed in the second sample.
+/// - `continuity_correction`: whether to apply Yates' continuity correction.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n_x == 0` or `n_x < n_s_x`.
+/// - `n_y == 0` or `n_y < n_s_y`.
+/// - All trials in both samples are successes or all trials in both samples are failures.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn two_proportion_z_test(
+    n_x: u64,
+    n_s_x: u64,
+    n_y: u64,
+    n_s_y: u64,
+    continuity_correction: bool,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p_value = two_proportion_z_p(n_x, n_s_x, n_y, n_s_y, continuity_correction, alt_hyp)?;
+    let test_res = HypTestResult::new(p_value, alpha, alt_hyp);
+    Ok(test_res)
+}
+
+/// Builds a confidence interval for a difference of proportions from its estimate and the margins below and above it,
+/// truncating the result to the interval `[-1, 1]`.
+fn diff_ci(mid: f64, delta_lo: f64, delta_hi: f64, alt_hyp: AltHyp) -> Ci {
+    let lo = (mid - delta_lo).max(-1.);
+    let hi = (mid + delta_hi).min(1.);
+    match alt_hyp {
+        AltHyp::Lt => Ci(-1., hi),
+        AltHyp::Ne => Ci(lo, hi),
+        AltHyp::Gt => Ci(lo, 1.),
+    }
+}
+
+/// Wald confidence interval for the difference of the probabilities of success (`p_x - p_y`)
+/// of two Bernoulli distributions. This is the interval computed by `R`'s `prop.test` function for two samples.
+///
+/// Arguments:
+/// - `n_x`: number of trials in the first sample.
+/// - `n_s_x`: number of successes (`1`s) observed in the first sample.
+/// - `n_y`: number of trials in the second sample.
+/// - `n_s_y`: number of successes (`1`s) observed in the second sample.
+/// - `continuity_correction`: whether to apply Yates' continuity correction.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n_x == 0` or `n_x < n_s_x`.
+/// - `n_y == 0` or `n_y < n_s_y`.
+/// - `alpha` is not in interval `(0, 1)`.
+#[allow(clippy::too_many_arguments)]
+pub fn two_proportion_wald_alt_hyp_ci(
+    n_x: u64,
+    n_s_x: u64,
+    n_y: u64,
+    n_s_y: u64,
+    continuity_correction: bool,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    let p_hat_x = bernoulli_p_hat(n_x, n_s_x)?;
+    let p_hat_y = bernoulli_p_hat(n_y, n_s_y)?;
+
+    check_alpha_in_open_0_1(alpha)?; // need this guard because `alpha / 2.` below masks errors
+    let z_alpha = if let AltHyp::Ne = alt_hyp {
+        z_alpha(alpha / 2.)?
+    } else {
+        z_alpha(alpha)?
+    };
+
+    let d_p_hat = p_hat_x - p_hat_y;
+    let sum_inv_n = 1. / n_x as f64 + 1. / n_y as f64;
+    let yates = two_proportion_yates(n_x, n_y, d_p_hat, continuity_correction);
+    let var = p_hat_x * (1. - p_hat_x) / n_x as f64 + p_hat_y * (1. - p_hat_y) / n_y as f64;
+    let delta = z_alpha * var.sqrt() + yates * sum_inv_n;
+
+    Ok(diff_ci(d_p_hat, delta, delta, alt_hyp))
+}
+
+/// Wald confidence interval for the difference of the probabilities of success (`p_x - p_y`)
+/// of two Bernoulli distributions, with the alternative hypothesis of inequality (two-sided).
+/// This is the interval computed by `R`'s `prop.test` function for two samples.
+///
+/// Arguments:
+/// - `n_x`: number of trials in the first sample.
+/// - `n_s_x`: number of successes (`1`s) observed in the first sample.
+/// - `n_y`: number of trials in the second sample.
+/// - `n_s_y`: number of successes (`1`s) observed in the second sample.
+/// - `continuity_correction`: whether to apply Yates' continuity correction.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n_x == 0` or `n_x < n_s_x`.
+/// - `n_y == 0` or `n_y < n_s_y`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn two_proportion_wald_ci(
+    n_x: u64,
+    n_s_x: u64,
+    n_y: u64,
+    n_s_y: u64,
+    continuity_correction: bool,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    two_proportion_wald_alt_hyp_ci(
+        n_x,
+        n_s_x,
+        n_y,
+        n_s_y,
+        continuity_correction,
+        AltHyp::Ne,
+        alpha,
+    )
+}
+
+/// Newcombe's hybrid score confidence interval for the difference of the probabilities of success (`p_x - p_y`)
+/// of two Bernoulli distributions, based on the [Wilson score intervals](binomial_ws_alt_hyp_ci)
+/// for each sample (without continuity correction).
+///
+/// Reference: R.G. Newcombe, "Interval estimation for the difference between independent proportions:
+/// comparison of eleven methods", Statistics in Medicine 17, 1998 (method 10).
+///
+/// Arguments:
+/// - `n_x`: number of trials in the first sample.
+/// - `n_s_x`: number of successes (`1`s) observed in the first sample.
+/// - `n_y`: number of trials in the second sample.
+/// - `n_s_y`: number of successes (`1`s) observed in the second sample.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n_x == 0` or `n_x < n_s_x`.
+/// - `n_y == 0` or `n_y < n_s_y`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn two_proportion_newcombe_alt_hyp_ci(
+    n_x: u64,
+    n_s_x: u64,
+    n_y: u64,
+    n_s_y: u64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    let p_hat_x = bernoulli_p_hat(n_x, n_s_x)?;
+    let p_hat_y = bernoulli_p_hat(n_y, n_s_y)?;
+    let Ci(lo_x, hi_x) = binomial_ws_alt_hyp_ci(n_x, n_s_x, AltHyp::Ne, alpha)?;
+    let Ci(lo_y, hi_y) = binomial_ws_alt_hyp_ci(n_y, n_s_y, AltHyp::Ne, alpha)?;
+    // One-sided bounds use the Wilson bounds at level `alpha` instead of `alpha / 2`.
+    let (lo_x, hi_x, lo_y, hi_y) = match alt_hyp {
+        AltHyp::Ne => (lo_x, hi_x, lo_y, hi_y),
+        _ => {
+            let lo_x = binomial_ws_alt_hyp_ci(n_x, n_s_x, AltHyp::Gt, alpha)?.0;
+            let hi_x = binomial_ws_alt_hyp_ci(n_x, n_s_x, AltHyp::Lt, alpha)?.1;
+            let lo_y = binomial_ws_alt_hyp_ci(n_y, n_s_y, AltHyp::Gt, alpha)?.0;
+            let hi_y = binomial_ws_alt_hyp_ci(n_y, n_s_y, AltHyp::Lt, alpha)?.1;
+            (lo_x, hi_x, lo_y, hi_y)
+        }
+    };
+
+    let d_p_hat = p_hat_x - p_hat_y;
+    let delta_lo = ((p_hat_x - lo_x).powi(2) + (hi_y - p_hat_y).powi(2)).sqrt();
+    let delta_hi = ((hi_x - p_hat_x).powi(2) + (p_hat_y - lo_y).powi(2)).sqrt();
+
+    Ok(diff_ci(d_p_hat, delta_lo, delta_hi, alt_hyp))
+}
+
+/// Newcombe's hybrid score confidence interval for the difference of the probabilities of success (`p_x - p_y`)
+/// of two Bernoulli distributions, with the alternative hypothesis of inequality (two-sided).
+///
+/// See [`two_proportion_newcombe_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `n_x`: number of trials in the first sample.
+/// - `n_s_x`: number of successes (`1`s) observed in the first sample.
+/// - `n_y`: number of trials in the second sample.
+/// - `n_s_y`: number of successes (`1`s) observed in the second sample.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n_x == 0` or `n_x < n_s_x`.
+/// - `n_y == 0` or `n_y < n_s_y`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn two_proportion_newcombe_ci(
+    n_x: u64,
+    n_s_x: u64,
+    n_y: u64,
+    n_s_y: u64,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    two_proportion_newcombe_alt_hyp_ci(n_x, n_s_x, n_y, n_s_y, AltHyp::Ne, alpha)
+}
+
+/// Agresti–Caffo confidence interval for the difference of the probabilities of success (`p_x - p_y`)
+/// of two Bernoulli distributions. It is the Wald interval computed after adding one success and one failure
+/// to each sample.
+///
+/// Reference: A. Agresti and B. Caffo, "Simple and effective confidence intervals for proportions and
+/// differences of proportions result from adding two successes and two failures",
+/// The American Statistician 54(4), 2000.
+///
+/// Arguments:
+/// - `n_x`: number of trials in the first sample.
+/// - `n_s_x`: number of successes (`1`s) observed in the first sample.
+/// - `n_y`: number of trials in the second sample.
+/// - `n_s_y`: number of successes (`1`s) observed in the second sample.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n_x == 0` or `n_x < n_s_x`.
+/// - `n_y == 0` or `n_y < n_s_y`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn two_proportion_ac_alt_hyp_ci(
+    n_x: u64,
+    n_s_x: u64,
+    n_y: u64,
+    n_s_y: u64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    // Validate the original counts before adjusting them.
+    bernoulli_p_hat(n_x, n_s_x)?;
+    bernoulli_p_hat(n_y, n_s_y)?;
+    two_proportion_wald_alt_hyp_ci(
+        n_x + 2,
+        n_s_x + 1,
+        n_y + 2,
+        n_s_y + 1,
+        false,
+        alt_hyp,
+        alpha,
+    )
+}
+
+/// Agresti–Caffo confidence interval for the difference of the probabilities of success (`p_x - p_y`)
+/// of two Bernoulli distributions, with the alternative hypothesis of inequality (two-sided).
+///
+/// See [`two_proportion_ac_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `n_x`: number of trials in the first sample.
+/// - `n_s_x`: number of successes (`1`s) observed in the first sample.
+/// - `n_y`: number of trials in the second sample.
+/// - `n_s_y`: number of successes (`1`s) observed in the second sample.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n_x == 0` or `n_x < n_s_x`.
+/// - `n_y == 0` or `n_y < n_s_y`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn two_proportion_ac_ci(
+    n_x: u64,
+    n_s_x: u64,
+    n_y: u64,
+    n_s_y: u64,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    two_proportion_ac_alt_hyp_ci(n_x, n_s_x, n_y, n_s_y, AltHyp::Ne, alpha)
+}
+
+fn check_discordant(n_sf: u64, n_fs: u64) -> StatsResult<()> {
+    if n_sf + n_fs == 0 {
+        return Err(StatsError(
+            "at least one of args `n_sf` and `n_fs` must be positive",
+        ));
+    }
+    Ok(())
+}
+
+/// Normal approximation z-value of McNemar's test for paired Bernoulli samples, under the hypothesis that the
+/// probabilities of success in the first and second samples are equal.
+///
+/// Each pair consists of an observation in the first sample and an observation in the second sample
+/// (e.g., the outcomes of the same test case on an old build and on a new build). Only discordant pairs
+/// are informative. The square of this value is the chi-square statistic computed by `R`'s `mcnemar.test`
+/// function, except that, with continuity correction, this value is `0` when `n_sf == n_fs`.
+///
+/// Arguments:
+/// - `n_sf`: number of pairs with a success in the first sample and a failure in the second sample.
+/// - `n_fs`: number of pairs with a failure in the first sample and a success in the second sample.
+/// - `continuity_correction`: whether to apply the continuity correction.
+///
+/// # Errors
+///
+/// Returns an error if `n_sf + n_fs == 0`.
+pub fn mcnemar_z(n_sf: u64, n_fs: u64, continuity_correction: bool) -> StatsResult<f64> {
+    check_discordant(n_sf, n_fs)?;
+    let diff = n_sf as f64 - n_fs as f64;
+    let numerator = if continuity_correction {
+        diff.signum() * (diff.abs() - 1.).max(0.)
+    } else {
+        diff
+    };
+    Ok(numerator / ((n_sf + n_fs) as f64).sqrt())
+}
+
+/// Normal approximation p-value of McNemar's test for paired Bernoulli samples, under the hypothesis that the
+/// probabilities of success in the first and second samples are equal.
+///
+/// See [`mcnemar_z`].
+///
+/// Arguments:
+/// - `n_sf`: number of pairs with a success in the first sample and a failure in the second sample.
+/// - `n_fs`: number of pairs with a failure in the first sample and a success in the second sample.
+/// - `continuity_correction`: whether to apply the continuity correction.
+/// - `alt_hyp`: alternative hypothesis.
+///
+/// # Errors
+///
+/// Returns an error if `n_sf + n_fs == 0`.
+pub fn mcnemar_z_p(
+    n_sf: u64,
+    n_fs: u64,
+    continuity_correction: bool,
+    alt_hyp: AltHyp,
+) -> StatsResult<f64> {
+    let z = mcnemar_z(n_sf, n_fs, continuity_correction)?;
+    Ok(z_to_p(z, alt_hyp))
+}
+
+/// McNemar's test for paired Bernoulli samples, using the Normal approximation.
+/// With [`AltHyp::Ne`], equivalent to `R`'s `mcnemar.test` function.
+///
+/// See [`mcnemar_z`].
+///
+/// Arguments:
+/// - `n_sf`: number of pairs with a success in the first sample and a failure in the second sample.
+/// - `n_fs`: number of pairs with a failure in the first sample and a success in the second sample.
+/// - `continuity_correction`: whether to apply the continuity correction.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n_sf + n_fs == 0`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn mcnemar_z_test(
+    n_sf: u64,
+    n_fs: u64,
+    continuity_correction: bool,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p_value = mcnemar_z_p(n_sf, n_fs, continuity_correction, alt_hyp)?;
+    let test_res = HypTestResult::new(p_value, alpha, alt_hyp);
+    Ok(test_res)
+}
+
+/// Exact p-value of McNemar's test for paired Bernoulli samples, under the hypothesis that the probabilities of
+/// success in the first and second samples are equal.
+///
+/// Conditional on the number of discordant pairs, `n_sf` has a Binomial distribution with probability of
+/// success `0.5` under the null hypothesis, so this is [`exact_binomial_p`] with `n = n_sf + n_fs`,
+/// `n_s = n_sf`, and `p0 = 0.5`.
+///
+/// Arguments:
+/// - `n_sf`: number of pairs with a success in the first sample and a failure in the second sample.
+/// - `n_fs`: number of pairs with a failure in the first sample and a success in the second sample.
+/// - `alt_hyp`: alternative hypothesis.
+///
+/// # Errors
+///
+/// Returns an error if `n_sf + n_fs == 0`.
+pub fn exact_mcnemar_p(n_sf: u64, n_fs: u64, alt_hyp: AltHyp) -> StatsResult<f64> {
+    check_discordant(n_sf, n_fs)?;
+    exact_binomial_p(n_sf + n_fs, n_sf, 0.5, alt_hyp)
+}
+
+/// Exact McNemar's test for paired Bernoulli samples.
+///
+/// See [`exact_mcnemar_p`].
+///
+/// Arguments:
+/// - `n_sf`: number of pairs with a success in the first sample and a failure in the second sample.
+/// - `n_fs`: number of pairs with a failure in the first sample and a success in the second sample.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n_sf + n_fs == 0`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn exact_mcnemar_test(
+    n_sf: u64,
+    n_fs: u64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p_value = exact_mcnemar_p(n_sf, n_fs, alt_hyp)?;
+    let test_res = HypTestResult::new(p_value, alpha, alt_hyp);
+    Ok(test_res)
+}
+
+fn check_paired_counts(n: u64, n_sf: u64, n_fs: u64) -> StatsResult<()> {
+    if n == 0 {
+        return Err(StatsError("arg `n` must be positive"));
+    }
+    if n < n_sf + n_fs {
+        return Err(StatsError(
+            "arg `n` must be greater than or equal to `n_sf + n_fs`",
+        ));
+    }
+    Ok(())
+}
+
+/// Wald interval for the difference of paired proportions, with real-valued counts to support adjustments.
+fn paired_proportion_wald_ci_f64(
+    n: f64,
+    n_sf: f64,
+    n_fs: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    check_alpha_in_open_0_1(alpha)?; // need this guard because `alpha / 2.` below masks errors
+    let z_alpha = if let AltHyp::Ne = alt_hyp {
+        z_alpha(alpha / 2.)?
+    } else {
+        z_alpha(alpha)?
+    };
+
+    let d_p_hat = (n_sf - n_fs) / n;
+    let var = ((n_sf + n_fs) - (n_sf - n_fs).powi(2) / n) / n.powi(2);
+    let delta = z_alpha * var.sqrt();
+
+    Ok(diff_ci(d_p_hat, delta, delta, alt_hyp))
+}
+
+/// Wald confidence interval for the difference of the probabilities of success (`p_x - p_y`)
+/// of two paired Bernoulli samples.
+///
+/// Arguments:
+/// - `n`: number of pairs.
+/// - `n_sf`: number of pairs with a success in the first sample and a failure in the second sample.
+/// - `n_fs`: number of pairs with a failure in the first sample and a success in the second sample.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_sf + n_fs`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn paired_proportion_wald_alt_hyp_ci(
+    n: u64,
+    n_sf: u64,
+    n_fs: u64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    check_paired_counts(n, n_sf, n_fs)?;
+    paired_proportion_wald_ci_f64(n as f64, n_sf as f64, n_fs as f64, alt_hyp, alpha)
+}
+
+/// Wald confidence interval for the difference of the probabilities of success (`p_x - p_y`)
+/// of two paired Bernoulli samples, with the alternative hypothesis of inequality (two-sided).
+///
+/// Arguments:
+/// - `n`: number of pairs.
+/// - `n_sf`: number of pairs with a success in the first sample and a failure in the second sample.
+/// - `n_fs`: number of pairs with a failure in the first sample and a success in the second sample.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_sf + n_fs`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn paired_proportion_wald_ci(n: u64, n_sf: u64, n_fs: u64, alpha: f64) -> StatsResult<Ci> {
+    paired_proportion_wald_alt_hyp_ci(n, n_sf, n_fs, AltHyp::Ne, alpha)
+}
+
+/// Agresti–Min confidence interval for the difference of the probabilities of success (`p_x - p_y`)
+/// of two paired Bernoulli samples. It is the Wald interval computed after adding `0.5` to each of the four
+/// cells of the 2x2 table of paired outcomes.
+///
+/// Reference: A. Agresti and Y. Min, "Simple improved confidence intervals for comparing matched proportions",
+/// Statistics in Medicine 24, 2005.
+///
+/// Arguments:
+/// - `n`: number of pairs.
+/// - `n_sf`: number of pairs with a success in the first sample and a failure in the second sample.
+/// - `n_fs`: number of pairs with a failure in the first sample and a success in the second sample.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_sf + n_fs`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn paired_proportion_am_alt_hyp_ci(
+    n: u64,
+    n_sf: u64,
+    n_fs: u64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    check_paired_counts(n, n_sf, n_fs)?;
+    paired_proportion_wald_ci_f64(
+        n as f64 + 2.,
+        n_sf as f64 + 0.5,
+        n_fs as f64 + 0.5,
+        alt_hyp,
+        alpha,
+    )
+}
+
+/// Agresti–Min confidence interval for the difference of the probabilities of success (`p_x - p_y`)
+/// of two paired Bernoulli samples, with the alternative hypothesis of inequality (two-sided).
+///
+/// See [`paired_proportion_am_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `n`: number of pairs.
+/// - `n_sf`: number of pairs with a success in the first sample and a failure in the second sample.
+/// - `n_fs`: number of pairs with a failure in the first sample and a success in the second sample.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0` or `n < n_sf + n_fs`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn paired_proportion_am_ci(n: u64, n_sf: u64, n_fs: u64, alpha: f64) -> StatsResult<Ci> {
+    paired_proportion_am_alt_hyp_ci(n, n_sf, n_fs, AltHyp::Ne, alpha)
+}
+
+fn check_p1_in_open_0_1(p1: f64) -> StatsResult<()> {
+    if 0.0 < p1 && p1 < 1.0 {
+        return Ok(());
+    }
+    Err(StatsError("arg `p1` must be in interval (0, 1)"))
+}
+
+fn check_power_in_open_0_1(power: f64) -> StatsResult<()> {
+    if 0.0 < power && power < 1.0 {
+        return Ok(());
+    }
+    Err(StatsError("arg `power` must be in interval (0, 1)"))
+}
+
+/// Checks that the power of a test of `alt_hyp` tends to `1` as the sample size grows when the true value of the
+/// tested parameter is `actual` and its value under the null hypothesis is `null`.
+fn check_power_attainable(null: f64, actual: f64, alt_hyp: AltHyp) -> StatsResult<()> {
+    match alt_hyp {
+        AltHyp::Lt if actual >= null => Err(StatsError(
+            "power target is unattainable: for `AltHyp::Lt`, the alternative proportion must be less than the null one",
+        )),
+        AltHyp::Gt if actual <= null => Err(StatsError(
+            "power target is unattainable: for `AltHyp::Gt`, the alternative proportion must be greater than the null one",
+        )),
+        AltHyp::Ne if actual == null => Err(StatsError(
+            "power target is unattainable: for `AltHyp::Ne`, the alternative proportion must differ from the null one",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Power of a z-test whose statistic estimates a quantity equal to `0` under the null hypothesis and to `delta`
+/// under the alternative, with standard deviation `sd0` under the null hypothesis and `sd1` under the alternative.
+fn z_test_power(delta: f64, sd0: f64, sd1: f64, alt_hyp: AltHyp, alpha: f64) -> StatsResult<f64> {
+    check_alpha_in_open_0_1(alpha)?; // need this guard because `alpha / 2.` below masks errors
+    let prob_lt =
+        |a: f64| -> StatsResult<f64> { Ok(z_to_p((-z_alpha(a)? * sd0 - delta) / sd1, AltHyp::Lt)) };
+    let prob_gt =
+        |a: f64| -> StatsResult<f64> { Ok(z_to_p((z_alpha(a)? * sd0 - delta) / sd1, AltHyp::Gt)) };
+    let power = match alt_hyp {
+        AltHyp::Lt => prob_lt(alpha)?,
+        AltHyp::Gt => prob_gt(alpha)?,
+        AltHyp::Ne => prob_lt(alpha / 2.)? + prob_gt(alpha / 2.)?,
+    };
+    Ok(power)
+}
+
+/// Smallest sample size `n >= 1` with `power_fn(n) >= power`, searching from the approximation `n_start`,
+/// assuming that `power_fn` is non-decreasing.
+fn min_n_with_power(
+    n_start: f64,
+    power: f64,
+    power_fn: impl Fn(u64) -> StatsResult<f64>,
+) -> StatsResult<u64> {
+    let mut n = n_start.ceil().max(1.) as u64;
+    while n > 1 && power_fn(n - 1)? >= power {
+        n -= 1;
+    }
+    while power_fn(n)? < power {
+        n += 1;
+    }
+    Ok(n)
+}
+
+/// Power of [`one_proportion_z_test`] with `n` trials when the actual probability of success is `p1`.
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `p0`: probability of success under null hypothesis.
+/// - `p1`: actual probability of success.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0`.
+/// - `p0` or `p1` is not in interval `(0, 1)`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn one_proportion_z_power(
+    n: u64,
+    p0: f64,
+    p1: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<f64> {
+    if n == 0 {
+        return Err(StatsError("arg `n` must be positive"));
+    }
+    check_p0_in_open_0_1(p0)?;
+    check_p1_in_open_0_1(p1)?;
+    let n = n as f64;
+    let sd0 = (p0 * (1. - p0) / n).sqrt();
+    let sd1 = (p1 * (1. - p1) / n).sqrt();
+    z_test_power(p1 - p0, sd0, sd1, alt_hyp, alpha)
+}
+
+/// Smallest number of trials for which the power of [`one_proportion_z_test`] is at least `power` when the actual
+/// probability of success is `p1`.
+///
+/// Arguments:
+/// - `p0`: probability of success under null hypothesis.
+/// - `p1`: actual probability of success.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+/// - `power`: target power.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p0` or `p1` is not in interval `(0, 1)`.
+/// - `alpha` is not in interval `(0, 1)`.
+/// - `power` is not in interval `(0, 1)`.
+/// - The target is unattainable: `p1 >= p0` for [`AltHyp::Lt`], `p1 <= p0` for [`AltHyp::Gt`], or `p1 == p0`
+///   for [`AltHyp::Ne`].
+pub fn one_proportion_z_sample_size(
+    p0: f64,
+    p1: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+    power: f64,
+) -> StatsResult<u64> {
+    check_p0_in_open_0_1(p0)?;
+    check_p1_in_open_0_1(p1)?;
+    check_alpha_in_open_0_1(alpha)?;
+    check_power_in_open_0_1(power)?;
+    check_power_attainable(p0, p1, alt_hyp)?;
+
+    let z_a = if let AltHyp::Ne = alt_hyp {
+        z_alpha(alpha / 2.)?
+    } else {
+        z_alpha(alpha)?
+    };
+    let z_b = z_alpha(1. - power)?;
+    let n_start =
+        ((z_a * (p0 * (1. - p0)).sqrt() + z_b * (p1 * (1. - p1)).sqrt()) / (p1 - p0)).powi(2);
+    min_n_with_power(n_start, power, |n| {
+        one_proportion_z_power(n, p0, p1, alt_hyp, alpha)
+    })
+}
+
+/// Power of [`exact_binomial_test`] with `n` trials when the actual probability of success is `p1`, computed
+/// exactly from the Binomial distribution of the number of successes and the rejection region of the test.
+///
+/// Arguments:
+/// - `n`: number of trials.
+/// - `p0`: probability of success under null hypothesis.
+/// - `p1`: actual probability of success.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n == 0`.
+/// - `p0` or `p1` is not in interval `(0, 1)`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn exact_binomial_power(
+    n: u64,
+    p0: f64,
+    p1: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<f64> {
+    if n == 0 {
+        return Err(StatsError("arg `n` must be positive"));
+    }
+    check_p0_in_open_0_1(p0)?;
+    check_p1_in_open_0_1(p1)?;
+    check_alpha_in_open_0_1(alpha)?;
+
+    let rejects = |n_s: u64| -> bool {
+        exact_binomial_p(n, n_s, p0, alt_hyp).expect("args should be valid") <= alpha
+    };
+    Ok(binomial_power_of_region(n, p0, p1, alt_hyp, rejects))
+}
+
+/// Power with `n` trials when the actual probability of success is `p1`, for a test of `p0` with alternative
+/// hypothesis `alt_hyp` that rejects the null hypothesis for the numbers of successes for which `rejects` holds.
+fn binomial_power_of_region(
+    n: u64,
+    p0: f64,
+    p1: f64,
+    alt_hyp: AltHyp,
+    rejects: impl Fn(u64) -> bool,
+) -> f64 {
+    // The p-value is monotone on each side of the mode, so the rejection region consists of a lower tail
+    // `0..lo_end` and an upper tail `hi_start..=n`.
+    let mode = n as f64 * p0;
+    let (lo_end, hi_start) = match alt_hyp {
+        AltHyp::Lt => (first_true(0, n, |n_s| !rejects(n_s)), n + 1),
+        AltHyp::Gt => (0, first_true(0, n, &rejects)),
+        AltHyp::Ne => (
+            first_true(0, mode.floor() as u64, |n_s| !rejects(n_s)),
+            first_true(mode.ceil() as u64, n, &rejects),
+        ),
+    };
+
+    let binomial = Binomial::new(p1, n).expect("`p1` should be in interval (0, 1)");
+    let prob_lo = if lo_end == 0 {
+        0.
+    } else {
+        binomial.cdf(lo_end - 1)
+    };
+    let prob_hi = match hi_start {
+        0 => 1.,
+        _ if hi_start > n => 0.,
+        _ => binomial.sf(hi_start - 1),
+    };
+    prob_lo + prob_hi
+}
+
+/// Upper bound of [`exact_binomial_power`], for args already validated.
+///
+/// For [`AltHyp::Ne`], the p-value of each number of successes is computed as in [`exact_binomial_p`], but with
+/// the probabilities of the opposite tail taken from the CDF instead of summed one by one, which takes `O(log(n))`
+/// instead of `O(n)` time. It is compared with `alpha` with a relative slack that absorbs the rounding differences,
+/// so the resulting rejection region contains that of [`exact_binomial_test`]. For one-sided alternatives, whose
+/// exact power is fast to compute, it returns `1`.
+fn exact_binomial_power_upper_bound(n: u64, p0: f64, p1: f64, alt_hyp: AltHyp, alpha: f64) -> f64 {
+    let AltHyp::Ne = alt_hyp else {
+        return 1.;
+    };
+    let binomial = Binomial::new(p0, n).expect("`p0` should be in interval (0, 1)");
+    let rel_err = 1. + 1e-7;
+    let mode = n as f64 * p0;
+    let prob_ge = |k: u64| if k == 0 { 1. } else { 1. - binomial.cdf(k - 1) };
+    let p_ne = |n_s: u64| -> f64 {
+        let max_prob = binomial.pmf(n_s) * rel_err;
+        match (n_s as f64).total_cmp(&mode) {
+            Ordering::Equal => 1.,
+            Ordering::Less => {
+                // Probabilities decrease above the mode.
+                let start = first_true(mode.ceil() as u64, n, |i| binomial.pmf(i) <= max_prob);
+                let sum_prob = if start > n { 0. } else { prob_ge(start) };
+                binomial.cdf(n_s) + sum_prob
+            }
+            Ordering::Greater => {
+                // Probabilities increase below the mode.
+                let end = first_true(0, mode.floor() as u64, |i| binomial.pmf(i) > max_prob);
+                let sum_prob = if end == 0 { 0. } else { binomial.cdf(end - 1) };
+                prob_ge(n_s) + sum_prob
+            }
+        }
+    };
+    binomial_power_of_region(n, p0, p1, alt_hyp, |n_s| p_ne(n_s) <= alpha * (1. + 1e-9))
+}
+
+/// Smallest number of trials for which the power of [`exact_binomial_test`] is at least `power` when the actual
+/// probability of success is `p1`.
+///
+/// Due to the discreteness of the Binomial distribution, the exact power is not monotone in the number of trials
+/// (it has a saw-tooth shape), so some larger numbers of trials may have power below the target. The search scans
+/// the numbers of trials from `1`, skipping those for which a fast upper bound of the power is below the target, so
+/// it takes time roughly proportional to the result (about a second for `30_000` trials in an optimized build).
+///
+/// Arguments:
+/// - `p0`: probability of success under null hypothesis.
+/// - `p1`: actual probability of success.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+/// - `power`: target power.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p0` or `p1` is not in interval `(0, 1)`.
+/// - `alpha` is not in interval `(0, 1)`.
+/// - `power` is not in interval `(0, 1)`.
+/// - The target is unattainable: `p1 >= p0` for [`AltHyp::Lt`], `p1 <= p0` for [`AltHyp::Gt`], or `p1 == p0`
+///   for [`AltHyp::Ne`].
+pub fn exact_binomial_sample_size(
+    p0: f64,
+    p1: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+    power: f64,
+) -> StatsResult<u64> {
+    check_p0_in_open_0_1(p0)?;
+    check_p1_in_open_0_1(p1)?;
+    check_alpha_in_open_0_1(alpha)?;
+    check_power_in_open_0_1(power)?;
+    check_power_attainable(p0, p1, alt_hyp)?;
+
+    // The exact power is only computed where its fast upper bound reaches the target.
+    let mut n = 1;
+    while exact_binomial_power_upper_bound(n, p0, p1, alt_hyp, alpha) < power
+        || exact_binomial_power(n, p0, p1, alt_hyp, alpha)? < power
+    {
+        n += 1;
+    }
+    Ok(n)
+}
+
+/// Power of [`two_proportion_z_test`] without continuity correction, with `n_x` and `n_y` trials in the first and
+/// second samples, when the actual probabilities of success are `p_x` and `p_y`.
+/// With `n_x == n_y`, equivalent to `R`'s `power.prop.test` function with `strict = TRUE`.
+///
+/// Arguments:
+/// - `n_x`: number of trials in the first sample.
+/// - `n_y`: number of trials in the second sample.
+/// - `p_x`: actual probability of success in the first sample.
+/// - `p_y`: actual probability of success in the second sample.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n_x == 0` or `n_y == 0`.
+/// - `p_x` or `p_y` is not in interval `(0, 1)`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn two_proportion_z_power(
+    n_x: u64,
+    n_y: u64,
+    p_x: f64,
+    p_y: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<f64> {
+    if n_x == 0 || n_y == 0 {
+        return Err(StatsError("args `n_x` and `n_y` must be positive"));
+    }
+    if !(0. < p_x && p_x < 1. && 0. < p_y && p_y < 1.) {
+        return Err(StatsError(
+            "args `p_x` and `p_y` must be in interval (0, 1)",
+        ));
+    }
+    let (n_x, n_y) = (n_x as f64, n_y as f64);
+    let p_pooled = (n_x * p_x + n_y * p_y) / (n_x + n_y);
+    let sd0 = (p_pooled * (1. - p_pooled) * (1. / n_x + 1. / n_y)).sqrt();
+    let sd1 = (p_x * (1. - p_x) / n_x + p_y * (1. - p_y) / n_y).sqrt();
+    z_test_power(p_x - p_y, sd0, sd1, alt_hyp, alpha)
+}
+
+/// Smallest number of trials per sample, with equal sample sizes, for which the power of [`two_proportion_z_test`]
+/// without continuity correction is at least `power` when the actual probabilities of success are `p_x` and `p_y`.
 ///
 /// Arguments:
-/// - `n`: number of trials.
-/// - `n_s`: number of successes (`1`s) observed.
-/// - `p0`: probability of success under null hypothesis.
+/// - `p_x`: actual probability of success in the first sample.
+/// - `p_y`: actual probability of success in the second sample.
 /// - `alt_hyp`: alternative hypothesis.
 /// - `alpha`: confidence level = `1 - alpha`.
+/// - `power`: target power.
 ///
 /// # Errors
 ///
 /// Returns an error in any of these conditions:
-/// - `n == 0` or `n < n_s`.
-/// - `p0` is not in interval `[0, 1]`.
+/// - `p_x` or `p_y` is not in interval `(0, 1)`.
 /// - `alpha` is not in interval `(0, 1)`.
-pub fn exact_binomial_test(
-    n: u64,
-    n_s: u64,
-    p0: f64,
+/// - `power` is not in interval `(0, 1)`.
+/// - The target is unattainable: `p_x >= p_y` for [`AltHyp::Lt`], `p_x <= p_y` for [`AltHyp::Gt`], or
+///   `p_x == p_y` for [`AltHyp::Ne`].
+pub fn two_proportion_z_sample_size(
+    p_x: f64,
+    p_y: f64,
     alt_hyp: AltHyp,
     alpha: f64,
-) -> StatsResult<HypTestResult> {
+    power: f64,
+) -> StatsResult<u64> {
+    if !(0. < p_x && p_x < 1. && 0. < p_y && p_y < 1.) {
+        return Err(StatsError(
+            "args `p_x` and `p_y` must be in interval (0, 1)",
+        ));
+    }
     check_alpha_in_open_0_1(alpha)?;
+    check_power_in_open_0_1(power)?;
+    check_power_attainable(p_y, p_x, alt_hyp)?;
 
-    let p_value = exact_binomial_p(n, n_s, p0, alt_hyp)?;
-    let test_res = HypTestResult::new(p_value, alpha, alt_hyp);
-    Ok(test_res)
+    let z_a = if let AltHyp::Ne = alt_hyp {
+        z_alpha(alpha / 2.)?
+    } else {
+        z_alpha(alpha)?
+    };
+    let z_b = z_alpha(1. - power)?;
+    let p_mean = (p_x + p_y) / 2.;
+    let sd0 = (2. * p_mean * (1. - p_mean)).sqrt();
+    let sd1 = (p_x * (1. - p_x) + p_y * (1. - p_y)).sqrt();
+    let n_start = ((z_a * sd0 + z_b * sd1) / (p_x - p_y)).powi(2);
+    min_n_with_power(n_start, power, |n| {
+        two_proportion_z_power(n, n, p_x, p_y, alt_hyp, alpha)
+    })
 }
 
 #[cfg(test)]
@@ -800,4 +2585,868 @@ mod test {
 
         check_binomial_no_z(n, n_s, p0, alt_hyp, exp_p, exp_cp_ci, exp_accept_hyp);
     }
+
+    //==================
+    // Two-sample proportions.
+    // Expected p-values and Wald intervals follow R's prop.test function; Newcombe and Agresti-Caffo intervals
+    // were computed from their definitions. Newcombe's method 10 example gives (0.0524, 0.3339) for 56/70 - 48/80.
+
+    #[allow(clippy::too_many_arguments)]
+    fn check_two_proportion(
+        (n_x, n_s_x, n_y, n_s_y): (u64, u64, u64, u64),
+        continuity_correction: bool,
+        alt_hyp: AltHyp,
+        exp_z: f64,
+        exp_p: f64,
+        exp_wald_ci: Ci,
+        exp_newcombe_ci: Ci,
+        exp_ac_ci: Ci,
+        exp_accept_hyp: Hyp,
+    ) {
+        let z = two_proportion_z(n_x, n_s_x, n_y, n_s_y, continuity_correction).unwrap();
+        let res = two_proportion_z_test(
+            n_x,
+            n_s_x,
+            n_y,
+            n_s_y,
+            continuity_correction,
+            alt_hyp,
+            ALPHA,
+        )
+        .unwrap();
+        let p = res.p();
+        let wald_ci = two_proportion_wald_alt_hyp_ci(
+            n_x,
+            n_s_x,
+            n_y,
+            n_s_y,
+            continuity_correction,
+            alt_hyp,
+            ALPHA,
+        )
+        .unwrap();
+        let newcombe_ci =
+            two_proportion_newcombe_alt_hyp_ci(n_x, n_s_x, n_y, n_s_y, alt_hyp, ALPHA).unwrap();
+        let ac_ci = two_proportion_ac_alt_hyp_ci(n_x, n_s_x, n_y, n_s_y, alt_hyp, ALPHA).unwrap();
+
+        if alt_hyp == AltHyp::Ne {
+            assert_eq!(
+                wald_ci,
+                two_proportion_wald_ci(n_x, n_s_x, n_y, n_s_y, continuity_correction, ALPHA)
+                    .unwrap()
+            );
+            assert_eq!(
+                newcombe_ci,
+                two_proportion_newcombe_ci(n_x, n_s_x, n_y, n_s_y, ALPHA).unwrap()
+            );
+            assert_eq!(
+                ac_ci,
+                two_proportion_ac_ci(n_x, n_s_x, n_y, n_s_y, ALPHA).unwrap()
+            );
+        }
+
+        let ctx = format!(
+            "n_x={n_x}, n_s_x={n_s_x}, n_y={n_y}, n_s_y={n_s_y}, cc={continuity_correction}, alt_hyp={alt_hyp:?}"
+        );
+
+        assert!(exp_z.approx_eq(z, EPSILON), "{ctx} -> exp_z={exp_z}, z={z}");
+        assert!(exp_p.approx_eq(p, EPSILON), "{ctx} -> exp_p={exp_p}, p={p}");
+        for (name, exp_ci, ci) in [
+            ("wald", exp_wald_ci, wald_ci),
+            ("newcombe", exp_newcombe_ci, newcombe_ci),
+            ("ac", exp_ac_ci, ac_ci),
+        ] {
+            assert!(
+                exp_ci.0.approx_eq(ci.0, EPSILON),
+                "{ctx} -> {name}: exp_ci.0={}, ci.0={}",
+                exp_ci.0,
+                ci.0
+            );
+            assert!(
+                exp_ci.1.approx_eq(ci.1, EPSILON),
+                "{ctx} -> {name}: exp_ci.1={}, ci.1={}",
+                exp_ci.1,
+                ci.1
+            );
+        }
+
+        assert_eq!(ALPHA, res.alpha(), "{ctx} -> res.alpha");
+        assert_eq!(alt_hyp, res.alt_hyp(), "{ctx} -> res.alt_hyp");
+        assert_eq!(exp_accept_hyp, res.accepted(), "{ctx} -> res.accepted");
+    }
+
+    #[test]
+    fn test_two_proportion_56_70_48_80() {
+        let counts = (70, 56, 80, 48);
+        let exp_newcombe_ne = Ci(0.0524315, 0.3338727);
+        let exp_ac_ne = Ci(0.0524529, 0.3357585);
+
+        check_two_proportion(
+            counts,
+            false,
+            AltHyp::Lt,
+            2.6501720,
+            0.9959775,
+            Ci(-1., 0.3195857),
+            Ci(-1., 0.3136446),
+            Ci(-1., 0.3129844),
+            Hyp::Null,
+        );
+        check_two_proportion(
+            counts,
+            false,
+            AltHyp::Ne,
+            2.6501720,
+            0.008045081,
+            Ci(0.0575049, 0.3424951),
+            exp_newcombe_ne,
+            exp_ac_ne,
+            Hyp::Alt(AltHyp::Ne),
+        );
+        check_two_proportion(
+            counts,
+            false,
+            AltHyp::Gt,
+            2.6501720,
+            0.004022541,
+            Ci(0.0804143, 1.),
+            Ci(0.0765642, 1.),
+            Ci(0.0752269, 1.),
+            Hyp::Alt(AltHyp::Gt),
+        );
+        check_two_proportion(
+            counts,
+            true,
+            AltHyp::Ne,
+            2.4727051,
+            0.01340948,
+            Ci(0.0441120, 0.3558880),
+            exp_newcombe_ne,
+            exp_ac_ne,
+            Hyp::Alt(AltHyp::Ne),
+        );
+    }
+
+    #[test]
+    fn test_two_proportion_15_50_25_50() {
+        let counts = (50, 15, 50, 25);
+
+        check_two_proportion(
+            counts,
+            false,
+            AltHyp::Ne,
+            -2.0412415,
+            0.04122683,
+            Ci(-0.3879931, -0.0120069),
+            Ci(-0.3723663, -0.0083125),
+            Ci(-0.3772538, -0.0073616),
+            Hyp::Alt(AltHyp::Ne),
+        );
+        check_two_proportion(
+            counts,
+            true,
+            AltHyp::Lt,
+            -1.8371173,
+            0.03309629,
+            Ci(-1., -0.0222312),
+            Ci(-1., -0.0388592),
+            Ci(-1., -0.0370960),
+            Hyp::Alt(AltHyp::Lt),
+        );
+        check_two_proportion(
+            counts,
+            true,
+            AltHyp::Ne,
+            -1.8371173,
+            0.06619258,
+            Ci(-0.4079931, 0.0079931),
+            Ci(-0.3723663, -0.0083125),
+            Ci(-0.3772538, -0.0073616),
+            Hyp::Null,
+        );
+        check_two_proportion(
+            counts,
+            true,
+            AltHyp::Gt,
+            -1.8371173,
+            0.9669037,
+            Ci(-0.3777688, 1.),
+            Ci(-0.3472473, 1.),
+            Ci(-0.3475194, 1.),
+            Hyp::Null,
+        );
+    }
+
+    #[test]
+    fn test_two_proportion_9_10_3_10() {
+        let counts = (10, 9, 10, 3);
+
+        check_two_proportion(
+            counts,
+            false,
+            AltHyp::Ne,
+            2.7386128,
+            0.006169899,
+            Ci(0.2605243, 0.9394757),
+            Ci(0.1705227, 0.8090180),
+            Ci(0.1600008, 0.8399992),
+            Hyp::Alt(AltHyp::Ne),
+        );
+        // Wald interval truncated at `1`.
+        check_two_proportion(
+            counts,
+            true,
+            AltHyp::Ne,
+            2.2821773,
+            0.02247887,
+            Ci(0.1605243, 1.),
+            Ci(0.1705227, 0.8090180),
+            Ci(0.1600008, 0.8399992),
+            Hyp::Alt(AltHyp::Ne),
+        );
+    }
+
+    // McNemar expected values follow `R`'s mcnemar.test function for the two-sided test with continuity
+    // correction. Other expected values were computed from their definitions. Agresti's presidential approval
+    // example (n = 1600, n_sf = 150, n_fs = 86) gives a Wald interval of (0.02, 0.06) for the difference.
+
+    #[allow(clippy::too_many_arguments)]
+    fn check_mcnemar(
+        (n, n_sf, n_fs): (u64, u64, u64),
+        continuity_correction: bool,
+        alt_hyp: AltHyp,
+        exp_z: f64,
+        exp_p: f64,
+        exp_exact_p: f64,
+        exp_wald_ci: Ci,
+        exp_am_ci: Ci,
+        exp_accept_hyp: Hyp,
+    ) {
+        let z = mcnemar_z(n_sf, n_fs, continuity_correction).unwrap();
+        let res = mcnemar_z_test(n_sf, n_fs, continuity_correction, alt_hyp, ALPHA).unwrap();
+        let p = res.p();
+        let exact_p = exact_mcnemar_p(n_sf, n_fs, alt_hyp).unwrap();
+        let exact_res = exact_mcnemar_test(n_sf, n_fs, alt_hyp, ALPHA).unwrap();
+        let wald_ci = paired_proportion_wald_alt_hyp_ci(n, n_sf, n_fs, alt_hyp, ALPHA).unwrap();
+        let am_ci = paired_proportion_am_alt_hyp_ci(n, n_sf, n_fs, alt_hyp, ALPHA).unwrap();
+
+        if alt_hyp == AltHyp::Ne {
+            assert_eq!(
+                wald_ci,
+                paired_proportion_wald_ci(n, n_sf, n_fs, ALPHA).unwrap()
+            );
+            assert_eq!(
+                am_ci,
+                paired_proportion_am_ci(n, n_sf, n_fs, ALPHA).unwrap()
+            );
+        }
+
+        let ctx = format!(
+            "n={n}, n_sf={n_sf}, n_fs={n_fs}, cc={continuity_correction}, alt_hyp={alt_hyp:?}"
+        );
+
+        assert!(exp_z.approx_eq(z, EPSILON), "{ctx} -> exp_z={exp_z}, z={z}");
+        assert!(exp_p.approx_eq(p, EPSILON), "{ctx} -> exp_p={exp_p}, p={p}");
+        assert!(
+            exp_exact_p.approx_eq(exact_p, EPSILON),
+            "{ctx} -> exp_exact_p={exp_exact_p}, exact_p={exact_p}"
+        );
+        assert_eq!(exact_p, exact_res.p(), "{ctx} -> exact_res.p");
+        for (name, exp_ci, ci) in [("wald", exp_wald_ci, wald_ci), ("am", exp_am_ci, am_ci)] {
+            assert!(
+                exp_ci.0.approx_eq(ci.0, EPSILON),
+                "{ctx} -> {name}: exp_ci.0={}, ci.0={}",
+                exp_ci.0,
+                ci.0
+            );
+            assert!(
+                exp_ci.1.approx_eq(ci.1, EPSILON),
+                "{ctx} -> {name}: exp_ci.1={}, ci.1={}",
+                exp_ci.1,
+                ci.1
+            );
+        }
+
+        assert_eq!(ALPHA, res.alpha(), "{ctx} -> res.alpha");
+        assert_eq!(alt_hyp, res.alt_hyp(), "{ctx} -> res.alt_hyp");
+        assert_eq!(exp_accept_hyp, res.accepted(), "{ctx} -> res.accepted");
+        assert_eq!(alt_hyp, exact_res.alt_hyp(), "{ctx} -> exact_res.alt_hyp");
+    }
+
+    #[test]
+    fn test_mcnemar_1600_150_86() {
+        let counts = (1600, 150, 86);
+        let exp_wald_ne = Ci(0.0212839, 0.0587161);
+        let exp_am_ne = Ci(0.0212172, 0.0586829);
+
+        check_mcnemar(
+            counts,
+            true,
+            AltHyp::Ne,
+            4.1009507,
+            4.114562e-5,
+            3.715936e-5,
+            exp_wald_ne,
+            exp_am_ne,
+            Hyp::Alt(AltHyp::Ne),
+        );
+        check_mcnemar(
+            counts,
+            false,
+            AltHyp::Ne,
+            4.1660452,
+            3.099293e-5,
+            3.715936e-5,
+            exp_wald_ne,
+            exp_am_ne,
+            Hyp::Alt(AltHyp::Ne),
+        );
+        check_mcnemar(
+            counts,
+            false,
+            AltHyp::Lt,
+            4.1660452,
+            0.9999845,
+            0.9999896,
+            Ci(-1., 0.0557071),
+            Ci(-1., 0.0556712),
+            Hyp::Null,
+        );
+        check_mcnemar(
+            counts,
+            false,
+            AltHyp::Gt,
+            4.1660452,
+            1.549647e-5,
+            1.857968e-5,
+            Ci(0.0242929, 1.),
+            Ci(0.0242289, 1.),
+            Hyp::Alt(AltHyp::Gt),
+        );
+    }
+
+    #[test]
+    fn test_mcnemar_30_2_8() {
+        let counts = (30, 2, 8);
+
+        check_mcnemar(
+            counts,
+            false,
+            AltHyp::Ne,
+            -1.8973666,
+            0.05777957,
+            0.109375,
+            Ci(-0.3938064, -0.0061936),
+            Ci(-0.3799716, 0.0049716),
+            Hyp::Null,
+        );
+        check_mcnemar(
+            counts,
+            true,
+            AltHyp::Lt,
+            -1.5811388,
+            0.05692315,
+            0.0546875,
+            Ci(-1., -0.0373525),
+            Ci(-1., -0.0259727),
+            Hyp::Null,
+        );
+        check_mcnemar(
+            counts,
+            false,
+            AltHyp::Lt,
+            -1.8973666,
+            0.02888979,
+            0.0546875,
+            Ci(-1., -0.0373525),
+            Ci(-1., -0.0259727),
+            Hyp::Alt(AltHyp::Lt),
+        );
+    }
+
+    #[test]
+    fn test_mcnemar_20_5_0() {
+        let counts = (20, 5, 0);
+
+        check_mcnemar(
+            counts,
+            true,
+            AltHyp::Gt,
+            1.7888544,
+            0.03681914,
+            0.03125,
+            Ci(0.0907377, 1.),
+            Ci(0.0623862, 1.),
+            Hyp::Alt(AltHyp::Gt),
+        );
+        check_mcnemar(
+            counts,
+            true,
+            AltHyp::Ne,
+            1.7888544,
+            0.07363827,
+            0.0625,
+            Ci(0.0602273, 0.4397727),
+            Ci(0.0307983, 0.4237471),
+            Hyp::Null,
+        );
+    }
+
+    #[test]
+    fn test_mcnemar_no_difference() {
+        // With continuity correction, z is `0` when the discordant counts are equal.
+        assert_eq!(0., mcnemar_z(7, 7, true).unwrap());
+        assert_eq!(1., mcnemar_z_p(7, 7, true, AltHyp::Ne).unwrap());
+        assert_eq!(1., exact_mcnemar_p(7, 7, AltHyp::Ne).unwrap());
+    }
+
+    // Expected values for the additional confidence interval methods were computed from their definitions with
+    // high precision (using the `mpmath` Python library). For example, the Blaker interval for 40 successes in 100
+    // trials is (0.3055, 0.5000), as reported by the `R` package `exactci`.
+
+    fn check_ci_method(
+        n: u64,
+        n_s: u64,
+        method: BinomialCiMethod,
+        exp_ci: Ci,
+        exp_lt_hi: f64,
+        exp_gt_lo: f64,
+    ) {
+        let ci = binomial_ci(n, n_s, method, ALPHA).unwrap();
+        let lt_ci = binomial_alt_hyp_ci(n, n_s, method, AltHyp::Lt, ALPHA).unwrap();
+        let gt_ci = binomial_alt_hyp_ci(n, n_s, method, AltHyp::Gt, ALPHA).unwrap();
+
+        assert_eq!(
+            ci,
+            binomial_alt_hyp_ci(n, n_s, method, AltHyp::Ne, ALPHA).unwrap()
+        );
+
+        let ctx = format!("n={n}, n_s={n_s}, method={method:?}");
+        for (name, exp, act) in [
+            ("ci.0", exp_ci.0, ci.0),
+            ("ci.1", exp_ci.1, ci.1),
+            ("lt_ci.0", 0., lt_ci.0),
+            ("lt_ci.1", exp_lt_hi, lt_ci.1),
+            ("gt_ci.0", exp_gt_lo, gt_ci.0),
+            ("gt_ci.1", 1., gt_ci.1),
+        ] {
+            assert!(
+                exp.approx_eq(act, EPSILON),
+                "{ctx} -> {name}: exp={exp}, act={act}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ci_methods_20_4() {
+        use BinomialCiMethod::*;
+        let (n, n_s) = (20, 4);
+
+        check_ci_method(
+            n,
+            n_s,
+            AgrestiCoull,
+            Ci(0.0749115, 0.4217636),
+            0.3822695,
+            0.0892252,
+        );
+        check_ci_method(n, n_s, Wald, Ci(0.0246955, 0.3753045), 0.3471202, 0.0528798);
+        check_ci_method(
+            n,
+            n_s,
+            Arcsine,
+            Ci(0.0650694, 0.4112732),
+            0.3768508,
+            0.0835133,
+        );
+        check_ci_method(
+            n,
+            n_s,
+            Jeffreys,
+            Ci(0.0715201, 0.4082258),
+            0.3727393,
+            0.0873350,
+        );
+        check_ci_method(
+            n,
+            n_s,
+            LikelihoodRatio,
+            Ci(0.0668375, 0.4053643),
+            0.3693988,
+            0.0823922,
+        );
+        check_ci_method(n, n_s, MidP, Ci(0.0669723, 0.4148579), 0.3783418, 0.0830258);
+        check_ci_method(
+            n,
+            n_s,
+            Blaker,
+            Ci(0.0713539, 0.4218550),
+            0.4010281,
+            0.0713539,
+        );
+    }
+
+    #[test]
+    fn test_ci_methods_50_0() {
+        use BinomialCiMethod::*;
+        let (n, n_s) = (50, 0);
+
+        check_ci_method(n, n_s, AgrestiCoull, Ci(0., 0.0852161), 0.0614958, 0.);
+        check_ci_method(n, n_s, Wald, Ci(0., 0.), 0., 0.);
+        check_ci_method(n, n_s, Arcsine, Ci(0., 0.0496273), 0.0403996, 0.);
+        check_ci_method(n, n_s, Jeffreys, Ci(0., 0.0487585), 0.0375011, 0.);
+        check_ci_method(n, n_s, LikelihoodRatio, Ci(0., 0.0376861), 0.0266927, 0.);
+        check_ci_method(n, n_s, MidP, Ci(0., 0.0581551), 0.0450074, 0.);
+        check_ci_method(n, n_s, Blaker, Ci(0., 0.0641646), 0.0581551, 0.);
+    }
+
+    #[test]
+    fn test_ci_methods_10_10() {
+        use BinomialCiMethod::*;
+        let (n, n_s) = (10, 10);
+
+        check_ci_method(n, n_s, AgrestiCoull, Ci(0.6791127, 1.), 1., 0.7511977);
+        check_ci_method(n, n_s, Wald, Ci(1., 1.), 1., 1.);
+        check_ci_method(n, n_s, Arcsine, Ci(0.7720227, 1.), 1., 0.8124087);
+        check_ci_method(n, n_s, Jeffreys, Ci(0.7828037, 1.), 1., 0.8292269);
+        check_ci_method(n, n_s, LikelihoodRatio, Ci(0.8252467, 1.), 1., 0.8734738);
+        check_ci_method(n, n_s, MidP, Ci(0.7411344, 1.), 1., 0.7943282);
+        check_ci_method(n, n_s, Blaker, Ci(0.7170653, 1.), 1., 0.7411344);
+    }
+
+    #[test]
+    fn test_ci_methods_100_40() {
+        use BinomialCiMethod::*;
+        let (n, n_s) = (100, 40);
+
+        check_ci_method(
+            n,
+            n_s,
+            AgrestiCoull,
+            Ci(0.3093314, 0.4980673),
+            0.4822329,
+            0.3230356,
+        );
+        check_ci_method(n, n_s, Wald, Ci(0.3039818, 0.4960182), 0.4805810, 0.3194190);
+        check_ci_method(
+            n,
+            n_s,
+            Arcsine,
+            Ci(0.3072106, 0.4980789),
+            0.4823271,
+            0.3218411,
+        );
+        check_ci_method(
+            n,
+            n_s,
+            Jeffreys,
+            Ci(0.3079523, 0.4977433),
+            0.4819694,
+            0.3222517,
+        );
+        check_ci_method(
+            n,
+            n_s,
+            LikelihoodRatio,
+            Ci(0.3074111, 0.4976519),
+            0.4818382,
+            0.3217399,
+        );
+        check_ci_method(n, n_s, MidP, Ci(0.3074766, 0.4982385), 0.4823863, 0.3218466);
+        check_ci_method(n, n_s, Blaker, Ci(0.3055114, 0.5), 0.4870242, 0.3175260);
+    }
+
+    #[test]
+    fn test_ci_methods_7_3() {
+        use BinomialCiMethod::*;
+        let (n, n_s) = (7, 3);
+
+        check_ci_method(
+            n,
+            n_s,
+            AgrestiCoull,
+            Ci(0.1575212, 0.7502402),
+            0.7110680,
+            0.1858981,
+        );
+        check_ci_method(n, n_s, Wald, Ci(0.0619721, 0.7951707), 0.7362313, 0.1209116);
+        check_ci_method(
+            n,
+            n_s,
+            Arcsine,
+            Ci(0.1177744, 0.7870087),
+            0.7363284,
+            0.1587823,
+        );
+        check_ci_method(
+            n,
+            n_s,
+            Jeffreys,
+            Ci(0.1388642, 0.7654988),
+            0.7191819,
+            0.1746068,
+        );
+        check_ci_method(
+            n,
+            n_s,
+            LikelihoodRatio,
+            Ci(0.1287429, 0.7728133),
+            0.7254262,
+            0.1645227,
+        );
+        check_ci_method(n, n_s, MidP, Ci(0.1227408, 0.7840130), 0.7364809, 0.1588766);
+        check_ci_method(
+            n,
+            n_s,
+            Blaker,
+            Ci(0.1287564, 0.7746784),
+            0.7746784,
+            0.1287564,
+        );
+    }
+
+    #[test]
+    fn test_ci_methods_existing() {
+        use BinomialCiMethod::*;
+
+        for (n, n_s) in [(20, 4), (50, 0), (100, 40)] {
+            assert_eq!(
+                binomial_ws_ci(n, n_s, ALPHA).unwrap(),
+                binomial_ci(n, n_s, WilsonScore, ALPHA).unwrap()
+            );
+            assert_eq!(
+                binomial_cp_alt_hyp_ci(n, n_s, AltHyp::Gt, ALPHA).unwrap(),
+                binomial_alt_hyp_ci(n, n_s, ClopperPearson, AltHyp::Gt, ALPHA).unwrap()
+            );
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn check_exact_method(
+        n: u64,
+        n_s: u64,
+        p0: f64,
+        exp_midp_lt: f64,
+        exp_midp_ne: f64,
+        exp_midp_gt: f64,
+        exp_blaker_ne: f64,
+    ) {
+        use ExactBinomialMethod::*;
+        let ctx = format!("n={n}, n_s={n_s}, p0={p0}");
+
+        for (method, alt_hyp, exp_p) in [
+            (MidP, AltHyp::Lt, exp_midp_lt),
+            (MidP, AltHyp::Ne, exp_midp_ne),
+            (MidP, AltHyp::Gt, exp_midp_gt),
+            (Blaker, AltHyp::Ne, exp_blaker_ne),
+        ] {
+            let p = exact_binomial_method_p(n, n_s, p0, method, alt_hyp).unwrap();
+            let res = exact_binomial_method_test(n, n_s, p0, method, alt_hyp, ALPHA).unwrap();
+            assert!(
+                exp_p.approx_eq(p, EPSILON),
+                "{ctx}, method={method:?}, alt_hyp={alt_hyp:?} -> exp_p={exp_p}, p={p}"
+            );
+            assert_eq!(p, res.p());
+            assert_eq!(alt_hyp, res.alt_hyp());
+        }
+
+        for alt_hyp in [AltHyp::Lt, AltHyp::Ne, AltHyp::Gt] {
+            let exp_p = exact_binomial_p(n, n_s, p0, alt_hyp).unwrap();
+            assert_eq!(
+                exp_p,
+                exact_binomial_method_p(n, n_s, p0, Standard, alt_hyp).unwrap(),
+                "{ctx}, alt_hyp={alt_hyp:?}"
+            );
+            if alt_hyp != AltHyp::Ne {
+                assert_eq!(
+                    exp_p,
+                    exact_binomial_method_p(n, n_s, p0, Blaker, alt_hyp).unwrap(),
+                    "{ctx}, alt_hyp={alt_hyp:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_exact_methods() {
+        check_exact_method(20, 4, 0.5, 0.003598690, 0.007197380, 0.9964013, 0.01181793);
+        check_exact_method(20, 4, 0.1, 0.9119361, 0.1761278, 0.08806391, 0.2545300);
+        check_exact_method(10, 7, 0.5, 0.8867188, 0.2265625, 0.1132813, 0.34375);
+        check_exact_method(100, 40, 0.5, 0.02302203, 0.04604407, 0.9769780, 0.05688793);
+        check_exact_method(7, 3, 0.2, 0.909312, 0.181376, 0.090688, 0.148032);
+        check_exact_method(5, 0, 0.3, 0.084035, 0.16807, 0.915965, 0.33115);
+        check_exact_method(12, 12, 0.8, 0.9656403, 0.06871948, 0.03435974, 0.08812476);
+    }
+
+    #[test]
+    fn test_exact_methods_ci_inversion() {
+        // Values just inside the confidence interval are not rejected by the test and values just outside are.
+        use ExactBinomialMethod::*;
+        const DELTA: f64 = 1e-6;
+
+        for (n, n_s) in [(20, 4), (100, 40), (7, 3), (30, 29)] {
+            for method in [Standard, MidP, Blaker] {
+                for alt_hyp in [AltHyp::Lt, AltHyp::Ne, AltHyp::Gt] {
+                    if method == Standard && alt_hyp == AltHyp::Ne {
+                        // Clopper–Pearson does not invert the two-sided standard test.
+                        continue;
+                    }
+                    let Ci(lo, hi) =
+                        exact_binomial_method_alt_hyp_ci(n, n_s, method, alt_hyp, ALPHA).unwrap();
+                    if alt_hyp == AltHyp::Ne {
+                        assert_eq!(
+                            Ci(lo, hi),
+                            exact_binomial_method_ci(n, n_s, method, ALPHA).unwrap()
+                        );
+                    }
+                    let accepted = |p0: f64| {
+                        exact_binomial_method_test(n, n_s, p0, method, alt_hyp, ALPHA)
+                            .unwrap()
+                            .accepted()
+                            == Hyp::Null
+                    };
+                    let ctx = format!("n={n}, n_s={n_s}, method={method:?}, alt_hyp={alt_hyp:?}");
+                    if lo > 0. {
+                        assert!(accepted(lo + DELTA), "{ctx} -> lo={lo}");
+                        assert!(!accepted(lo - DELTA), "{ctx} -> lo={lo}");
+                    }
+                    if hi < 1. {
+                        assert!(accepted(hi - DELTA), "{ctx} -> hi={hi}");
+                        assert!(!accepted(hi + DELTA), "{ctx} -> hi={hi}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_one_proportion_z_power() {
+        // Expected values computed from the Normal approximation with high precision.
+        let cases = [
+            (AltHyp::Lt, 0.0000996062005, 0.6414994873),
+            (AltHyp::Ne, 0.5163234210, 0.5163234210),
+            (AltHyp::Gt, 0.6414994873, 0.0000996062005),
+        ];
+        for (alt_hyp, exp_power_06, exp_power_04) in cases {
+            let power_06 = one_proportion_z_power(100, 0.5, 0.6, alt_hyp, ALPHA).unwrap();
+            let power_04 = one_proportion_z_power(100, 0.5, 0.4, alt_hyp, ALPHA).unwrap();
+            assert!(
+                exp_power_06.approx_eq(power_06, EPSILON),
+                "{alt_hyp:?} -> power_06={power_06}"
+            );
+            assert!(
+                exp_power_04.approx_eq(power_04, EPSILON),
+                "{alt_hyp:?} -> power_04={power_04}"
+            );
+        }
+
+        assert_eq!(
+            194,
+            one_proportion_z_sample_size(0.5, 0.6, AltHyp::Ne, ALPHA, 0.8).unwrap()
+        );
+        assert_eq!(
+            153,
+            one_proportion_z_sample_size(0.5, 0.6, AltHyp::Gt, ALPHA, 0.8).unwrap()
+        );
+        assert_eq!(
+            161,
+            one_proportion_z_sample_size(0.3, 0.2, AltHyp::Lt, ALPHA, 0.9).unwrap()
+        );
+        assert!(one_proportion_z_sample_size(0.5, 0.6, AltHyp::Lt, ALPHA, 0.8).is_err());
+        assert!(one_proportion_z_sample_size(0.5, 0.4, AltHyp::Gt, ALPHA, 0.8).is_err());
+        assert!(one_proportion_z_sample_size(0.5, 0.5, AltHyp::Ne, ALPHA, 0.8).is_err());
+    }
+
+    #[test]
+    fn test_exact_binomial_power() {
+        // Expected values computed by summing, with high precision, the Binomial probabilities of all numbers of
+        // successes rejected by the exact test.
+        let cases = [
+            (AltHyp::Lt, 0.0000960820753, 0.00000514741057),
+            (AltHyp::Ne, 0.4620933823, 0.8649557123),
+            (AltHyp::Gt, 0.6225326761, 0.8649552971),
+        ];
+        for (alt_hyp, exp_power_100, exp_power_30) in cases {
+            let power_100 = exact_binomial_power(100, 0.5, 0.6, alt_hyp, ALPHA).unwrap();
+            let power_30 = exact_binomial_power(30, 0.2, 0.45, alt_hyp, ALPHA).unwrap();
+            assert!(
+                exp_power_100.approx_eq(power_100, EPSILON),
+                "{alt_hyp:?} -> power_100={power_100}"
+            );
+            assert!(
+                exp_power_30.approx_eq(power_30, EPSILON),
+                "{alt_hyp:?} -> power_30={power_30}"
+            );
+        }
+
+        assert_eq!(
+            199,
+            exact_binomial_sample_size(0.5, 0.6, AltHyp::Ne, ALPHA, 0.8).unwrap()
+        );
+        assert_eq!(
+            158,
+            exact_binomial_sample_size(0.5, 0.6, AltHyp::Gt, ALPHA, 0.8).unwrap()
+        );
+        assert_eq!(
+            164,
+            exact_binomial_sample_size(0.3, 0.2, AltHyp::Lt, ALPHA, 0.9).unwrap()
+        );
+        assert!(exact_binomial_sample_size(0.5, 0.6, AltHyp::Lt, ALPHA, 0.8).is_err());
+        assert!(exact_binomial_sample_size(0.5, 0.5, AltHyp::Ne, ALPHA, 0.8).is_err());
+
+        // Small effects, for which the fast upper bound of the power must skip most numbers of trials. Expected
+        // values from a scan of `exact_binomial_power` over all numbers of trials from `1`.
+        assert_eq!(
+            4927,
+            exact_binomial_sample_size(0.5, 0.52, AltHyp::Ne, ALPHA, 0.8).unwrap()
+        );
+        assert_eq!(
+            147,
+            exact_binomial_sample_size(0.3, 0.2, AltHyp::Ne, ALPHA, 0.8).unwrap()
+        );
+        assert_eq!(
+            22709,
+            exact_binomial_sample_size(0.1, 0.105, AltHyp::Gt, ALPHA, 0.8).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_two_proportion_z_power() {
+        // Expected values computed from the Normal approximation with high precision. For equal sample sizes, they
+        // agree with `R`'s power.prop.test function (e.g., `power.prop.test(p1 = .50, p2 = .75, power = .90)`
+        // gives `n = 76.65294`).
+        let cases = [
+            (AltHyp::Lt, 0.8339835234, 0.000428711894),
+            (AltHyp::Ne, 0.7401671935, 0.3471545557),
+            (AltHyp::Gt, 0.00000606612416, 0.4730627669),
+        ];
+        for (alt_hyp, exp_power_eq, exp_power_ne) in cases {
+            let power_eq = two_proportion_z_power(50, 50, 0.5, 0.75, alt_hyp, ALPHA).unwrap();
+            let power_ne = two_proportion_z_power(120, 80, 0.3, 0.2, alt_hyp, ALPHA).unwrap();
+            assert!(
+                exp_power_eq.approx_eq(power_eq, EPSILON),
+                "{alt_hyp:?} -> power_eq={power_eq}"
+            );
+            assert!(
+                exp_power_ne.approx_eq(power_ne, EPSILON),
+                "{alt_hyp:?} -> power_ne={power_ne}"
+            );
+        }
+
+        assert_eq!(
+            77,
+            two_proportion_z_sample_size(0.5, 0.75, AltHyp::Ne, ALPHA, 0.9).unwrap()
+        );
+        assert_eq!(
+            231,
+            two_proportion_z_sample_size(0.2, 0.3, AltHyp::Lt, ALPHA, 0.8).unwrap()
+        );
+        assert_eq!(
+            3026,
+            two_proportion_z_sample_size(0.12, 0.1, AltHyp::Gt, ALPHA, 0.8).unwrap()
+        );
+        assert!(two_proportion_z_sample_size(0.3, 0.2, AltHyp::Lt, ALPHA, 0.8).is_err());
+        assert!(two_proportion_z_sample_size(0.1, 0.12, AltHyp::Gt, ALPHA, 0.8).is_err());
+        assert!(two_proportion_z_sample_size(0.2, 0.2, AltHyp::Ne, ALPHA, 0.8).is_err());
+    }
 }
@@ -0,0 +1,477 @@
+//! Bootstrap confidence intervals for arbitrary statistics, such as high percentiles or ratios of medians,
+//! that have no closed-form sampling distribution.
+//!
+//! The statistic is supplied as a closure over a slice (or over two slices, for two-sample statistics), and the
+//! interval is computed with one of the methods in [`BootstrapCiMethod`]. Resampling uses the seedable
+//! [`SeededRng`], so results are reproducible given a seed.
+//!
+//! This module is included by default. However, if `default-features = false` is specified in the dependency
+//! declaration for this library, then inclusion of this module is gated by feature "**bootstrap**".
+//!
+//! # Example
+//!
+//! ```
+#![doc = include_str!("../examples/bootstrap.rs")]
+//! ```
+
+use crate::core::{Ci, SeededRng, StatsError, StatsResult, check_alpha_in_open_0_1};
+use statrs::distribution::{ContinuousCDF, Normal};
+
+/// Methods for computing bootstrap confidence intervals.
+///
+/// Reference: Davison, A. C. & Hinkley, D. V. (1997). *Bootstrap Methods and their Application*, chapter 5.
+/// Cambridge University Press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BootstrapCiMethod {
+    /// Percentile interval: the `alpha/2` and `1 - alpha/2` quantiles of the bootstrap replicates.
+    Percentile,
+    /// Basic (reverse percentile) interval: the percentile interval reflected about the statistic's value.
+    Basic,
+    /// Studentized (bootstrap-t) interval, where the standard error of each replicate is estimated with a
+    /// nested bootstrap of `n_inner` resamples.
+    Studentized {
+        /// Number of nested resamples per replicate.
+        n_inner: usize,
+    },
+    /// Bias-corrected and accelerated (BCa) interval, with the acceleration estimated by the jackknife.
+    Bca,
+}
+
+/// Resamples `src` with replacement into `dst`.
+fn resample_into(rng: &mut SeededRng, src: &[f64], dst: &mut Vec<f64>) {
+    dst.clear();
+    dst.extend((0..src.len()).map(|_| src[rng.below(src.len() as u64) as usize]));
+}
+
+/// Sample standard deviation of `values`.
+fn sd(values: &[f64]) -> f64 {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.)).sqrt()
+}
+
+/// Quantile of sorted `values` by linear interpolation between order statistics (type 7 in `R`'s `quantile`).
+fn quantile_sorted(values: &[f64], q: f64) -> f64 {
+    let h = (values.len() - 1) as f64 * q;
+    let lo = h.floor() as usize;
+    let hi = (lo + 1).min(values.len() - 1);
+    values[lo] + (h - lo as f64) * (values[hi] - values[lo])
+}
+
+fn check_samples(samples: &[&[f64]]) -> StatsResult<()> {
+    if samples.iter().any(|s| s.is_empty()) {
+        return Err(StatsError("sample size must be positive"));
+    }
+    Ok(())
+}
+
+/// Bootstrap replicates of `stat` over independent `samples`, each resampled separately. If `n_inner > 0`,
+/// also returns the nested bootstrap standard error of each replicate.
+fn replicates(
+    samples: &[&[f64]],
+    stat: &dyn Fn(&[&[f64]]) -> f64,
+    n_resamples: usize,
+    n_inner: usize,
+    seed: u64,
+) -> (Vec<f64>, Vec<f64>) {
+    let mut rng = SeededRng::new(seed);
+    let mut bufs = vec![Vec::new(); samples.len()];
+    let mut inner_bufs = vec![Vec::new(); samples.len()];
+    let mut inner_reps = Vec::with_capacity(n_inner);
+    let mut reps = Vec::with_capacity(n_resamples);
+    let mut ses = Vec::with_capacity(if n_inner > 0 { n_resamples } else { 0 });
+
+    for _ in 0..n_resamples {
+        for (src, dst) in samples.iter().zip(bufs.iter_mut()) {
+            resample_into(&mut rng, src, dst);
+        }
+        let views: Vec<&[f64]> = bufs.iter().map(|b| b.as_slice()).collect();
+        reps.push(stat(&views));
+
+        if n_inner > 0 {
+            inner_reps.clear();
+            for _ in 0..n_inner {
+                for (src, dst) in bufs.iter().zip(inner_bufs.iter_mut()) {
+                    resample_into(&mut rng, src, dst);
+                }
+                let inner_views: Vec<&[f64]> = inner_bufs.iter().map(|b| b.as_slice()).collect();
+                inner_reps.push(stat(&inner_views));
+            }
+            ses.push(sd(&inner_reps));
+        }
+    }
+    (reps, ses)
+}
+
+/// Jackknife estimate of the BCa acceleration, generalized to several independent samples as in `scipy`'s
+/// `bootstrap` function.
+fn bca_acceleration(samples: &[&[f64]], stat: &dyn Fn(&[&[f64]]) -> f64) -> f64 {
+    let mut num = 0.;
+    let mut den = 0.;
+    for (i, sample) in samples.iter().enumerate() {
+        let n = sample.len();
+        let mut buf = Vec::with_capacity(n - 1);
+        let jack: Vec<f64> = (0..n)
+            .map(|j| {
+                buf.clear();
+                buf.extend_from_slice(&sample[..j]);
+                buf.extend_from_slice(&sample[j + 1..]);
+                let mut views = samples.to_vec();
+                views[i] = &buf;
+                stat(&views)
+            })
+            .collect();
+        let mean = jack.iter().sum::<f64>() / n as f64;
+        let nf = n as f64;
+        num += jack
+            .iter()
+            .map(|v| ((nf - 1.) * (mean - v)).powi(3))
+            .sum::<f64>()
+            / nf.powi(3);
+        den += jack
+            .iter()
+            .map(|v| ((nf - 1.) * (mean - v)).powi(2))
+            .sum::<f64>()
+            / nf.powi(2);
+    }
+    if den == 0. {
+        return 0.;
+    }
+    num / (6. * den.powf(1.5))
+}
+
+fn ci(
+    samples: &[&[f64]],
+    stat: &dyn Fn(&[&[f64]]) -> f64,
+    method: BootstrapCiMethod,
+    n_resamples: usize,
+    seed: u64,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    check_alpha_in_open_0_1(alpha)?;
+    check_samples(samples)?;
+    if n_resamples < 2 {
+        return Err(StatsError("arg `n_resamples` must be at least `2`"));
+    }
+    let n_inner = match method {
+        BootstrapCiMethod::Studentized { n_inner } if n_inner < 2 => {
+            return Err(StatsError("arg `n_inner` must be at least `2`"));
+        }
+        BootstrapCiMethod::Studentized { n_inner } => n_inner,
+        BootstrapCiMethod::Bca if samples.iter().any(|s| s.len() < 2) => {
+            return Err(StatsError(
+                "sample size must be at least `2` for BCa intervals",
+            ));
+        }
+        _ => 0,
+    };
+
+    let theta = stat(samples);
+    let (mut reps, ses) = replicates(samples, stat, n_resamples, n_inner, seed);
+    if !theta.is_finite() || reps.iter().any(|v| !v.is_finite()) {
+        return Err(StatsError(
+            "statistic must be finite for the data and all resamples",
+        ));
+    }
+
+    let lo_q = alpha / 2.;
+    let hi_q = 1. - alpha / 2.;
+
+    let ci = match method {
+        BootstrapCiMethod::Percentile => {
+            reps.sort_by(f64::total_cmp);
+            Ci(quantile_sorted(&reps, lo_q), quantile_sorted(&reps, hi_q))
+        }
+        BootstrapCiMethod::Basic => {
+            reps.sort_by(f64::total_cmp);
+            Ci(
+                2. * theta - quantile_sorted(&reps, hi_q),
+                2. * theta - quantile_sorted(&reps, lo_q),
+            )
+        }
+        BootstrapCiMethod::Studentized { .. } => {
+            if ses.iter().any(|&se| se.is_nan() || se <= 0.) {
+                return Err(StatsError(
+                    "nested bootstrap standard error must be positive for all resamples",
+                ));
+            }
+            let se_theta = sd(&reps);
+            let mut ts: Vec<f64> = reps
+                .iter()
+                .zip(ses.iter())
+                .map(|(rep, se)| (rep - theta) / se)
+                .collect();
+            ts.sort_by(f64::total_cmp);
+            Ci(
+                theta - quantile_sorted(&ts, hi_q) * se_theta,
+                theta - quantile_sorted(&ts, lo_q) * se_theta,
+            )
+        }
+        BootstrapCiMethod::Bca => {
+            let n_less = reps.iter().filter(|&&v| v < theta).count();
+            if n_less == 0 || n_less == n_resamples {
+                return Err(StatsError(
+                    "bootstrap distribution is degenerate: BCa bias correction is infinite",
+                ));
+            }
+            let normal = Normal::standard();
+            let z0 = normal.inverse_cdf(n_less as f64 / n_resamples as f64);
+            let a = bca_acceleration(samples, stat);
+            let adjust = |q: f64| {
+                let z = z0 + normal.inverse_cdf(q);
+                normal.cdf(z0 + z / (1. - a * z))
+            };
+            reps.sort_by(f64::total_cmp);
+            Ci(
+                quantile_sorted(&reps, adjust(lo_q)),
+                quantile_sorted(&reps, adjust(hi_q)),
+            )
+        }
+    };
+    Ok(ci)
+}
+
+/// Bootstrap replicates of a statistic: the values of `stat` on `n_resamples` resamples of `dataset`, drawn
+/// with replacement.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `stat`: statistic, computed from a slice of data.
+/// - `n_resamples`: number of resamples.
+/// - `seed`: seed of the pseudo-random number generator.
+///
+/// # Errors
+///
+/// Returns an error if `dataset` is empty.
+pub fn bootstrap_replicates(
+    dataset: &[f64],
+    stat: impl Fn(&[f64]) -> f64,
+    n_resamples: usize,
+    seed: u64,
+) -> StatsResult<Vec<f64>> {
+    let samples = [dataset];
+    check_samples(&samples)?;
+    let stat = |s: &[&[f64]]| stat(s[0]);
+    Ok(replicates(&samples, &stat, n_resamples, 0, seed).0)
+}
+
+/// Bootstrap confidence interval for a statistic of one sample.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `stat`: statistic, computed from a slice of data.
+/// - `method`: method used to compute the interval.
+/// - `n_resamples`: number of resamples.
+/// - `seed`: seed of the pseudo-random number generator.
+/// - `alpha`: complement of the confidence level.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `alpha` not in open interval `(0, 1)`.
+/// - `dataset` is empty, or has fewer than `2` items and `method` is [`BootstrapCiMethod::Bca`].
+/// - `n_resamples < 2`, or `n_inner < 2` for [`BootstrapCiMethod::Studentized`].
+/// - `stat` returns a non-finite value for `dataset` or for any resample.
+/// - For [`BootstrapCiMethod::Studentized`], a nested bootstrap standard error is zero.
+/// - For [`BootstrapCiMethod::Bca`], all replicates are on the same side of the statistic's value.
+pub fn bootstrap_ci(
+    dataset: &[f64],
+    stat: impl Fn(&[f64]) -> f64,
+    method: BootstrapCiMethod,
+    n_resamples: usize,
+    seed: u64,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    let stat = |s: &[&[f64]]| stat(s[0]);
+    ci(&[dataset], &stat, method, n_resamples, seed, alpha)
+}
+
+/// Bootstrap replicates of a two-sample statistic: the values of `stat` on `n_resamples` pairs of resamples of
+/// `x` and `y`, each drawn with replacement from its own sample.
+///
+/// Arguments:
+/// - `x`: first sample.
+/// - `y`: second sample.
+/// - `stat`: statistic, computed from a slice of data from each sample.
+/// - `n_resamples`: number of resamples.
+/// - `seed`: seed of the pseudo-random number generator.
+///
+/// # Errors
+///
+/// Returns an error if `x` or `y` is empty.
+pub fn bootstrap_2samp_replicates(
+    x: &[f64],
+    y: &[f64],
+    stat: impl Fn(&[f64], &[f64]) -> f64,
+    n_resamples: usize,
+    seed: u64,
+) -> StatsResult<Vec<f64>> {
+    let samples = [x, y];
+    check_samples(&samples)?;
+    let stat = |s: &[&[f64]]| stat(s[0], s[1]);
+    Ok(replicates(&samples, &stat, n_resamples, 0, seed).0)
+}
+
+/// Bootstrap confidence interval for a statistic of two independent samples, such as the difference of means
+/// or the ratio of medians.
+///
+/// Arguments:
+/// - `x`: first sample.
+/// - `y`: second sample.
+/// - `stat`: statistic, computed from a slice of data from each sample.
+/// - `method`: method used to compute the interval.
+/// - `n_resamples`: number of resamples.
+/// - `seed`: seed of the pseudo-random number generator.
+/// - `alpha`: complement of the confidence level.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `alpha` not in open interval `(0, 1)`.
+/// - `x` or `y` is empty, or has fewer than `2` items and `method` is [`BootstrapCiMethod::Bca`].
+/// - `n_resamples < 2`, or `n_inner < 2` for [`BootstrapCiMethod::Studentized`].
+/// - `stat` returns a non-finite value for the samples or for any resample.
+/// - For [`BootstrapCiMethod::Studentized`], a nested bootstrap standard error is zero.
+/// - For [`BootstrapCiMethod::Bca`], all replicates are on the same side of the statistic's value.
+pub fn bootstrap_2samp_ci(
+    x: &[f64],
+    y: &[f64],
+    stat: impl Fn(&[f64], &[f64]) -> f64,
+    method: BootstrapCiMethod,
+    n_resamples: usize,
+    seed: u64,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    let stat = |s: &[&[f64]]| stat(s[0], s[1]);
+    ci(&[x, y], &stat, method, n_resamples, seed, alpha)
+}
+
+#[cfg(test)]
+mod test {
+    //! Expected values come from an independent Python implementation of the same algorithms, driven by a
+    //! Python port of [`SeededRng`] so that the resamples are identical.
+
+    use super::*;
+    use crate::dev_utils::ApproxEq;
+
+    const EPSILON: f64 = 1e-9;
+    const ALPHA: f64 = 0.05;
+    const SEED: u64 = 12345;
+
+    const X: [f64; 12] = [
+        112., 98., 105., 131., 98., 120., 101., 99., 250., 108., 115., 103.,
+    ];
+    const Y: [f64; 10] = [95., 91., 102., 99., 88., 97., 180., 93., 96., 90.];
+
+    fn mean(s: &[f64]) -> f64 {
+        s.iter().sum::<f64>() / s.len() as f64
+    }
+
+    fn median(s: &[f64]) -> f64 {
+        let mut v = s.to_vec();
+        v.sort_by(f64::total_cmp);
+        quantile_sorted(&v, 0.5)
+    }
+
+    fn check_ci(exp: Ci, actual: Ci) {
+        assert!(
+            exp.0.approx_eq(actual.0, EPSILON) && exp.1.approx_eq(actual.1, EPSILON),
+            "exp={exp:?}, actual={actual:?}"
+        );
+    }
+
+    #[test]
+    fn test_replicates() {
+        let reps = bootstrap_replicates(&X, mean, 1000, SEED).unwrap();
+        assert_eq!(1000, reps.len());
+        assert_eq!(reps, bootstrap_replicates(&X, mean, 1000, SEED).unwrap());
+        assert_ne!(
+            reps,
+            bootstrap_replicates(&X, mean, 1000, SEED + 1).unwrap()
+        );
+
+        // Resamples are drawn from the data.
+        let mins = bootstrap_replicates(&X, |s| s.iter().cloned().fold(f64::MAX, f64::min), 200, 1)
+            .unwrap();
+        assert!(mins.iter().all(|v| X.contains(v)));
+
+        let reps2 =
+            bootstrap_2samp_replicates(&X, &Y, |x, y| mean(x) - mean(y), 1000, SEED).unwrap();
+        assert_eq!(1000, reps2.len());
+    }
+
+    #[test]
+    fn test_1samp_ci() {
+        let cases = [
+            (
+                BootstrapCiMethod::Percentile,
+                Ci(103.99791666666667, 144.83333333333334),
+            ),
+            (
+                BootstrapCiMethod::Basic,
+                Ci(95.16666666666666, 136.00208333333333),
+            ),
+            (
+                BootstrapCiMethod::Studentized { n_inner: 50 },
+                Ci(101.98108379986598, 220.38675898106763),
+            ),
+            (
+                BootstrapCiMethod::Bca,
+                Ci(106.08333333333333, 166.37904208523312),
+            ),
+        ];
+        for (method, exp) in cases {
+            let n_resamples = match method {
+                BootstrapCiMethod::Studentized { .. } => 500,
+                _ => 2000,
+            };
+            let actual = bootstrap_ci(&X, mean, method, n_resamples, SEED, ALPHA).unwrap();
+            check_ci(exp, actual);
+        }
+    }
+
+    #[test]
+    fn test_2samp_ci() {
+        let ratio = |x: &[f64], y: &[f64]| median(x) / median(y);
+        let cases = [
+            (
+                BootstrapCiMethod::Percentile,
+                Ci(1.0403101697604316, 1.2472527472527473),
+            ),
+            (
+                BootstrapCiMethod::Basic,
+                Ci(0.9831137448938496, 1.1900563223861653),
+            ),
+            (
+                BootstrapCiMethod::Studentized { n_inner: 50 },
+                Ci(1.0095954832847034, 1.2106931345956184),
+            ),
+            (
+                BootstrapCiMethod::Bca,
+                Ci(1.0295701572869354, 1.2307665505522236),
+            ),
+        ];
+        for (method, exp) in cases {
+            let n_resamples = match method {
+                BootstrapCiMethod::Studentized { .. } => 500,
+                _ => 2000,
+            };
+            let actual =
+                bootstrap_2samp_ci(&X, &Y, ratio, method, n_resamples, SEED, ALPHA).unwrap();
+            check_ci(exp, actual);
+        }
+    }
+
+    #[test]
+    fn test_bca_acceleration() {
+        // For the mean, the jackknife acceleration is the sample skewness divided by `6 * sqrt(n)`.
+        let samples: [&[f64]; 1] = [&X];
+        let a = bca_acceleration(&samples, &|s| mean(s[0]));
+        let n = X.len() as f64;
+        let m = mean(&X);
+        let m2 = X.iter().map(|v| (v - m).powi(2)).sum::<f64>() / n;
+        let m3 = X.iter().map(|v| (v - m).powi(3)).sum::<f64>() / n;
+        let exp = m3 / m2.powf(1.5) / (6. * n.sqrt());
+        assert!(exp.approx_eq(a, EPSILON), "exp={exp}, a={a}");
+    }
+}
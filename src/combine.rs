@@ -0,0 +1,616 @@
+//! Combination of p-values from independent hypothesis tests into a single test result.
+//!
+//! All functions in this module take a slice of p-values, each one obtained from a separate and independent
+//! test. The combined null hypothesis is that all the individual null hypotheses are true and the combined
+//! alternative hypothesis is that at least one of the individual null hypotheses is false. Accordingly, the
+//! [`HypTestResult`]s returned by the `*_test` functions in this module have an alternative hypothesis of
+//! [`AltHyp::Ne`], except for Stouffer's method, whose combined p-value is one-sided (see
+//! [`stouffer_combined_test`]).
+//!
+//! - For Fisher's method, use `fisher_combined_*` functions.
+//! - For Stouffer's Z method, use `stouffer_*` functions. The `stouffer_weighted_*` variants support
+//!   weighted combinations.
+//! - For Tippett's minimum-p method, use `tippett_combined_*` functions.
+//! - For the harmonic mean p-value, use [`harmonic_mean_p`] for the statistic and `hmp_*` functions for the
+//!   asymptotically exact combined p-value and test. The `*_weighted_*` variants support weighted combinations.
+//!
+//! This module is included by default. However, if `default-features = false` is specified in the dependency
+//! declaration for this library, then inclusion of this module is gated by feature "**combine**".
+//!
+//! # Example
+//!
+//! ```
+#![doc = include_str!("../examples/combine.rs")]
+//! ```
+
+use crate::{
+    core::{
+        AltHyp, AsStatsResult, HypTestResult, StatsError, StatsResult, check_alpha_in_open_0_1,
+        integrate,
+    },
+    normal::z_to_p,
+};
+use statrs::distribution::{ChiSquared, ContinuousCDF, Normal};
+use std::f64::consts::{FRAC_2_PI, FRAC_PI_2, PI};
+
+/// Euler–Mascheroni constant.
+const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+
+fn check_p_values(p_values: &[f64], lo_open: bool, hi_open: bool) -> StatsResult<()> {
+    if p_values.is_empty() {
+        return Err(StatsError("arg `p_values` must not be empty"));
+    }
+    let in_range = |p: f64| {
+        let lo_ok = if lo_open { 0. < p } else { 0. <= p };
+        let hi_ok = if hi_open { p < 1. } else { p <= 1. };
+        lo_ok && hi_ok
+    };
+    if p_values.iter().all(|&p| in_range(p)) {
+        return Ok(());
+    }
+    Err(match (lo_open, hi_open) {
+        (true, true) => StatsError("items of arg `p_values` must be in interval (0, 1)"),
+        (true, false) => StatsError("items of arg `p_values` must be in interval (0, 1]"),
+        (false, true) => StatsError("items of arg `p_values` must be in interval [0, 1)"),
+        (false, false) => StatsError("items of arg `p_values` must be in interval [0, 1]"),
+    })
+}
+
+fn check_weights(p_values: &[f64], weights: &[f64]) -> StatsResult<()> {
+    if p_values.len() != weights.len() {
+        return Err(StatsError(
+            "args `p_values` and `weights` must have the same length",
+        ));
+    }
+    if weights.iter().all(|&w| w.is_finite() && w > 0.) {
+        return Ok(());
+    }
+    Err(StatsError(
+        "items of arg `weights` must be finite and positive",
+    ))
+}
+
+/// Fisher's combined chi-square statistic, `-2 * sum(ln(p_i))`.
+///
+/// Under the combined null hypothesis, this statistic has a chi-square distribution with
+/// `2 * p_values.len()` degrees of freedom.
+///
+/// Arguments:
+/// - `p_values`: p-values from independent tests.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1]`.
+pub fn fisher_combined_chi2(p_values: &[f64]) -> StatsResult<f64> {
+    check_p_values(p_values, true, false)?;
+    Ok(-2. * p_values.iter().map(|p| p.ln()).sum::<f64>())
+}
+
+/// Degrees of freedom of the chi-square distribution for Fisher's method.
+///
+/// Arguments:
+/// - `p_values`: p-values from independent tests.
+///
+/// # Errors
+///
+/// Returns an error if `p_values` is empty.
+pub fn fisher_combined_df(p_values: &[f64]) -> StatsResult<f64> {
+    if p_values.is_empty() {
+        return Err(StatsError("arg `p_values` must not be empty"));
+    }
+    Ok(2. * p_values.len() as f64)
+}
+
+/// Combined p-value using Fisher's method.
+///
+/// Arguments:
+/// - `p_values`: p-values from independent tests.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1]`.
+pub fn fisher_combined_p(p_values: &[f64]) -> StatsResult<f64> {
+    let chi2 = fisher_combined_chi2(p_values)?;
+    let df = fisher_combined_df(p_values)?;
+    let chi_squared = ChiSquared::new(df).stats_result("degrees of freedom must be positive")?;
+    Ok(chi_squared.sf(chi2))
+}
+
+/// Combined test using Fisher's method.
+///
+/// Arguments:
+/// - `p_values`: p-values from independent tests.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1]`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn fisher_combined_test(p_values: &[f64], alpha: f64) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p = fisher_combined_p(p_values)?;
+    Ok(HypTestResult::new(p, alpha, AltHyp::Ne))
+}
+
+/// Stouffer's weighted Z statistic, `sum(w_i * z_i) / sqrt(sum(w_i^2))`, where `z_i` is the standard normal
+/// quantile of `1 - p_i`.
+///
+/// The p-values should be one-sided p-values, all computed for the same direction of the alternative hypothesis.
+/// Under the combined null hypothesis, this statistic has a standard normal distribution.
+///
+/// Arguments:
+/// - `p_values`: one-sided p-values from independent tests.
+/// - `weights`: weights of the individual tests (e.g., the square roots of the sample sizes).
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1)`.
+/// - `p_values` and `weights` have different lengths.
+/// - Any item of `weights` is not finite and positive.
+pub fn stouffer_weighted_z(p_values: &[f64], weights: &[f64]) -> StatsResult<f64> {
+    check_p_values(p_values, true, true)?;
+    check_weights(p_values, weights)?;
+
+    let normal = Normal::standard();
+    let sum_wz = p_values
+        .iter()
+        .zip(weights)
+        .map(|(&p, &w)| -w * normal.inverse_cdf(p))
+        .sum::<f64>();
+    let sum_w2 = weights.iter().map(|w| w * w).sum::<f64>();
+    Ok(sum_wz / sum_w2.sqrt())
+}
+
+/// Stouffer's Z statistic, `sum(z_i) / sqrt(k)`, where `z_i` is the standard normal quantile of `1 - p_i`
+/// and `k` is the number of p-values.
+///
+/// The p-values should be one-sided p-values, all computed for the same direction of the alternative hypothesis.
+/// Under the combined null hypothesis, this statistic has a standard normal distribution.
+///
+/// Arguments:
+/// - `p_values`: one-sided p-values from independent tests.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1)`.
+pub fn stouffer_z(p_values: &[f64]) -> StatsResult<f64> {
+    let weights = vec![1.; p_values.len()];
+    stouffer_weighted_z(p_values, &weights)
+}
+
+/// Combined one-sided p-value using Stouffer's weighted Z method.
+///
+/// Arguments:
+/// - `p_values`: one-sided p-values from independent tests.
+/// - `weights`: weights of the individual tests (e.g., the square roots of the sample sizes).
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1)`.
+/// - `p_values` and `weights` have different lengths.
+/// - Any item of `weights` is not finite and positive.
+pub fn stouffer_weighted_combined_p(p_values: &[f64], weights: &[f64]) -> StatsResult<f64> {
+    let z = stouffer_weighted_z(p_values, weights)?;
+    Ok(z_to_p(z, AltHyp::Gt))
+}
+
+/// Combined one-sided p-value using Stouffer's Z method.
+///
+/// Arguments:
+/// - `p_values`: one-sided p-values from independent tests.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1)`.
+pub fn stouffer_combined_p(p_values: &[f64]) -> StatsResult<f64> {
+    let z = stouffer_z(p_values)?;
+    Ok(z_to_p(z, AltHyp::Gt))
+}
+
+/// Combined test using Stouffer's weighted Z method.
+///
+/// The combined p-value is one-sided: small values indicate that the individual tests, taken together, favor their
+/// one-sided alternative hypotheses. Accordingly, the returned [`HypTestResult`] has an alternative hypothesis of
+/// [`AltHyp::Gt`], i.e., the combined Z statistic is greater than expected under the null hypothesis.
+///
+/// Arguments:
+/// - `p_values`: one-sided p-values from independent tests.
+/// - `weights`: weights of the individual tests (e.g., the square roots of the sample sizes).
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1)`.
+/// - `p_values` and `weights` have different lengths.
+/// - Any item of `weights` is not finite and positive.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn stouffer_weighted_combined_test(
+    p_values: &[f64],
+    weights: &[f64],
+    alpha: f64,
+) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p = stouffer_weighted_combined_p(p_values, weights)?;
+    Ok(HypTestResult::new(p, alpha, AltHyp::Gt))
+}
+
+/// Combined test using Stouffer's Z method.
+///
+/// The combined p-value is one-sided: small values indicate that the individual tests, taken together, favor their
+/// one-sided alternative hypotheses. Accordingly, the returned [`HypTestResult`] has an alternative hypothesis of
+/// [`AltHyp::Gt`], i.e., the combined Z statistic is greater than expected under the null hypothesis.
+///
+/// Arguments:
+/// - `p_values`: one-sided p-values from independent tests.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1)`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn stouffer_combined_test(p_values: &[f64], alpha: f64) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p = stouffer_combined_p(p_values)?;
+    Ok(HypTestResult::new(p, alpha, AltHyp::Gt))
+}
+
+/// Combined p-value using Tippett's minimum-p method, `1 - (1 - min(p_i))^k`, where `k` is the number of p-values.
+///
+/// Arguments:
+/// - `p_values`: p-values from independent tests.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `[0, 1]`.
+pub fn tippett_combined_p(p_values: &[f64]) -> StatsResult<f64> {
+    check_p_values(p_values, false, false)?;
+    let k = p_values.len() as f64;
+    let p_min = p_values.iter().cloned().fold(1., f64::min);
+    Ok(-(k * (-p_min).ln_1p()).exp_m1())
+}
+
+/// Combined test using Tippett's minimum-p method.
+///
+/// Arguments:
+/// - `p_values`: p-values from independent tests.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `[0, 1]`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn tippett_combined_test(p_values: &[f64], alpha: f64) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p = tippett_combined_p(p_values)?;
+    Ok(HypTestResult::new(p, alpha, AltHyp::Ne))
+}
+
+/// Weighted harmonic mean of p-values, `sum(w_i) / sum(w_i / p_i)`.
+///
+/// This is the raw harmonic mean p-value statistic, which is anti-conservative when interpreted directly
+/// as a p-value. See [`hmp_weighted_combined_p`] for the asymptotically exact combined p-value.
+///
+/// Arguments:
+/// - `p_values`: p-values from independent tests.
+/// - `weights`: weights of the individual tests.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1]`.
+/// - `p_values` and `weights` have different lengths.
+/// - Any item of `weights` is not finite and positive.
+pub fn harmonic_mean_weighted_p(p_values: &[f64], weights: &[f64]) -> StatsResult<f64> {
+    check_p_values(p_values, true, false)?;
+    check_weights(p_values, weights)?;
+    let sum_w = weights.iter().sum::<f64>();
+    let sum_w_over_p = p_values
+        .iter()
+        .zip(weights)
+        .map(|(p, w)| w / p)
+        .sum::<f64>();
+    Ok(sum_w / sum_w_over_p)
+}
+
+/// Harmonic mean of p-values, `k / sum(1 / p_i)`, where `k` is the number of p-values.
+///
+/// This is the raw harmonic mean p-value statistic, which is anti-conservative when interpreted directly
+/// as a p-value. See [`hmp_combined_p`] for the asymptotically exact combined p-value.
+///
+/// Arguments:
+/// - `p_values`: p-values from independent tests.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1]`.
+pub fn harmonic_mean_p(p_values: &[f64]) -> StatsResult<f64> {
+    let weights = vec![1.; p_values.len()];
+    harmonic_mean_weighted_p(p_values, &weights)
+}
+
+/// Upper tail probability of the standard stable distribution with `alpha == 1` and `beta == 1`
+/// (in Nolan's parameterizations, which coincide for the standard distribution when `alpha == 1`).
+///
+/// Based on the integral representation of the CDF in J.P. Nolan, "Numerical calculation of stable densities
+/// and distribution functions", Communications in Statistics. Stochastic Models, 13(4), 1997, Theorem 1.
+fn stable_1_1_sf(x: f64) -> f64 {
+    let c = (-FRAC_PI_2 * x).exp();
+    let v = |theta: f64| {
+        let a = FRAC_PI_2 + theta;
+        FRAC_2_PI * (a / theta.cos()) * (a * theta.tan()).exp()
+    };
+    let integrand = |theta: f64| {
+        let cv = c * v(theta);
+        if cv.is_nan() { 1. } else { -(-cv).exp_m1() }
+    };
+    let value = integrate(integrand, -FRAC_PI_2, FRAC_PI_2, 1e-15) / PI;
+    value.clamp(0., 1.)
+}
+
+/// Asymptotically exact combined p-value for the weighted harmonic mean p-value.
+///
+/// Under the combined null hypothesis, the reciprocal of the harmonic mean p-value of `L` p-values with
+/// normalized weights has approximately a Landau distribution with location `ln(L) + 1 - γ`, where
+/// `γ` is the Euler–Mascheroni constant. The approximation improves as the p-value decreases.
+/// Equivalent to `R`'s `harmonicmeanp::p.hmp` function when the weights sum to `1`.
+///
+/// Reference: D.J. Wilson, "The harmonic mean p-value for combining dependent tests",
+/// PNAS 116(4), 2019.
+///
+/// Arguments:
+/// - `p_values`: p-values from independent tests.
+/// - `weights`: weights of the individual tests; they are normalized to sum to `1`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1]`.
+/// - `p_values` and `weights` have different lengths.
+/// - Any item of `weights` is not finite and positive.
+pub fn hmp_weighted_combined_p(p_values: &[f64], weights: &[f64]) -> StatsResult<f64> {
+    let hmp = harmonic_mean_weighted_p(p_values, weights)?;
+    let l = p_values.len() as f64;
+    let location = l.ln() + 1. - EULER_GAMMA;
+    // Standardize the Landau variable, which is stable with alpha = 1, beta = 1, and scale = pi/2.
+    let x = (1. / hmp - location - FRAC_PI_2.ln()) / FRAC_PI_2;
+    Ok(stable_1_1_sf(x))
+}
+
+/// Asymptotically exact combined p-value for the harmonic mean p-value.
+///
+/// See [`hmp_weighted_combined_p`].
+///
+/// Arguments:
+/// - `p_values`: p-values from independent tests.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1]`.
+pub fn hmp_combined_p(p_values: &[f64]) -> StatsResult<f64> {
+    let weights = vec![1.; p_values.len()];
+    hmp_weighted_combined_p(p_values, &weights)
+}
+
+/// Combined test using the weighted harmonic mean p-value.
+///
+/// See [`hmp_weighted_combined_p`].
+///
+/// Arguments:
+/// - `p_values`: p-values from independent tests.
+/// - `weights`: weights of the individual tests; they are normalized to sum to `1`.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1]`.
+/// - `p_values` and `weights` have different lengths.
+/// - Any item of `weights` is not finite and positive.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn hmp_weighted_combined_test(
+    p_values: &[f64],
+    weights: &[f64],
+    alpha: f64,
+) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p = hmp_weighted_combined_p(p_values, weights)?;
+    Ok(HypTestResult::new(p, alpha, AltHyp::Ne))
+}
+
+/// Combined test using the harmonic mean p-value.
+///
+/// See [`hmp_weighted_combined_p`].
+///
+/// Arguments:
+/// - `p_values`: p-values from independent tests.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `p_values` is empty.
+/// - Any item of `p_values` is not in interval `(0, 1]`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn hmp_combined_test(p_values: &[f64], alpha: f64) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p = hmp_combined_p(p_values)?;
+    Ok(HypTestResult::new(p, alpha, AltHyp::Ne))
+}
+
+#[cfg(test)]
+mod test {
+    //! Expected values computed independently with the `mpmath` Python library, using the definitions
+    //! of the statistics and the distributions involved. For the harmonic mean p-value, the Landau tail
+    //! probability was computed by numerical inversion of the characteristic function.
+
+    use super::*;
+    use crate::{core::Hyp, dev_utils::ApproxEq};
+
+    const ALPHA: f64 = 0.05;
+    const EPSILON: f64 = 0.000005;
+
+    const P_VALUES: [f64; 5] = [0.01, 0.2, 0.3, 0.04, 0.5];
+    const WEIGHTS: [f64; 5] = [1., 2., 3., 1., 2.];
+
+    fn check_res(res: HypTestResult, exp_p: f64, exp_accept_hyp: Hyp) {
+        assert!(
+            exp_p.approx_eq(res.p(), EPSILON),
+            "exp_p={exp_p}, p={}",
+            res.p()
+        );
+        assert_eq!(ALPHA, res.alpha());
+        if let Hyp::Alt(alt_hyp) = exp_accept_hyp {
+            assert_eq!(alt_hyp, res.alt_hyp());
+        }
+        assert_eq!(exp_accept_hyp, res.accepted());
+    }
+
+    #[test]
+    fn test_fisher() {
+        let exp_chi2 = 22.6612078;
+        let exp_df = 10.;
+        let exp_p = 0.01206861;
+
+        let chi2 = fisher_combined_chi2(&P_VALUES).unwrap();
+        let df = fisher_combined_df(&P_VALUES).unwrap();
+        let p = fisher_combined_p(&P_VALUES).unwrap();
+        let res = fisher_combined_test(&P_VALUES, ALPHA).unwrap();
+
+        assert!(
+            exp_chi2.approx_eq(chi2, EPSILON),
+            "exp_chi2={exp_chi2}, chi2={chi2}"
+        );
+        assert_eq!(exp_df, df);
+        assert!(exp_p.approx_eq(p, EPSILON), "exp_p={exp_p}, p={p}");
+        check_res(res, exp_p, Hyp::Alt(AltHyp::Ne));
+    }
+
+    #[test]
+    fn test_stouffer() {
+        let exp_z = 2.4342085;
+        let exp_p = 0.00746220;
+
+        let z = stouffer_z(&P_VALUES).unwrap();
+        let p = stouffer_combined_p(&P_VALUES).unwrap();
+        let res = stouffer_combined_test(&P_VALUES, ALPHA).unwrap();
+
+        assert!(exp_z.approx_eq(z, EPSILON), "exp_z={exp_z}, z={z}");
+        assert!(exp_p.approx_eq(p, EPSILON), "exp_p={exp_p}, p={p}");
+        check_res(res, exp_p, Hyp::Alt(AltHyp::Gt));
+    }
+
+    #[test]
+    fn test_stouffer_weighted() {
+        let exp_z = 1.6824152;
+        let exp_p = 0.04624418;
+
+        let z = stouffer_weighted_z(&P_VALUES, &WEIGHTS).unwrap();
+        let p = stouffer_weighted_combined_p(&P_VALUES, &WEIGHTS).unwrap();
+        let res = stouffer_weighted_combined_test(&P_VALUES, &WEIGHTS, ALPHA).unwrap();
+
+        assert!(exp_z.approx_eq(z, EPSILON), "exp_z={exp_z}, z={z}");
+        assert!(exp_p.approx_eq(p, EPSILON), "exp_p={exp_p}, p={p}");
+        check_res(res, exp_p, Hyp::Alt(AltHyp::Gt));
+
+        let unit_weights = [1.; 5];
+        assert_eq!(
+            stouffer_z(&P_VALUES).unwrap(),
+            stouffer_weighted_z(&P_VALUES, &unit_weights).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_tippett() {
+        let exp_p = 0.04900995;
+
+        let p = tippett_combined_p(&P_VALUES).unwrap();
+        let res = tippett_combined_test(&P_VALUES, ALPHA).unwrap();
+
+        assert!(exp_p.approx_eq(p, EPSILON), "exp_p={exp_p}, p={p}");
+        check_res(res, exp_p, Hyp::Alt(AltHyp::Ne));
+
+        assert_eq!(0., tippett_combined_p(&[0., 0.5]).unwrap());
+        assert_eq!(1., tippett_combined_p(&[1., 1.]).unwrap());
+    }
+
+    #[test]
+    fn test_hmp() {
+        let exp_hmp = 0.03694581;
+        let exp_p = 0.04453200;
+
+        let hmp = harmonic_mean_p(&P_VALUES).unwrap();
+        let p = hmp_combined_p(&P_VALUES).unwrap();
+        let res = hmp_combined_test(&P_VALUES, ALPHA).unwrap();
+
+        assert!(
+            exp_hmp.approx_eq(hmp, EPSILON),
+            "exp_hmp={exp_hmp}, hmp={hmp}"
+        );
+        assert!(exp_p.approx_eq(p, EPSILON), "exp_p={exp_p}, p={p}");
+        check_res(res, exp_p, Hyp::Alt(AltHyp::Ne));
+    }
+
+    #[test]
+    fn test_hmp_weighted() {
+        let exp_hmp = 0.06040268;
+        let exp_p = 0.07936578;
+
+        let hmp = harmonic_mean_weighted_p(&P_VALUES, &WEIGHTS).unwrap();
+        let p = hmp_weighted_combined_p(&P_VALUES, &WEIGHTS).unwrap();
+        let res = hmp_weighted_combined_test(&P_VALUES, &WEIGHTS, ALPHA).unwrap();
+
+        assert!(
+            exp_hmp.approx_eq(hmp, EPSILON),
+            "exp_hmp={exp_hmp}, hmp={hmp}"
+        );
+        assert!(exp_p.approx_eq(p, EPSILON), "exp_p={exp_p}, p={p}");
+        check_res(res, exp_p, Hyp::Null);
+    }
+
+    #[test]
+    fn test_hmp_small() {
+        let mut p_values = [0.5; 10];
+        p_values[0] = 0.001;
+
+        let exp_hmp = 0.00982318;
+        let exp_p = 0.01052951;
+
+        let hmp = harmonic_mean_p(&p_values).unwrap();
+        let p = hmp_combined_p(&p_values).unwrap();
+
+        assert!(
+            exp_hmp.approx_eq(hmp, EPSILON),
+            "exp_hmp={exp_hmp}, hmp={hmp}"
+        );
+        assert!(exp_p.approx_eq(p, EPSILON), "exp_p={exp_p}, p={p}");
+    }
+}
@@ -0,0 +1,942 @@
+//! Statistics related to contingency tables, including tests of independence and Fisher's exact test for 2x2 tables.
+//!
+//! - For Pearson's chi-square test of independence, the likelihood-ratio G-test, and related statistics for
+//!   tables of any size, construct a [`ContingencyTable`] and use its methods.
+//! - For Fisher's exact test and related statistics for 2x2 tables, use `fisher_exact_*` functions.
+//!
+//! For the `fisher_exact_*` functions, a 2x2 table is represented as `[[u64; 2]; 2]`, where `table[i][j]` is the
+//! count of observations in row `i` and column `j`. Typically, rows correspond to groups (e.g., platforms) and columns correspond to outcomes
+//! (e.g., failure and success). The odds ratio of the table is `(table[0][0] * table[1][1]) / (table[0][1] * table[1][0])`.
+//!
+//! This module is included by default. However, if `default-features = false` is specified in the dependency
+//! declaration for this library, then inclusion of this module is gated by feature "**contingency**".
+//!
+//! # Example
+//!
+//! ```
+#![doc = include_str!("../examples/contingency.rs")]
+//! ```
+
+use crate::core::{
+    AltHyp, Ci, HypTestResult, StatsError, StatsResult, check_alpha_in_open_0_1, find_root,
+};
+use statrs::{
+    distribution::{ChiSquared, ContinuousCDF},
+    function::factorial::ln_binomial,
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Absolute tolerance used for root finding on the odds ratio scale.
+const ROOT_TOL: f64 = 1e-14;
+
+/// Noncentral hypergeometric distribution of the top-left cell of a 2x2 table conditional on the table's margins.
+///
+/// Based on https://github.com/SurajGupta/r-source/blob/master/src/library/stats/R/fisher.test.R
+/// code for lambdas dnhyper, mnhyper, and pnhyper.
+struct NcHypergeometric {
+    x: u64,
+    lo: u64,
+    hi: u64,
+    logdc: Vec<f64>,
+}
+
+impl NcHypergeometric {
+    fn new(table: [[u64; 2]; 2]) -> StatsResult<Self> {
+        let [[a, b], [c, d]] = table;
+        let m = a + c;
+        let n = b + d;
+        let k = a + b;
+        if m == 0 || n == 0 || k == 0 || c + d == 0 {
+            return Err(StatsError(
+                "row sums and column sums of arg `table` must be positive",
+            ));
+        }
+
+        let lo = k.saturating_sub(n);
+        let hi = k.min(m);
+        let logdc = (lo..=hi)
+            .map(|i| ln_binomial(m, i) + ln_binomial(n, k - i))
+            .collect();
+
+        Ok(Self {
+            x: a,
+            lo,
+            hi,
+            logdc,
+        })
+    }
+
+    fn support(&self) -> impl Iterator<Item = u64> {
+        self.lo..=self.hi
+    }
+
+    /// Probabilities of the support values for noncentrality parameter (odds ratio) `ncp`,
+    /// where `0 < ncp < inf`.
+    fn dnhyper(&self, ncp: f64) -> Vec<f64> {
+        let ln_ncp = ncp.ln();
+        let d = self
+            .support()
+            .zip(&self.logdc)
+            .map(|(i, l)| l + ln_ncp * i as f64)
+            .collect::<Vec<_>>();
+        let max = d.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let d = d.into_iter().map(|v| (v - max).exp()).collect::<Vec<_>>();
+        let sum = d.iter().sum::<f64>();
+        d.into_iter().map(|v| v / sum).collect()
+    }
+
+    /// Mean of the distribution for noncentrality parameter (odds ratio) `ncp`.
+    fn mnhyper(&self, ncp: f64) -> f64 {
+        if ncp == 0. {
+            return self.lo as f64;
+        }
+        if ncp == f64::INFINITY {
+            return self.hi as f64;
+        }
+        self.support()
+            .zip(self.dnhyper(ncp))
+            .map(|(i, p)| i as f64 * p)
+            .sum()
+    }
+
+    /// Lower (`upper_tail == false`) or upper (`upper_tail == true`) tail probability at `q`, inclusive,
+    /// for noncentrality parameter (odds ratio) `ncp`.
+    fn pnhyper(&self, q: u64, ncp: f64, upper_tail: bool) -> f64 {
+        let in_tail = |i: u64| if upper_tail { i >= q } else { i <= q };
+        if ncp == 0. {
+            return in_tail(self.lo) as u64 as f64;
+        }
+        if ncp == f64::INFINITY {
+            return in_tail(self.hi) as u64 as f64;
+        }
+        self.support()
+            .zip(self.dnhyper(ncp))
+            .filter(|(i, _)| in_tail(*i))
+            .map(|(_, p)| p)
+            .sum::<f64>()
+            .min(1.)
+    }
+
+    /// Solves `g(t) == target` for `t` in `(0, inf)`, where `g` is a monotone function,
+    /// given the value `g1` of `g` at `t == 1`.
+    ///
+    /// Based on the structure of the `uniroot` calls in `R`'s `fisher.test` function, which search `(0, 1)` and
+    /// then the reciprocals of `(0, 1)`.
+    fn solve(&self, g: impl Fn(f64) -> f64, g1: f64, target: f64, increasing: bool) -> f64 {
+        let root_in_0_1 = (g1 > target) == increasing;
+        if g1 == target {
+            return 1.;
+        }
+        if root_in_0_1 {
+            find_root(|t| g(t) - target, 0., 1., ROOT_TOL).unwrap_or(f64::NAN)
+        } else {
+            let r = find_root(|t| g(1. / t) - target, f64::EPSILON, 1., ROOT_TOL);
+            r.map(|r| 1. / r).unwrap_or(f64::NAN)
+        }
+    }
+
+    /// Upper confidence bound for the odds ratio at level `alpha`.
+    fn ncp_u(&self, alpha: f64) -> f64 {
+        if self.x == self.hi {
+            return f64::INFINITY;
+        }
+        let g = |t| self.pnhyper(self.x, t, false);
+        self.solve(g, g(1.), alpha, false)
+    }
+
+    /// Lower confidence bound for the odds ratio at level `alpha`.
+    fn ncp_l(&self, alpha: f64) -> f64 {
+        if self.x == self.lo {
+            return 0.;
+        }
+        let g = |t| self.pnhyper(self.x, t, true);
+        self.solve(g, g(1.), alpha, true)
+    }
+
+    /// Conditional maximum likelihood estimate of the odds ratio.
+    fn mle(&self) -> f64 {
+        if self.x == self.lo {
+            return 0.;
+        }
+        if self.x == self.hi {
+            return f64::INFINITY;
+        }
+        let g = |t| self.mnhyper(t);
+        self.solve(g, g(1.), self.x as f64, true)
+    }
+}
+
+/// p-value for [Fisher's exact test](fisher_exact_test) of the null hypothesis that the odds ratio of a 2x2 table is `1`.
+///
+/// The two-sided p-value is the sum of the probabilities of all tables with the same margins whose probability
+/// is less than or equal to that of the observed table, as in `R`'s `fisher.test` function.
+///
+/// Arguments:
+/// - `table`: the 2x2 contingency table.
+/// - `alt_hyp`: alternative hypothesis, with respect to the odds ratio being `1`.
+///
+/// # Errors
+///
+/// Returns an error if any row sum or column sum of `table` is zero.
+pub fn fisher_exact_p(table: [[u64; 2]; 2], alt_hyp: AltHyp) -> StatsResult<f64> {
+    let hyper = NcHypergeometric::new(table)?;
+    let x = hyper.x;
+
+    let p_value = match alt_hyp {
+        AltHyp::Lt => hyper.pnhyper(x, 1., false),
+        AltHyp::Gt => hyper.pnhyper(x, 1., true),
+        AltHyp::Ne => {
+            let rel_err = 1. + 1e-7;
+            let d = hyper.dnhyper(1.);
+            let d_x = d[(x - hyper.lo) as usize];
+            d.iter()
+                .filter(|&&p| p <= d_x * rel_err)
+                .sum::<f64>()
+                .min(1.)
+        }
+    };
+
+    Ok(p_value)
+}
+
+/// Fisher's exact test of the null hypothesis that the odds ratio of a 2x2 table is `1`
+/// (i.e., that rows and columns are independent).
+///
+/// Arguments:
+/// - `table`: the 2x2 contingency table.
+/// - `alt_hyp`: alternative hypothesis, with respect to the odds ratio being `1`.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - Any row sum or column sum of `table` is zero.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn fisher_exact_test(
+    table: [[u64; 2]; 2],
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p_value = fisher_exact_p(table, alt_hyp)?;
+    Ok(HypTestResult::new(p_value, alpha, alt_hyp))
+}
+
+/// Conditional maximum likelihood estimate of the odds ratio of a 2x2 table, as reported by `R`'s `fisher.test`
+/// function. Unlike the sample odds ratio, it maximizes the likelihood of the noncentral hypergeometric distribution
+/// of `table[0][0]` given the table's margins.
+///
+/// The returned value is `0` when `table[0][0]` is at the minimum allowed by the margins and `Infinity` when
+/// `table[0][0]` is at the maximum allowed by the margins.
+///
+/// Arguments:
+/// - `table`: the 2x2 contingency table.
+///
+/// # Errors
+///
+/// Returns an error if any row sum or column sum of `table` is zero.
+pub fn fisher_exact_odds_ratio(table: [[u64; 2]; 2]) -> StatsResult<f64> {
+    let hyper = NcHypergeometric::new(table)?;
+    Ok(hyper.mle())
+}
+
+/// Exact conditional confidence interval for the odds ratio of a 2x2 table, obtained by inverting the one-sided
+/// Fisher's exact tests, as in `R`'s `fisher.test` function.
+///
+/// The low end of the interval is `0` when `table[0][0]` is at the minimum allowed by the margins or `alt_hyp`
+/// is [`AltHyp::Lt`]. The high end of the interval is `Infinity` when `table[0][0]` is at the maximum allowed by the
+/// margins or `alt_hyp` is [`AltHyp::Gt`].
+///
+/// Arguments:
+/// - `table`: the 2x2 contingency table.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - Any row sum or column sum of `table` is zero.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn fisher_exact_alt_hyp_ci(
+    table: [[u64; 2]; 2],
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    check_alpha_in_open_0_1(alpha)?;
+    let hyper = NcHypergeometric::new(table)?;
+
+    let ci = match alt_hyp {
+        AltHyp::Lt => Ci(0., hyper.ncp_u(alpha)),
+        AltHyp::Ne => Ci(hyper.ncp_l(alpha / 2.), hyper.ncp_u(alpha / 2.)),
+        AltHyp::Gt => Ci(hyper.ncp_l(alpha), f64::INFINITY),
+    };
+    Ok(ci)
+}
+
+/// Exact conditional confidence interval for the odds ratio of a 2x2 table,
+/// with the alternative hypothesis of inequality (two-sided).
+///
+/// See [`fisher_exact_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `table`: the 2x2 contingency table.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - Any row sum or column sum of `table` is zero.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn fisher_exact_ci(table: [[u64; 2]; 2], alpha: f64) -> StatsResult<Ci> {
+    fisher_exact_alt_hyp_ci(table, AltHyp::Ne, alpha)
+}
+
+/// Two-way contingency table with `r` rows and `c` columns, where `r >= 2` and `c >= 2`.
+///
+/// This struct's methods implement Pearson's chi-square test of independence, the likelihood-ratio G-test,
+/// and related statistics.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ContingencyTable {
+    n_rows: usize,
+    n_cols: usize,
+    counts: Vec<u64>,
+    row_sums: Vec<u64>,
+    col_sums: Vec<u64>,
+    total: u64,
+}
+
+impl ContingencyTable {
+    /// Instantiates `Self` from the table's counts, provided as a slice of rows,
+    /// where `rows[i][j]` is the count of observations in row `i` and column `j`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in any of these conditions:
+    /// - There are fewer than `2` rows or fewer than `2` columns.
+    /// - The rows do not all have the same length.
+    /// - Any row sum or column sum is zero.
+    pub fn from_counts<R: AsRef<[u64]>>(rows: &[R]) -> StatsResult<Self> {
+        let n_rows = rows.len();
+        let n_cols = rows.first().map(|row| row.as_ref().len()).unwrap_or(0);
+        if n_rows < 2 || n_cols < 2 {
+            return Err(StatsError(
+                "table must have at least 2 rows and at least 2 columns",
+            ));
+        }
+        if rows.iter().any(|row| row.as_ref().len() != n_cols) {
+            return Err(StatsError("table rows must all have the same length"));
+        }
+
+        let counts = rows
+            .iter()
+            .flat_map(|row| row.as_ref().iter().cloned())
+            .collect::<Vec<_>>();
+        let row_sums = rows
+            .iter()
+            .map(|row| row.as_ref().iter().sum())
+            .collect::<Vec<u64>>();
+        let col_sums = (0..n_cols)
+            .map(|j| rows.iter().map(|row| row.as_ref()[j]).sum())
+            .collect::<Vec<u64>>();
+        if row_sums.contains(&0) || col_sums.contains(&0) {
+            return Err(StatsError(
+                "table row sums and column sums must be positive",
+            ));
+        }
+        let total = row_sums.iter().sum();
+
+        Ok(Self {
+            n_rows,
+            n_cols,
+            counts,
+            row_sums,
+            col_sums,
+            total,
+        })
+    }
+
+    /// Instantiates `Self` by cross-tabulating a pair of samples of categorical values provided by iterators,
+    /// where the items at the same position in the two iterators are the row and column categories of the
+    /// same observation.
+    ///
+    /// Returns a triple consisting of the table, the distinct row categories, and the distinct column categories.
+    /// Row and column categories are sorted in increasing order and row `i` (column `j`) of the table corresponds to
+    /// the `i`-th row category (`j`-th column category).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in any of these conditions:
+    /// - The iterators do not have the same number of items.
+    /// - There are fewer than `2` distinct row categories or fewer than `2` distinct column categories.
+    #[allow(clippy::type_complexity)]
+    pub fn from_paired_iterators<X: Ord + Clone, Y: Ord + Clone>(
+        mut it_x: impl Iterator<Item = X>,
+        mut it_y: impl Iterator<Item = Y>,
+    ) -> StatsResult<(Self, Vec<X>, Vec<Y>)> {
+        let mut cells = BTreeMap::<(X, Y), u64>::new();
+        let mut cats_x = BTreeSet::<X>::new();
+        let mut cats_y = BTreeSet::<Y>::new();
+        loop {
+            match (it_x.next(), it_y.next()) {
+                (Some(x), Some(y)) => {
+                    cats_x.insert(x.clone());
+                    cats_y.insert(y.clone());
+                    *cells.entry((x, y)).or_insert(0) += 1;
+                }
+                (None, None) => break,
+                _ => return Err(StatsError("paired samples must have the same size")),
+            }
+        }
+
+        let cats_x = cats_x.into_iter().collect::<Vec<_>>();
+        let cats_y = cats_y.into_iter().collect::<Vec<_>>();
+        let rows = cats_x
+            .iter()
+            .map(|x| {
+                cats_y
+                    .iter()
+                    .map(|y| cells.get(&(x.clone(), y.clone())).cloned().unwrap_or(0))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let table = Self::from_counts(&rows)?;
+        Ok((table, cats_x, cats_y))
+    }
+
+    /// Number of rows.
+    pub fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    /// Number of columns.
+    pub fn n_cols(&self) -> usize {
+        self.n_cols
+    }
+
+    /// Observed count in row `i` and column `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= self.n_rows()` or `j >= self.n_cols()`.
+    pub fn count(&self, i: usize, j: usize) -> u64 {
+        assert!(i < self.n_rows && j < self.n_cols, "index out of bounds");
+        self.counts[i * self.n_cols + j]
+    }
+
+    /// Row sums.
+    pub fn row_sums(&self) -> &[u64] {
+        &self.row_sums
+    }
+
+    /// Column sums.
+    pub fn col_sums(&self) -> &[u64] {
+        &self.col_sums
+    }
+
+    /// Total number of observations.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Degrees of freedom of the chi-square distribution for the tests of independence, `(r - 1) * (c - 1)`.
+    pub fn df(&self) -> f64 {
+        ((self.n_rows - 1) * (self.n_cols - 1)) as f64
+    }
+
+    fn expected(&self, i: usize, j: usize) -> f64 {
+        self.row_sums[i] as f64 * self.col_sums[j] as f64 / self.total as f64
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (usize, usize)> + use<> {
+        let n_cols = self.n_cols;
+        (0..self.n_rows).flat_map(move |i| (0..n_cols).map(move |j| (i, j)))
+    }
+
+    fn map_cells(&self, f: impl Fn(usize, usize) -> f64) -> Vec<Vec<f64>> {
+        (0..self.n_rows)
+            .map(|i| (0..self.n_cols).map(|j| f(i, j)).collect())
+            .collect()
+    }
+
+    /// Expected counts under the hypothesis of independence, as a vector of rows.
+    pub fn expected_counts(&self) -> Vec<Vec<f64>> {
+        self.map_cells(|i, j| self.expected(i, j))
+    }
+
+    /// Pearson residuals, `(observed - expected) / sqrt(expected)`, as a vector of rows.
+    pub fn residuals(&self) -> Vec<Vec<f64>> {
+        self.map_cells(|i, j| {
+            let e = self.expected(i, j);
+            (self.count(i, j) as f64 - e) / e.sqrt()
+        })
+    }
+
+    /// Standardized (adjusted) residuals, as a vector of rows. Under the hypothesis of independence,
+    /// each standardized residual is approximately standard normal.
+    ///
+    /// Equivalent to the `stdres` component of the result of `R`'s `chisq.test` function.
+    pub fn std_residuals(&self) -> Vec<Vec<f64>> {
+        let total = self.total as f64;
+        self.map_cells(|i, j| {
+            let e = self.expected(i, j);
+            let v =
+                e * (1. - self.row_sums[i] as f64 / total) * (1. - self.col_sums[j] as f64 / total);
+            (self.count(i, j) as f64 - e) / v.sqrt()
+        })
+    }
+
+    /// Pearson's chi-square statistic for the test of independence.
+    ///
+    /// If `yates_correction` is `true` and the table is 2x2, Yates' continuity correction is applied as in
+    /// `R`'s `chisq.test` function. For larger tables, `yates_correction` is ignored.
+    pub fn chi2(&self, yates_correction: bool) -> f64 {
+        let diff = |i: usize, j: usize| (self.count(i, j) as f64 - self.expected(i, j)).abs();
+        let yates = if yates_correction && self.n_rows == 2 && self.n_cols == 2 {
+            self.cells().map(|(i, j)| diff(i, j)).fold(0.5, f64::min)
+        } else {
+            0.
+        };
+        self.cells()
+            .map(|(i, j)| (diff(i, j) - yates).powi(2) / self.expected(i, j))
+            .sum()
+    }
+
+    fn chi_squared_sf(&self, statistic: f64) -> f64 {
+        let chi_squared = ChiSquared::new(self.df())
+            .expect("`ChiSquared::new` arg `freedom` should be guaranteed to be positive");
+        chi_squared.sf(statistic)
+    }
+
+    /// p-value of Pearson's chi-square test of independence.
+    ///
+    /// See [`chi2`](Self::chi2) regarding `yates_correction`.
+    pub fn chi2_p(&self, yates_correction: bool) -> f64 {
+        self.chi_squared_sf(self.chi2(yates_correction))
+    }
+
+    /// Pearson's chi-square test of independence of rows and columns.
+    ///
+    /// See [`chi2`](Self::chi2) regarding `yates_correction`. The returned result's alternative hypothesis
+    /// is [`AltHyp::Ne`].
+    ///
+    /// Arguments:
+    /// - `yates_correction`: whether to apply Yates' continuity correction to 2x2 tables.
+    /// - `alpha`: confidence level = `1 - alpha`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `alpha` not in interval `(0, 1)`.
+    pub fn chi2_test(&self, yates_correction: bool, alpha: f64) -> StatsResult<HypTestResult> {
+        check_alpha_in_open_0_1(alpha)?;
+        let p = self.chi2_p(yates_correction);
+        Ok(HypTestResult::new(p, alpha, AltHyp::Ne))
+    }
+
+    /// Likelihood-ratio G statistic for the test of independence, `2 * sum(observed * ln(observed / expected))`,
+    /// where cells with zero observed count contribute `0`.
+    pub fn g(&self) -> f64 {
+        2. * self
+            .cells()
+            .map(|(i, j)| {
+                let o = self.count(i, j) as f64;
+                if o == 0. {
+                    0.
+                } else {
+                    o * (o / self.expected(i, j)).ln()
+                }
+            })
+            .sum::<f64>()
+    }
+
+    /// p-value of the likelihood-ratio G-test of independence.
+    pub fn g_p(&self) -> f64 {
+        self.chi_squared_sf(self.g())
+    }
+
+    /// Likelihood-ratio G-test of independence of rows and columns.
+    ///
+    /// The returned result's alternative hypothesis is [`AltHyp::Ne`].
+    ///
+    /// Arguments:
+    /// - `alpha`: confidence level = `1 - alpha`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `alpha` not in interval `(0, 1)`.
+    pub fn g_test(&self, alpha: f64) -> StatsResult<HypTestResult> {
+        check_alpha_in_open_0_1(alpha)?;
+        let p = self.g_p();
+        Ok(HypTestResult::new(p, alpha, AltHyp::Ne))
+    }
+
+    /// Cramér's V measure of association, `sqrt(chi2 / (n * (min(r, c) - 1)))`, where `chi2` is Pearson's
+    /// chi-square statistic without continuity correction and `n` is the total number of observations.
+    pub fn cramers_v(&self) -> f64 {
+        let k = self.n_rows.min(self.n_cols) - 1;
+        (self.chi2(false) / (self.total as f64 * k as f64)).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    //! Expected values follow `R`'s fisher.test function.
+    //! https://www.rdocumentation.org/packages/stats/versions/3.6.2/topics/fisher.test
+    //! Because `R` uses `uniroot` with a loose default tolerance, the expected odds ratio estimates and confidence
+    //! bounds were recomputed with high precision (using the `mpmath` Python library) following the same algorithm.
+    //! For example, for `R`'s TeaTasting data, `R` reports an odds ratio of 6.408309 and a confidence interval
+    //! of (0.2117329, 621.9337505), while the high precision values are 6.408320 and (0.2117356, 626.2435306).
+
+    use super::*;
+    use crate::{core::Hyp, dev_utils::ApproxEq};
+
+    const ALPHA: f64 = 0.05;
+    const EPSILON: f64 = 0.000005;
+
+    fn check_fisher(
+        table: [[u64; 2]; 2],
+        alt_hyp: AltHyp,
+        exp_p: f64,
+        exp_ci: Ci,
+        exp_odds_ratio: f64,
+        exp_accept_hyp: Hyp,
+    ) {
+        let p = fisher_exact_p(table, alt_hyp).unwrap();
+        let res = fisher_exact_test(table, alt_hyp, ALPHA).unwrap();
+        let ci = fisher_exact_alt_hyp_ci(table, alt_hyp, ALPHA).unwrap();
+        let odds_ratio = fisher_exact_odds_ratio(table).unwrap();
+
+        if alt_hyp == AltHyp::Ne {
+            assert_eq!(ci, fisher_exact_ci(table, ALPHA).unwrap());
+        }
+
+        // Compare relative to magnitude, as odds ratios can be large.
+        let rel_eq = |exp: f64, act: f64| exp == act || exp.approx_eq(act, EPSILON * exp.max(1.));
+
+        assert!(
+            exp_p.approx_eq(p, EPSILON),
+            "table={table:?}, alt_hyp={alt_hyp:?} -> exp_p={exp_p}, p={p}"
+        );
+        assert!(
+            rel_eq(exp_ci.0, ci.0),
+            "table={table:?}, alt_hyp={alt_hyp:?} -> exp_ci.0={}, ci.0={}",
+            exp_ci.0,
+            ci.0
+        );
+        assert!(
+            rel_eq(exp_ci.1, ci.1),
+            "table={table:?}, alt_hyp={alt_hyp:?} -> exp_ci.1={}, ci.1={}",
+            exp_ci.1,
+            ci.1
+        );
+        assert!(
+            rel_eq(exp_odds_ratio, odds_ratio),
+            "table={table:?} -> exp_odds_ratio={exp_odds_ratio}, odds_ratio={odds_ratio}"
+        );
+
+        assert_eq!(p, res.p(), "table={table:?}, alt_hyp={alt_hyp:?} -> res.p");
+        assert_eq!(ALPHA, res.alpha());
+        assert_eq!(alt_hyp, res.alt_hyp());
+        assert_eq!(
+            exp_accept_hyp,
+            res.accepted(),
+            "table={table:?}, alt_hyp={alt_hyp:?} -> res.accepted"
+        );
+    }
+
+    #[test]
+    fn test_fisher_tea_tasting() {
+        let table = [[3, 1], [1, 3]];
+        let exp_odds_ratio = 6.408319658;
+
+        check_fisher(
+            table,
+            AltHyp::Lt,
+            0.9857142857,
+            Ci(0., 306.2368079),
+            exp_odds_ratio,
+            Hyp::Null,
+        );
+        check_fisher(
+            table,
+            AltHyp::Ne,
+            0.4857142857,
+            Ci(0.2117355954, 626.2435306),
+            exp_odds_ratio,
+            Hyp::Null,
+        );
+        check_fisher(
+            table,
+            AltHyp::Gt,
+            0.2428571429,
+            Ci(0.3135737675, f64::INFINITY),
+            exp_odds_ratio,
+            Hyp::Null,
+        );
+    }
+
+    #[test]
+    fn test_fisher_convictions() {
+        let table = [[2, 15], [10, 3]];
+        let exp_odds_ratio = 0.04693663905;
+
+        check_fisher(
+            table,
+            AltHyp::Lt,
+            0.0004651809434,
+            Ci(0., 0.2849595456),
+            exp_odds_ratio,
+            Hyp::Alt(AltHyp::Lt),
+        );
+        check_fisher(
+            table,
+            AltHyp::Ne,
+            0.0005367241191,
+            Ci(0.003317163951, 0.3631896024),
+            exp_odds_ratio,
+            Hyp::Alt(AltHyp::Ne),
+        );
+        check_fisher(
+            table,
+            AltHyp::Gt,
+            0.999984519,
+            Ci(0.004994494834, f64::INFINITY),
+            exp_odds_ratio,
+            Hyp::Null,
+        );
+    }
+
+    #[test]
+    fn test_fisher_asymmetric() {
+        let table = [[12, 5], [7, 9]];
+        let exp_odds_ratio = 2.976780524;
+
+        check_fisher(
+            table,
+            AltHyp::Lt,
+            0.9727818886,
+            Ci(0., 12.9842907),
+            exp_odds_ratio,
+            Hyp::Null,
+        );
+        check_fisher(
+            table,
+            AltHyp::Ne,
+            0.1663200658,
+            Ci(0.5992659658, 16.64737227),
+            exp_odds_ratio,
+            Hyp::Null,
+        );
+        check_fisher(
+            table,
+            AltHyp::Gt,
+            0.1136738083,
+            Ci(0.7425877367, f64::INFINITY),
+            exp_odds_ratio,
+            Hyp::Null,
+        );
+    }
+
+    #[test]
+    fn test_fisher_boundary() {
+        // `table[0][0]` at the minimum allowed by the margins.
+        check_fisher(
+            [[0, 5], [5, 0]],
+            AltHyp::Ne,
+            0.007936507937,
+            Ci(0., 0.4353412057),
+            0.,
+            Hyp::Alt(AltHyp::Ne),
+        );
+
+        // `table[0][0]` at the maximum allowed by the margins.
+        check_fisher(
+            [[5, 0], [3, 4]],
+            AltHyp::Ne,
+            0.08080808081,
+            Ci(0.5726971047, f64::INFINITY),
+            f64::INFINITY,
+            Hyp::Null,
+        );
+        check_fisher(
+            [[5, 0], [3, 4]],
+            AltHyp::Gt,
+            0.07070707071,
+            Ci(0.8191863588, f64::INFINITY),
+            f64::INFINITY,
+            Hyp::Null,
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn check_table(
+        table: &ContingencyTable,
+        exp_chi2: f64,
+        exp_chi2_p: f64,
+        exp_g: f64,
+        exp_g_p: f64,
+        exp_cramers_v: f64,
+        exp_expected: &[&[f64]],
+        exp_residuals: &[&[f64]],
+        exp_std_residuals: &[&[f64]],
+    ) {
+        let check_vec = |name: &str, exp: &[&[f64]], act: Vec<Vec<f64>>| {
+            assert_eq!(exp.len(), act.len(), "{name}: number of rows");
+            for (exp_row, act_row) in exp.iter().zip(act.iter()) {
+                assert_eq!(exp_row.len(), act_row.len(), "{name}: number of columns");
+                for (e, a) in exp_row.iter().zip(act_row.iter()) {
+                    assert!(e.approx_eq(*a, EPSILON), "{name}: exp={e}, act={a}");
+                }
+            }
+        };
+
+        let chi2 = table.chi2(false);
+        let chi2_p = table.chi2_p(false);
+        let g = table.g();
+        let g_p = table.g_p();
+        let cramers_v = table.cramers_v();
+
+        assert!(
+            exp_chi2.approx_eq(chi2, EPSILON),
+            "exp_chi2={exp_chi2}, chi2={chi2}"
+        );
+        assert!(
+            exp_chi2_p.approx_eq(chi2_p, EPSILON),
+            "exp_chi2_p={exp_chi2_p}, chi2_p={chi2_p}"
+        );
+        assert!(exp_g.approx_eq(g, EPSILON), "exp_g={exp_g}, g={g}");
+        assert!(
+            exp_g_p.approx_eq(g_p, EPSILON),
+            "exp_g_p={exp_g_p}, g_p={g_p}"
+        );
+        assert!(
+            exp_cramers_v.approx_eq(cramers_v, EPSILON),
+            "exp_cramers_v={exp_cramers_v}, cramers_v={cramers_v}"
+        );
+        check_vec("expected_counts", exp_expected, table.expected_counts());
+        check_vec("residuals", exp_residuals, table.residuals());
+        check_vec("std_residuals", exp_std_residuals, table.std_residuals());
+
+        let chi2_res = table.chi2_test(false, ALPHA).unwrap();
+        assert_eq!(chi2_p, chi2_res.p());
+        assert_eq!(AltHyp::Ne, chi2_res.alt_hyp());
+        let g_res = table.g_test(ALPHA).unwrap();
+        assert_eq!(g_p, g_res.p());
+        assert_eq!(AltHyp::Ne, g_res.alt_hyp());
+    }
+
+    #[test]
+    fn test_table_party_gender() {
+        // `R`'s chisq.test documentation example.
+        let table = ContingencyTable::from_counts(&[[762, 327, 468], [484, 239, 477]]).unwrap();
+        assert_eq!(2, table.n_rows());
+        assert_eq!(3, table.n_cols());
+        assert_eq!(2., table.df());
+        assert_eq!(&[1557, 1200], table.row_sums());
+        assert_eq!(&[1246, 566, 945], table.col_sums());
+        assert_eq!(2757, table.total());
+        assert_eq!(239, table.count(1, 1));
+
+        check_table(
+            &table,
+            30.0701491,
+            2.953589e-07,
+            30.0166926,
+            3.033598e-07,
+            0.1044358,
+            &[
+                &[703.6713819, 319.6452666, 533.6833515],
+                &[542.3286181, 246.3547334, 411.3166485],
+            ],
+            &[
+                &[2.1988558, 0.4113702, -2.8432397],
+                &[-2.5046695, -0.4685829, 3.2386734],
+            ],
+            &[
+                &[4.5020535, 0.6994517, -5.3159455],
+                &[-4.5020535, -0.6994517, 5.3159455],
+            ],
+        );
+
+        // Yates' correction is ignored for tables larger than 2x2.
+        assert_eq!(table.chi2(false), table.chi2(true));
+        assert_eq!(
+            Hyp::Alt(AltHyp::Ne),
+            table.chi2_test(true, ALPHA).unwrap().accepted()
+        );
+    }
+
+    #[test]
+    fn test_table_2x2() {
+        let table = ContingencyTable::from_counts(&[[12, 5], [7, 9]]).unwrap();
+
+        check_table(
+            &table,
+            2.4305755,
+            0.1189892,
+            2.4600430,
+            0.1167756,
+            0.2713923,
+            &[&[9.7878788, 7.2121212], &[9.2121212, 6.7878788]],
+            &[&[0.7070736, -0.8237158], &[-0.7288348, 0.8490668]],
+            &[&[1.5590303, -1.5590303], &[-1.5590303, 1.5590303]],
+        );
+
+        let chi2 = table.chi2(true);
+        let p = table.chi2_p(true);
+        assert!(1.4559964.approx_eq(chi2, EPSILON), "chi2={chi2}");
+        assert!(0.2275682.approx_eq(p, EPSILON), "p={p}");
+        assert_eq!(Hyp::Null, table.chi2_test(true, ALPHA).unwrap().accepted());
+    }
+
+    #[test]
+    fn test_table_3x3_with_zeros() {
+        let table = ContingencyTable::from_counts(&[[10, 0, 3], [2, 8, 6], [1, 1, 9]]).unwrap();
+        assert_eq!(4., table.df());
+
+        check_table(
+            &table,
+            25.8015062,
+            3.469864e-05,
+            26.3912140,
+            2.638607e-05,
+            0.5679074,
+            &[
+                &[4.225, 2.925, 5.85],
+                &[5.2, 3.6, 7.2],
+                &[3.575, 2.475, 4.95],
+            ],
+            &[
+                &[2.8095621, -1.7102631, -1.1783300],
+                &[-1.4032928, 2.3190036, -0.4472136],
+                &[-1.3618812, -0.9375715, 1.8203396],
+            ],
+            &[
+                &[4.1623142, -2.3646137, -1.9338986],
+                &[-2.2050619, 3.4007519, -0.7784989],
+                &[-1.9467865, -1.2507905, 2.8827174],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_table_from_paired_iterators() {
+        let platforms = [
+            "linux", "mac", "linux", "windows", "mac", "linux", "windows",
+        ];
+        let failures = [
+            "timeout", "crash", "crash", "timeout", "crash", "timeout", "oom",
+        ];
+
+        let (table, rows, cols) =
+            ContingencyTable::from_paired_iterators(platforms.into_iter(), failures.into_iter())
+                .unwrap();
+
+        assert_eq!(vec!["linux", "mac", "windows"], rows);
+        assert_eq!(vec!["crash", "oom", "timeout"], cols);
+        assert_eq!(
+            ContingencyTable::from_counts(&[[1, 0, 2], [2, 0, 0], [0, 1, 1]]).unwrap(),
+            table
+        );
+    }
+}
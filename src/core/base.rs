@@ -247,6 +247,132 @@ impl Default for SampleMoments {
     }
 }
 
+/// Sample moments up to the fourth order, which support skewness and kurtosis in addition to the statistics
+/// supported by [`SampleMoments`]. Values are accumulated one at a time, so large samples can be processed in a
+/// streaming fashion.
+///
+/// Unlike [`SampleMoments`], this struct accumulates central moments with numerically stable one-pass updates,
+/// because higher-order power sums lose too much precision when the mean is large relative to the spread.
+#[derive(Debug, PartialEq)]
+pub struct HigherMoments {
+    n: u64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+}
+
+impl HigherMoments {
+    /// Instantiates `Self` with an empty sample.
+    pub fn new_empty() -> Self {
+        Self {
+            n: 0,
+            mean: 0.,
+            m2: 0.,
+            m3: 0.,
+            m4: 0.,
+        }
+    }
+
+    /// Updates `self` by accumulating an additional value.
+    pub fn collect_value(&mut self, value: f64) {
+        let n0 = self.n as f64;
+        self.n += 1;
+        let n = self.n as f64;
+        let delta = value - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * n0;
+        self.mean += delta_n;
+        self.m4 += term * delta_n2 * (n * n - 3. * n + 3.) + 6. * delta_n2 * self.m2
+            - 4. * delta_n * self.m3;
+        self.m3 += term * delta_n * (n - 2.) - 3. * delta_n * self.m2;
+        self.m2 += term;
+    }
+
+    /// Instantiates `Self` from a sample provided by an iterator.
+    pub fn from_iterator(dataset: impl Iterator<Item = f64>) -> Self {
+        let mut moments = HigherMoments::new_empty();
+        for v in dataset {
+            moments.collect_value(v);
+        }
+        moments
+    }
+
+    /// Instantiates `Self` from a sample provided by a slice.
+    pub fn from_slice(dataset: &[f64]) -> Self {
+        Self::from_iterator(dataset.iter().cloned())
+    }
+
+    /// Sample size as integer.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Sample size as floating point.
+    pub fn nf(&self) -> f64 {
+        self.n as f64
+    }
+
+    /// Sample mean.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `n == 0`.
+    pub fn mean(&self) -> StatsResult<f64> {
+        if self.n == 0 {
+            return Err(StatsError("sample size must be positive"));
+        }
+        Ok(self.mean)
+    }
+
+    /// Sample variance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `n <= 1`.
+    pub fn var(&self) -> StatsResult<f64> {
+        if self.n <= 1 {
+            return Err(StatsError("sample size must be greater than 1"));
+        }
+        Ok(self.m2 / (self.nf() - 1.))
+    }
+
+    fn check_m2(&self) -> StatsResult<()> {
+        if self.n == 0 || self.m2 <= 0. {
+            return Err(StatsError("sample must have at least two distinct values"));
+        }
+        Ok(())
+    }
+
+    /// Sample skewness `g1 = m3 / m2^(3/2)`, where `mk` is the `k`-th sample central moment (with divisor `n`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sample is empty or all its values are equal.
+    pub fn skewness(&self) -> StatsResult<f64> {
+        self.check_m2()?;
+        Ok(self.nf().sqrt() * self.m3 / self.m2.powf(1.5))
+    }
+
+    /// Sample excess kurtosis `g2 = m4 / m2^2 - 3`, where `mk` is the `k`-th sample central moment (with divisor
+    /// `n`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sample is empty or all its values are equal.
+    pub fn excess_kurtosis(&self) -> StatsResult<f64> {
+        self.check_m2()?;
+        Ok(self.nf() * self.m4 / (self.m2 * self.m2) - 3.)
+    }
+}
+
+impl Default for HigherMoments {
+    fn default() -> Self {
+        Self::new_empty()
+    }
+}
+
 /// Alternative statistical hypothesis to the null hypothesis of equality.
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum AltHyp {
@@ -419,6 +545,44 @@ mod test {
         assert_eq!(moments_i, moments_d);
     }
 
+    #[test]
+    fn test_higher_moments() {
+        let x = [14., 15., 15., 15., 16., 18., 22., 23., 24., 25., 25.];
+        let skewness = 0.154280635783522;
+        let excess_kurtosis = -1.73621770651535;
+
+        let moments = HigherMoments::from_slice(&x);
+        let moments_s = SampleMoments::from_slice(&x);
+        assert_eq!(11, moments.n());
+        assert_eq!(11., moments.nf());
+        assert!(
+            moments_s
+                .mean()
+                .unwrap()
+                .approx_eq(moments.mean().unwrap(), EPSILON)
+        );
+        assert!(
+            moments_s
+                .var()
+                .unwrap()
+                .approx_eq(moments.var().unwrap(), EPSILON)
+        );
+        assert!(skewness.approx_eq(moments.skewness().unwrap(), EPSILON));
+        assert!(excess_kurtosis.approx_eq(moments.excess_kurtosis().unwrap(), EPSILON));
+
+        // Shifting by a large constant does not change the results.
+        let shifted = HigherMoments::from_iterator(x.iter().map(|v| v + 1e9));
+        assert!(skewness.approx_eq(shifted.skewness().unwrap(), EPSILON));
+        assert!(excess_kurtosis.approx_eq(shifted.excess_kurtosis().unwrap(), EPSILON));
+
+        let empty = HigherMoments::default();
+        assert_eq!(HigherMoments::new_empty(), empty);
+        assert!(empty.mean().is_err());
+        assert!(empty.var().is_err());
+        assert!(empty.skewness().is_err());
+        assert!(empty.excess_kurtosis().is_err());
+    }
+
     #[test]
     fn test_ci() {
         let ci = Ci(0., 1.);
@@ -0,0 +1,331 @@
+//! Empirical cumulative distribution function of a sample.
+
+use super::{Ci, StatsError, StatsResult, iter_with_counts};
+
+/// Empirical cumulative distribution function (ECDF) of a sample.
+///
+/// Stores the sample's distinct values in increasing order, together with the cumulative counts of items
+/// less than or equal to each value.
+///
+/// # Example
+///
+/// ```
+#[doc = include_str!("../../examples/ecdf.rs")]
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ecdf {
+    values: Vec<f64>,
+    cum_counts: Vec<u64>,
+}
+
+impl Ecdf {
+    /// Instantiates `Self` from a sample in the form of an iterator of pairs. Each item returned
+    /// by the iterator is a pair whose first component is a data value and the second component is the
+    /// number of occurrences of the value in the sample.
+    ///
+    /// Pairs with a zero count are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in any of these conditions:
+    /// - The iterator does not yield data values in strictly increasing order.
+    /// - The sample is empty.
+    pub fn from_iter_with_counts(itc: impl Iterator<Item = (f64, u64)>) -> StatsResult<Self> {
+        let mut values = Vec::new();
+        let mut cum_counts = Vec::new();
+        let mut n = 0;
+        let mut prev: Option<f64> = None;
+
+        for (value, count) in itc {
+            match prev {
+                Some(prev) if prev < value => (),
+                None if !value.is_nan() => (),
+                _ => {
+                    return Err(StatsError(
+                        "invalid iterator argument: items not ordered properly",
+                    ));
+                }
+            }
+            prev = Some(value);
+            if count == 0 {
+                continue;
+            }
+            n += count;
+            values.push(value);
+            cum_counts.push(n);
+        }
+
+        if n == 0 {
+            return Err(StatsError("sample size must be positive"));
+        }
+        Ok(Self { values, cum_counts })
+    }
+
+    /// Instantiates `Self` from a sample in the form of a slice, which need not be sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in any of these conditions:
+    /// - `dataset` is empty.
+    /// - `dataset` contains a NaN value.
+    pub fn from_slice(dataset: &[f64]) -> StatsResult<Self> {
+        if dataset.iter().any(|v| v.is_nan()) {
+            return Err(StatsError("arg `dataset` must not contain NaN values"));
+        }
+        let mut sorted = dataset.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        // Zeros of both signs compare equal but are ordered by `total_cmp`; normalize them.
+        sorted
+            .iter_mut()
+            .filter(|v| **v == 0.)
+            .for_each(|v| *v = 0.);
+        Self::from_iter_with_counts(iter_with_counts(sorted.into_iter()))
+    }
+
+    /// Sample size.
+    pub fn n(&self) -> u64 {
+        *self.cum_counts.last().unwrap_or(&0)
+    }
+
+    /// Distinct values of the sample, in increasing order.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Number of sample items less than or equal to `x`.
+    fn count_le(&self, x: f64) -> u64 {
+        let idx = self.values.partition_point(|&v| v <= x);
+        if idx == 0 {
+            0
+        } else {
+            self.cum_counts[idx - 1]
+        }
+    }
+
+    /// Value of the ECDF at `x`, i.e., the fraction of sample items less than or equal to `x`.
+    pub fn eval(&self, x: f64) -> f64 {
+        self.count_le(x) as f64 / self.n() as f64
+    }
+
+    /// Inverse of the ECDF: the smallest sample value `x` such that `self.eval(x) >= q`.
+    ///
+    /// This is the sample quantile of type 1 in `R`'s `quantile` function.
+    /// For `q == 0`, returns the sample minimum.
+    /// As in `R`, `q * n` is reduced by a relative tolerance of `4 * f64::EPSILON` before rounding up, so that
+    /// rounding errors in `q` (e.g., `0.07 * 100 == 7.000000000000001`) don't select the next sample value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `q` is not in the interval `[0, 1]`.
+    pub fn quantile(&self, q: f64) -> StatsResult<f64> {
+        if !(0. ..=1.).contains(&q) {
+            return Err(StatsError("arg `q` must be in interval [0, 1]"));
+        }
+        let nq = q * self.n() as f64;
+        let target = (nq - 4. * f64::EPSILON * nq).ceil() as u64;
+        let idx = self
+            .cum_counts
+            .partition_point(|&c| c < target)
+            .min(self.values.len() - 1);
+        Ok(self.values[idx])
+    }
+
+    /// Half-width of the Dvoretzky–Kiefer–Wolfowitz simultaneous confidence band for the population CDF,
+    /// `sqrt(ln(2 / alpha) / (2 * n))`.
+    ///
+    /// Arguments:
+    /// - `alpha`: complement of the confidence level of the band.
+    ///
+    /// Reference: Massart, P. (1990). The tight constant in the Dvoretzky–Kiefer–Wolfowitz inequality.
+    /// *The Annals of Probability*, 18(3), 1269–1283.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `alpha` not in open interval `(0, 1)`.
+    pub fn dkw_epsilon(&self, alpha: f64) -> StatsResult<f64> {
+        if !(0. < alpha && alpha < 1.) {
+            return Err(StatsError("arg `alpha` must be in interval (0, 1)"));
+        }
+        Ok(((2. / alpha).ln() / (2. * self.n() as f64)).sqrt())
+    }
+
+    /// Dvoretzky–Kiefer–Wolfowitz confidence band for the population CDF, evaluated at `x`.
+    ///
+    /// The band is the ECDF at `x` plus or minus [`Self::dkw_epsilon`], clipped to `[0, 1]`. It covers the
+    /// entire population CDF simultaneously with probability at least `1 - alpha`.
+    ///
+    /// Arguments:
+    /// - `x`: point at which the band is evaluated.
+    /// - `alpha`: complement of the confidence level of the band.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `alpha` not in open interval `(0, 1)`.
+    pub fn dkw_band(&self, x: f64, alpha: f64) -> StatsResult<Ci> {
+        let eps = self.dkw_epsilon(alpha)?;
+        let f = self.eval(x);
+        Ok(Ci((f - eps).max(0.), (f + eps).min(1.)))
+    }
+
+    /// Coordinates of the normal Q-Q plot of the sample, one pair per sample item in increasing order.
+    ///
+    /// The first component of each pair is the standard normal quantile of the plotting position
+    /// `(i - a) / (n + 1 - 2a)`, for `i` in `1..=n`, where `a = 3/8` if `n <= 10` and `a = 1/2` otherwise;
+    /// the second component is the `i`-th smallest sample value. This matches `R`'s `qqnorm`.
+    #[cfg(feature = "normal")]
+    pub fn normal_qq(&self) -> Vec<(f64, f64)> {
+        use statrs::distribution::{ContinuousCDF, Normal};
+
+        let normal = Normal::standard();
+        let n = self.n();
+        let a = if n <= 10 { 3. / 8. } else { 0.5 };
+        let denom = n as f64 + 1. - 2. * a;
+
+        let mut prev_cum = 0;
+        let mut coords = Vec::with_capacity(n as usize);
+        for (&value, &cum) in self.values.iter().zip(self.cum_counts.iter()) {
+            for i in prev_cum + 1..=cum {
+                let pos = (i as f64 - a) / denom;
+                coords.push((normal.inverse_cdf(pos), value));
+            }
+            prev_cum = cum;
+        }
+        coords
+    }
+}
+
+#[cfg(test)]
+mod test {
+    //! Expected values are computed by hand from the definitions; normal quantiles come from `mpmath`.
+
+    use super::*;
+    use crate::dev_utils::ApproxEq;
+
+    const EPSILON: f64 = 1e-12;
+    const ALPHA: f64 = 0.05;
+
+    const DAT: [f64; 10] = [3.1, -1., 2., 0.5, 2., 7., 3.1, 2., -0., 0.];
+
+    #[test]
+    fn test_construction() {
+        let ecdf = Ecdf::from_slice(&DAT).unwrap();
+        assert_eq!(10, ecdf.n());
+        assert_eq!(&[-1., 0., 0.5, 2., 3.1, 7.], ecdf.values());
+
+        let mut sorted = DAT;
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let ecdf_itc = Ecdf::from_iter_with_counts(iter_with_counts(sorted.into_iter())).unwrap();
+        assert_eq!(ecdf, ecdf_itc);
+
+        let ecdf_zero =
+            Ecdf::from_iter_with_counts([(1., 0), (2., 3), (4., 0)].into_iter()).unwrap();
+        assert_eq!(3, ecdf_zero.n());
+        assert_eq!(&[2.], ecdf_zero.values());
+    }
+
+    #[test]
+    fn test_eval() {
+        let ecdf = Ecdf::from_slice(&DAT).unwrap();
+        assert_eq!(0., ecdf.eval(-1.5));
+        assert_eq!(0.1, ecdf.eval(-1.));
+        assert_eq!(0.3, ecdf.eval(0.));
+        assert_eq!(0.4, ecdf.eval(1.));
+        assert_eq!(0.7, ecdf.eval(2.));
+        assert_eq!(0.9, ecdf.eval(3.1));
+        assert_eq!(1., ecdf.eval(7.));
+        assert_eq!(1., ecdf.eval(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_quantile() {
+        let ecdf = Ecdf::from_slice(&DAT).unwrap();
+        let cases = [
+            (0., -1.),
+            (0.1, -1.),
+            (0.15, 0.),
+            (0.3, 0.),
+            (0.35, 0.5),
+            (0.5, 2.),
+            (0.7, 2.),
+            (0.71, 3.1),
+            (0.95, 7.),
+            (1., 7.),
+        ];
+        for (q, exp) in cases {
+            assert_eq!(exp, ecdf.quantile(q).unwrap(), "q={q}");
+        }
+
+        // Inverse relationship with `eval`.
+        for v in ecdf.values() {
+            assert_eq!(*v, ecdf.quantile(ecdf.eval(*v)).unwrap());
+        }
+
+        // Exact multiples of `1 / n` select the corresponding order statistic despite rounding errors in `q * n`,
+        // e.g., `0.07 * 100 == 7.000000000000001`.
+        for n in [3, 10, 49, 100, 1000] {
+            let dat: Vec<f64> = (1..=n).map(f64::from).collect();
+            let ecdf = Ecdf::from_slice(&dat).unwrap();
+            for k in 1..=n {
+                let x = f64::from(k);
+                assert_eq!(x, ecdf.quantile(x / f64::from(n)).unwrap(), "n={n}, k={k}");
+                assert_eq!(x, ecdf.quantile(ecdf.eval(x)).unwrap(), "n={n}, k={k}");
+            }
+            if n == 100 {
+                assert_eq!(7., ecdf.quantile(0.07).unwrap());
+                assert_eq!(8., ecdf.quantile(0.0701).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_dkw() {
+        let ecdf = Ecdf::from_slice(&DAT).unwrap();
+        let exp_eps = 0.4294694083467376;
+        let eps = ecdf.dkw_epsilon(ALPHA).unwrap();
+        assert!(
+            exp_eps.approx_eq(eps, EPSILON),
+            "exp_eps={exp_eps}, eps={eps}"
+        );
+
+        let Ci(lo, hi) = ecdf.dkw_band(2., ALPHA).unwrap();
+        assert!((0.7 - exp_eps).approx_eq(lo, EPSILON));
+        assert_eq!(1., hi);
+
+        let Ci(lo, hi) = ecdf.dkw_band(-1., ALPHA).unwrap();
+        assert_eq!(0., lo);
+        assert!((0.1 + exp_eps).approx_eq(hi, EPSILON));
+    }
+
+    #[cfg(feature = "normal")]
+    #[test]
+    fn test_normal_qq() {
+        let ecdf = Ecdf::from_slice(&DAT).unwrap();
+        let qq = ecdf.normal_qq();
+        assert_eq!(10, qq.len());
+
+        let exp_x = [
+            -1.54663527139923,
+            -1.000490545619315,
+            -0.6554235052344266,
+            -0.3754617702355184,
+            -0.1225808438888024,
+            0.1225808438888024,
+            0.3754617702355184,
+            0.6554235052344266,
+            1.000490545619315,
+            1.54663527139923,
+        ];
+        let exp_y = [-1., 0., 0., 0.5, 2., 2., 2., 3.1, 3.1, 7.];
+        for i in 0..10 {
+            assert!(exp_x[i].approx_eq(qq[i].0, EPSILON), "i={i}");
+            assert_eq!(exp_y[i], qq[i].1, "i={i}");
+        }
+
+        // For n > 10, the plotting positions are (i - 1/2) / n.
+        let dat: Vec<f64> = (1..=20).map(|i| i as f64).collect();
+        let qq = Ecdf::from_slice(&dat).unwrap().normal_qq();
+        let exp_x0 = -1.959963984540054;
+        assert!(exp_x0.approx_eq(qq[0].0, EPSILON));
+        assert!((-exp_x0).approx_eq(qq[19].0, EPSILON));
+    }
+}
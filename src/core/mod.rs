@@ -5,12 +5,20 @@
 mod base;
 #[cfg(feature = "normal")]
 mod check_interval;
+mod ecdf;
 mod error;
 mod iter;
+#[cfg(any(feature = "normal", feature = "jackknife"))]
+mod numeric;
+mod rng;
 
 pub use base::*;
+pub use ecdf::*;
 pub use error::*;
 pub use iter::*;
+pub use rng::*;
 
 #[cfg(feature = "normal")]
 pub(crate) use check_interval::*;
+#[cfg(any(feature = "normal", feature = "jackknife"))]
+pub(crate) use numeric::*;
@@ -0,0 +1,276 @@
+//! Numerical utilities used internally by other modules.
+
+#[cfg(feature = "binomial")]
+use super::{AltHyp, Ci, StatsResult, check_alpha_in_open_0_1};
+
+/// Approximates the definite integral of `f` over the finite interval `[a, b]` using adaptive Simpson quadrature.
+///
+/// `tol` is the absolute error tolerance requested for the result.
+#[cfg(any(feature = "bayes", feature = "combine"))]
+pub fn integrate(f: impl Fn(f64) -> f64, a: f64, b: f64, tol: f64) -> f64 {
+    const MAX_DEPTH: u32 = 40;
+
+    fn simpson(fa: f64, fm: f64, fb: f64, a: f64, b: f64) -> f64 {
+        (b - a) / 6. * (fa + 4. * fm + fb)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn adapt(
+        f: &impl Fn(f64) -> f64,
+        a: f64,
+        b: f64,
+        fa: f64,
+        fm: f64,
+        fb: f64,
+        whole: f64,
+        tol: f64,
+        depth: u32,
+    ) -> f64 {
+        let m = (a + b) / 2.;
+        let lm = (a + m) / 2.;
+        let rm = (m + b) / 2.;
+        let flm = f(lm);
+        let frm = f(rm);
+        let left = simpson(fa, flm, fm, a, m);
+        let right = simpson(fm, frm, fb, m, b);
+        let delta = left + right - whole;
+        if depth == 0 || delta.abs() <= 15. * tol {
+            return left + right + delta / 15.;
+        }
+        adapt(f, a, m, fa, flm, fm, left, tol / 2., depth - 1)
+            + adapt(f, m, b, fm, frm, fb, right, tol / 2., depth - 1)
+    }
+
+    if a == b {
+        return 0.;
+    }
+
+    let m = (a + b) / 2.;
+    let fa = f(a);
+    let fm = f(m);
+    let fb = f(b);
+    let whole = simpson(fa, fm, fb, a, b);
+    adapt(&f, a, b, fa, fm, fb, whole, tol, MAX_DEPTH)
+}
+
+/// Finds a root of `f` in the interval `[a, b]` using Brent's method.
+///
+/// `f(a)` and `f(b)` must not have the same sign. `tol` is the absolute error tolerance for the root.
+/// Returns `None` if `f(a)` and `f(b)` have the same sign or any of them is `NaN`.
+#[cfg(feature = "normal")]
+pub fn find_root(f: impl Fn(f64) -> f64, a: f64, b: f64, tol: f64) -> Option<f64> {
+    const MAX_ITER: u32 = 200;
+
+    let (mut a, mut b) = (a, b);
+    let (mut fa, mut fb) = (f(a), f(b));
+    if fa.is_nan() || fb.is_nan() || fa * fb > 0. {
+        return None;
+    }
+    if fa == 0. {
+        return Some(a);
+    }
+    if fb == 0. {
+        return Some(b);
+    }
+
+    let (mut c, mut fc) = (a, fa);
+    let mut d = b - a;
+    let mut e = d;
+
+    for _ in 0..MAX_ITER {
+        if fb * fc > 0. {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let tol1 = 2. * f64::EPSILON * b.abs() + tol / 2.;
+        let m = (c - b) / 2.;
+        if m.abs() <= tol1 || fb == 0. {
+            return Some(b);
+        }
+
+        if e.abs() >= tol1 && fa.abs() > fb.abs() {
+            // Attempt inverse quadratic interpolation or secant step.
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2. * m * s, 1. - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2. * m * q * (q - r) - (b - a) * (r - 1.)),
+                    (q - 1.) * (r - 1.) * (s - 1.),
+                )
+            };
+            if p > 0. {
+                q = -q;
+            } else {
+                p = -p;
+            }
+            if 2. * p < (3. * m * q - (tol1 * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = m;
+                e = m;
+            }
+        } else {
+            d = m;
+            e = m;
+        }
+
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol1 { d } else { tol1.copysign(m) };
+        fb = f(b);
+        if fb.is_nan() {
+            return None;
+        }
+    }
+
+    Some(b)
+}
+
+/// Number of ways to choose `k` items out of `n`, or `None` if it exceeds `limit`.
+///
+/// Requires `k <= n`.
+#[cfg(any(
+    feature = "jackknife",
+    feature = "multinomial",
+    feature = "permutation"
+))]
+pub fn n_choose_k_up_to(n: u64, k: u64, limit: u64) -> Option<u64> {
+    let k = k.min(n - k);
+    let mut c: u128 = 1;
+    for i in 0..k {
+        c = c * (n - i) as u128 / (i + 1) as u128;
+        if c > limit as u128 {
+            return None;
+        }
+    }
+    Some(c as u64)
+}
+
+/// Advances `idx`, a strictly increasing sequence of indices in `0..n`, to the next combination of `idx.len()`
+/// indices in lexicographic order. Returns `false`, leaving `idx` unchanged, if `idx` is the last combination.
+#[cfg(any(feature = "jackknife", feature = "permutation"))]
+pub fn next_combination(idx: &mut [usize], n: usize) -> bool {
+    let k = idx.len();
+    let Some(i) = (0..k).rev().find(|&i| idx[i] != i + n - k) else {
+        return false;
+    };
+    idx[i] += 1;
+    for j in i + 1..k {
+        idx[j] = idx[j - 1] + 1;
+    }
+    true
+}
+
+/// Returns the first value in `lo..=hi` for which `pred` holds, or `hi + 1` if there is none, assuming that `pred`
+/// is monotonically non-decreasing (i.e., once it holds, it holds for all larger values).
+#[cfg(feature = "binomial")]
+pub fn first_true(lo: u64, hi: u64, pred: impl Fn(u64) -> bool) -> u64 {
+    let (mut lo, mut hi) = (lo, hi + 1);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) { hi = mid } else { lo = mid + 1 }
+    }
+    lo
+}
+
+/// Builds a confidence interval for a parameter in `[0, upper]` from functions that compute its low and high ends
+/// for a given tail probability. Two-sided intervals use `alpha / 2` for each end and one-sided intervals use `alpha`
+/// for their finite end.
+#[cfg(feature = "binomial")]
+pub fn tail_bounds_ci(
+    alt_hyp: AltHyp,
+    alpha: f64,
+    upper: f64,
+    lo: impl Fn(f64) -> StatsResult<f64>,
+    hi: impl Fn(f64) -> StatsResult<f64>,
+) -> StatsResult<Ci> {
+    check_alpha_in_open_0_1(alpha)?; // need this guard because `alpha / 2.` below masks errors
+    let ci = match alt_hyp {
+        AltHyp::Lt => Ci(0., hi(alpha)?),
+        AltHyp::Ne => Ci(lo(alpha / 2.)?, hi(alpha / 2.)?),
+        AltHyp::Gt => Ci(lo(alpha)?, upper),
+    };
+    Ok(ci)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "normal")]
+    use crate::dev_utils::ApproxEq;
+
+    #[cfg(any(feature = "bayes", feature = "combine"))]
+    #[test]
+    fn test_integrate() {
+        let v = integrate(|x| x.sin(), 0., std::f64::consts::PI, 1e-12);
+        assert!(2_f64.approx_eq(v, 1e-10), "v={v}");
+
+        let v = integrate(|x| (-x * x).exp(), -10., 10., 1e-12);
+        assert!(std::f64::consts::PI.sqrt().approx_eq(v, 1e-10), "v={v}");
+    }
+
+    #[cfg(feature = "normal")]
+    #[test]
+    fn test_find_root() {
+        let v = find_root(|x| x * x - 2., 0., 2., 1e-14).unwrap();
+        assert!(2_f64.sqrt().approx_eq(v, 1e-12), "v={v}");
+
+        let v = find_root(|x| x.cos() - x, 0., 1., 1e-14).unwrap();
+        assert!(0.7390851332151607.approx_eq(v, 1e-12), "v={v}");
+
+        assert_eq!(Some(1.), find_root(|x| x - 1., 1., 2., 1e-14));
+        assert!(find_root(|x| x * x + 1., -1., 1., 1e-14).is_none());
+    }
+
+    #[cfg(any(
+        feature = "jackknife",
+        feature = "multinomial",
+        feature = "permutation"
+    ))]
+    #[test]
+    fn test_n_choose_k() {
+        assert_eq!(Some(1716), n_choose_k_up_to(13, 7, 10000));
+        assert_eq!(Some(1716), n_choose_k_up_to(13, 6, 1716));
+        assert_eq!(None, n_choose_k_up_to(13, 6, 1715));
+        assert_eq!(Some(1), n_choose_k_up_to(5, 5, 1));
+        assert_eq!(Some(1), n_choose_k_up_to(5, 0, 1));
+        assert_eq!(None, n_choose_k_up_to(200, 100, u64::MAX));
+    }
+
+    #[cfg(any(feature = "jackknife", feature = "permutation"))]
+    #[test]
+    fn test_next_combination() {
+        let mut idx = vec![0, 1];
+        let mut combs = vec![idx.clone()];
+        while next_combination(&mut idx, 4) {
+            combs.push(idx.clone());
+        }
+        let exp = vec![
+            vec![0, 1],
+            vec![0, 2],
+            vec![0, 3],
+            vec![1, 2],
+            vec![1, 3],
+            vec![2, 3],
+        ];
+        assert_eq!(exp, combs);
+
+        let mut empty: Vec<usize> = vec![];
+        assert!(!next_combination(&mut empty, 3));
+    }
+}
@@ -0,0 +1,119 @@
+//! Seedable pseudo-random number generator for resampling methods.
+
+/// Small, fast, seedable pseudo-random number generator (xoshiro256\*\*), used by resampling methods so that
+/// their results are reproducible given a seed without depending on an external crate.
+///
+/// The 256-bit state is initialized from the 64-bit seed with the SplitMix64 generator, as recommended by the
+/// authors of xoshiro256\*\*. This generator is not suitable for cryptographic purposes.
+///
+/// Reference: Blackman, D. & Vigna, S. (2021). Scrambled linear pseudorandom number generators.
+/// *ACM Transactions on Mathematical Software*, 47(4), 1–32.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeededRng {
+    s: [u64; 4],
+}
+
+impl SeededRng {
+    /// Instantiates `Self` from a seed. Equal seeds produce equal sequences.
+    pub fn new(seed: u64) -> Self {
+        let mut sm = seed;
+        let mut s = [0; 4];
+        for x in s.iter_mut() {
+            sm = sm.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = sm;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            *x = z ^ (z >> 31);
+        }
+        Self { s }
+    }
+
+    /// Next pseudo-random `u64`, uniformly distributed over all `u64` values.
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Next pseudo-random `f64`, uniformly distributed in the interval `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1. / (1u64 << 53) as f64)
+    }
+
+    /// Next pseudo-random `u64`, uniformly distributed in `0..n`, without modulo bias (Lemire's method).
+    /// Returns `0` if `n == 0`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        let mut m = self.next_u64() as u128 * n as u128;
+        if (m as u64) < n {
+            let threshold = n.wrapping_neg() % n;
+            while (m as u64) < threshold {
+                m = self.next_u64() as u128 * n as u128;
+            }
+        }
+        (m >> 64) as u64
+    }
+
+    /// Shuffles `items` in place, with all permutations equally likely (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    //! Expected values come from an independent Python implementation of SplitMix64 and xoshiro256**.
+
+    use super::*;
+
+    #[test]
+    fn test_next() {
+        let mut rng = SeededRng::new(0);
+        assert_eq!(0x99ec5f36cb75f2b4, rng.next_u64());
+        assert_eq!(0xbf6e1f784956452a, rng.next_u64());
+        assert_eq!(0x1a5f849d4933e6e0, rng.next_u64());
+
+        let mut rng = SeededRng::new(42);
+        assert_eq!(0.08386297105988216, rng.next_f64());
+        assert_eq!(0.3789802506626686, rng.next_f64());
+        let draws: Vec<u64> = (0..10).map(|_| rng.below(10)).collect();
+        assert_eq!(vec![6, 9, 9, 7, 7, 8, 7, 5, 6, 2], draws);
+
+        assert_eq!(0, rng.below(0));
+        assert_eq!(0, rng.below(1));
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = SeededRng::new(7);
+        let mut items: Vec<u32> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!((0..20).collect::<Vec<_>>(), items);
+        items.sort();
+        assert_eq!((0..20).collect::<Vec<_>>(), items);
+
+        // Each position of a 3-item shuffle is roughly uniform.
+        let mut counts = [[0u32; 3]; 3];
+        for _ in 0..30000 {
+            let mut items = [0, 1, 2];
+            rng.shuffle(&mut items);
+            for (pos, &item) in items.iter().enumerate() {
+                counts[pos][item] += 1;
+            }
+        }
+        for row in counts {
+            for c in row {
+                assert!((9500..10500).contains(&c), "c={c}");
+            }
+        }
+    }
+}
@@ -0,0 +1,350 @@
+//! Jackknife estimates of the bias and standard error of arbitrary statistics, a deterministic, cheaper
+//! alternative to the [`bootstrap`](crate::bootstrap) for smooth statistics.
+//!
+//! - For the leave-one-out jackknife of a statistic computed from a slice, use [`jackknife`].
+//! - For statistics that are functions of the sample size, sum, and sum of squares, use [`jackknife_moments`],
+//!   which computes each leave-one-out [`SampleMoments`] by subtracting one value from the full-sample moments,
+//!   in `O(n)` time overall instead of the `O(n^2)` of recomputing the statistic from each subsample.
+//! - For the delete-d jackknife, which is consistent for non-smooth statistics such as the median when `d` grows
+//!   with the sample size, use [`jackknife_delete_d`].
+//!
+//! This module is included by default. However, if `default-features = false` is specified in the dependency
+//! declaration for this library, then inclusion of this module is gated by feature "**jackknife**".
+//!
+//! # Example
+//!
+//! ```
+#![doc = include_str!("../examples/jackknife.rs")]
+//! ```
+
+use crate::core::{
+    SampleMoments, SeededRng, StatsError, StatsResult, n_choose_k_up_to, next_combination,
+};
+
+/// Jackknife estimates for a statistic.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct JackknifeEstimate {
+    estimate: f64,
+    bias: f64,
+    se: f64,
+}
+
+impl JackknifeEstimate {
+    /// Value of the statistic on the full sample.
+    pub fn estimate(&self) -> f64 {
+        self.estimate
+    }
+
+    /// Jackknife estimate of the statistic's bias.
+    pub fn bias(&self) -> f64 {
+        self.bias
+    }
+
+    /// Jackknife estimate of the statistic's standard error.
+    pub fn se(&self) -> f64 {
+        self.se
+    }
+
+    /// Bias-corrected estimate, `self.estimate() - self.bias()`.
+    pub fn bias_corrected(&self) -> f64 {
+        self.estimate - self.bias
+    }
+}
+
+/// Jackknife estimates from the statistic's value on the full sample and its `values` on subsamples that each
+/// delete `d` of the `n` items.
+///
+/// Reference: Shao, J. & Tu, D. (1995). *The Jackknife and Bootstrap*, section 2.3. Springer.
+fn estimate_from_values(
+    estimate: f64,
+    values: &[f64],
+    n: usize,
+    d: usize,
+) -> StatsResult<JackknifeEstimate> {
+    if !estimate.is_finite() || values.iter().any(|v| !v.is_finite()) {
+        return Err(StatsError(
+            "statistic must be finite for the data and all subsamples",
+        ));
+    }
+    let n_values = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n_values;
+    let ss = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>();
+    let factor = (n - d) as f64 / d as f64;
+    Ok(JackknifeEstimate {
+        estimate,
+        bias: factor * (mean - estimate),
+        se: (factor * ss / n_values).sqrt(),
+    })
+}
+
+/// Leave-one-out values of a statistic: the values of `stat` on the `n` subsamples of `dataset` that each omit
+/// one item, in the order of the omitted items.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `stat`: statistic, computed from a slice of data.
+///
+/// # Errors
+///
+/// Returns an error if `dataset.len() < 2`.
+pub fn jackknife_values(dataset: &[f64], stat: impl Fn(&[f64]) -> f64) -> StatsResult<Vec<f64>> {
+    if dataset.len() < 2 {
+        return Err(StatsError("sample size must be at least `2`"));
+    }
+    let mut buf = Vec::with_capacity(dataset.len() - 1);
+    let values = (0..dataset.len())
+        .map(|i| {
+            buf.clear();
+            buf.extend_from_slice(&dataset[..i]);
+            buf.extend_from_slice(&dataset[i + 1..]);
+            stat(&buf)
+        })
+        .collect();
+    Ok(values)
+}
+
+/// Leave-one-out jackknife estimates of the bias and standard error of a statistic.
+///
+/// With `n` the sample size, `theta` the statistic on the full sample, and `theta_i` and `theta_mean` the
+/// leave-one-out values and their mean, the bias is `(n - 1) * (theta_mean - theta)` and the standard error is
+/// `sqrt((n - 1) / n * sum((theta_i - theta_mean)^2))`.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `stat`: statistic, computed from a slice of data.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len() < 2`.
+/// - `stat` returns a non-finite value for `dataset` or for any subsample.
+pub fn jackknife(dataset: &[f64], stat: impl Fn(&[f64]) -> f64) -> StatsResult<JackknifeEstimate> {
+    let values = jackknife_values(dataset, &stat)?;
+    estimate_from_values(stat(dataset), &values, dataset.len(), 1)
+}
+
+/// Leave-one-out jackknife estimates of the bias and standard error of a statistic that is a function of
+/// [`SampleMoments`], such as the coefficient of variation.
+///
+/// Each leave-one-out [`SampleMoments`] is computed by subtracting the omitted value from the full-sample
+/// sum and sum of squares. The minimum and maximum of all the [`SampleMoments`] passed to `stat` are `NaN`.
+/// The results are otherwise the same as those of [`jackknife`] with the equivalent statistic, up to rounding.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `stat`: statistic, computed from the moments of a sample.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len() < 2`.
+/// - `stat` returns a non-finite value for `dataset` or for any subsample.
+pub fn jackknife_moments(
+    dataset: &[f64],
+    stat: impl Fn(&SampleMoments) -> f64,
+) -> StatsResult<JackknifeEstimate> {
+    if dataset.len() < 2 {
+        return Err(StatsError("sample size must be at least `2`"));
+    }
+    let n = dataset.len() as u64;
+    let sum = dataset.iter().sum::<f64>();
+    let sum2 = dataset.iter().map(|v| v * v).sum::<f64>();
+    let values: Vec<f64> = dataset
+        .iter()
+        .map(|v| stat(&SampleMoments::new(n - 1, sum - v, sum2 - v * v)))
+        .collect();
+    let estimate = stat(&SampleMoments::new(n, sum, sum2));
+    estimate_from_values(estimate, &values, dataset.len(), 1)
+}
+
+/// Delete-d jackknife estimates of the bias and standard error of a statistic.
+///
+/// With `n` the sample size, `theta` the statistic on the full sample, and `theta_s` and `theta_mean` the
+/// values on the `N` subsamples that each omit `d` items and their mean, the bias is
+/// `(n - d) / d * (theta_mean - theta)` and the standard error is
+/// `sqrt((n - d) / (d * N) * sum((theta_s - theta_mean)^2))`. For `d == 1`, these are the leave-one-out
+/// estimates of [`jackknife`].
+///
+/// If `C(n, d) <= max_subsets`, all subsamples are used. Otherwise, `max_subsets` subsamples are drawn at
+/// random with the seedable [`SeededRng`], so results are reproducible given a seed.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `stat`: statistic, computed from a slice of data.
+/// - `d`: number of items omitted from each subsample.
+/// - `max_subsets`: maximum number of subsamples.
+/// - `seed`: seed of the pseudo-random number generator.
+///
+/// Reference: Shao, J. & Wu, C. F. J. (1989). A general theory for jackknife variance estimation.
+/// *The Annals of Statistics*, 17(3), 1176–1197.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `d == 0` or `d >= dataset.len()`.
+/// - `max_subsets < 2`.
+/// - `stat` returns a non-finite value for `dataset` or for any subsample.
+pub fn jackknife_delete_d(
+    dataset: &[f64],
+    stat: impl Fn(&[f64]) -> f64,
+    d: usize,
+    max_subsets: u64,
+    seed: u64,
+) -> StatsResult<JackknifeEstimate> {
+    let n = dataset.len();
+    if d == 0 || d >= n {
+        return Err(StatsError(
+            "arg `d` must be positive and less than the sample size",
+        ));
+    }
+    if max_subsets < 2 {
+        return Err(StatsError("arg `max_subsets` must be at least `2`"));
+    }
+
+    let mut buf = Vec::with_capacity(n - d);
+    let mut deleted = vec![false; n];
+    let mut stat_without = |omitted: &[usize]| {
+        deleted.iter_mut().for_each(|b| *b = false);
+        omitted.iter().for_each(|&i| deleted[i] = true);
+        buf.clear();
+        buf.extend(
+            dataset
+                .iter()
+                .zip(deleted.iter())
+                .filter(|(_, del)| !**del)
+                .map(|(v, _)| *v),
+        );
+        stat(&buf)
+    };
+
+    let mut values = Vec::new();
+    if n_choose_k_up_to(n as u64, d as u64, max_subsets).is_some() {
+        let mut omitted: Vec<usize> = (0..d).collect();
+        loop {
+            values.push(stat_without(&omitted));
+            if !next_combination(&mut omitted, n) {
+                break;
+            }
+        }
+    } else {
+        let mut rng = SeededRng::new(seed);
+        let mut idx: Vec<usize> = (0..n).collect();
+        for _ in 0..max_subsets {
+            rng.shuffle(&mut idx);
+            values.push(stat_without(&idx[..d]));
+        }
+    }
+
+    estimate_from_values(stat(dataset), &values, n, d)
+}
+
+#[cfg(test)]
+mod test {
+    //! Expected values follow from closed-form jackknife results for the mean and the plug-in variance, and from
+    //! a brute-force Python implementation for the median.
+
+    use super::*;
+    use crate::dev_utils::ApproxEq;
+
+    const EPSILON: f64 = 1e-10;
+
+    const DAT: [f64; 9] = [12.1, 11.8, 13.5, 12.9, 14.2, 11.2, 12.4, 15.9, 13.3];
+
+    fn mean(s: &[f64]) -> f64 {
+        s.iter().sum::<f64>() / s.len() as f64
+    }
+
+    fn plugin_var(s: &[f64]) -> f64 {
+        let m = mean(s);
+        s.iter().map(|v| (v - m).powi(2)).sum::<f64>() / s.len() as f64
+    }
+
+    fn median(s: &[f64]) -> f64 {
+        let mut v = s.to_vec();
+        v.sort_by(f64::total_cmp);
+        let n = v.len();
+        if n % 2 == 1 {
+            v[n / 2]
+        } else {
+            (v[n / 2 - 1] + v[n / 2]) / 2.
+        }
+    }
+
+    #[test]
+    fn test_jackknife() {
+        let moments = SampleMoments::from_slice(&DAT);
+        let exp_se = moments.stdev().unwrap() / (DAT.len() as f64).sqrt();
+
+        // The mean is unbiased and its jackknife standard error is `s / sqrt(n)`.
+        let est = jackknife(&DAT, mean).unwrap();
+        assert!(moments.mean().unwrap().approx_eq(est.estimate(), EPSILON));
+        assert!(0_f64.approx_eq(est.bias(), EPSILON), "bias={}", est.bias());
+        assert!(
+            exp_se.approx_eq(est.se(), EPSILON),
+            "exp_se={exp_se}, se={}",
+            est.se()
+        );
+
+        // The bias-corrected plug-in variance is the unbiased sample variance.
+        let est = jackknife(&DAT, plugin_var).unwrap();
+        let exp_bias = -plugin_var(&DAT) / (DAT.len() as f64 - 1.);
+        assert!(exp_bias.approx_eq(est.bias(), EPSILON));
+        assert!(
+            moments
+                .var()
+                .unwrap()
+                .approx_eq(est.bias_corrected(), EPSILON)
+        );
+
+        let values = jackknife_values(&DAT, mean).unwrap();
+        assert_eq!(DAT.len(), values.len());
+        let exp_v0 = mean(&DAT[1..]);
+        assert!(exp_v0.approx_eq(values[0], EPSILON));
+    }
+
+    #[test]
+    fn test_jackknife_moments() {
+        let cv = |m: &SampleMoments| m.stdev().unwrap_or(f64::NAN) / m.mean().unwrap_or(f64::NAN);
+        let cv_slice = |s: &[f64]| cv(&SampleMoments::from_slice(s));
+        let exp = jackknife(&DAT, cv_slice).unwrap();
+        let est = jackknife_moments(&DAT, cv).unwrap();
+        assert!(exp.estimate().approx_eq(est.estimate(), EPSILON));
+        assert!(exp.bias().approx_eq(est.bias(), EPSILON));
+        assert!(exp.se().approx_eq(est.se(), EPSILON));
+    }
+
+    #[test]
+    fn test_jackknife_delete_d() {
+        // For `d == 1`, the delete-d jackknife is the leave-one-out jackknife.
+        let exp = jackknife(&DAT, median).unwrap();
+        let est = jackknife_delete_d(&DAT, median, 1, 1000, 1).unwrap();
+        assert!(exp.bias().approx_eq(est.bias(), EPSILON));
+        assert!(exp.se().approx_eq(est.se(), EPSILON));
+
+        // For the mean, the delete-d jackknife standard error is `s / sqrt(n)` for any `d`.
+        let exp_se = SampleMoments::from_slice(&DAT).stdev().unwrap() / (DAT.len() as f64).sqrt();
+        for d in 1..DAT.len() {
+            let est = jackknife_delete_d(&DAT, mean, d, 1000, 1).unwrap();
+            assert!(0_f64.approx_eq(est.bias(), EPSILON), "d={d}");
+            assert!(
+                exp_se.approx_eq(est.se(), EPSILON),
+                "d={d}, se={}",
+                est.se()
+            );
+        }
+
+        let est = jackknife_delete_d(&DAT, median, 3, 1000, 1).unwrap();
+        let (exp_bias, exp_se) = (-0.09523809523809135, 0.4889133677648204);
+        assert!(
+            exp_bias.approx_eq(est.bias(), EPSILON),
+            "bias={}",
+            est.bias()
+        );
+        assert!(exp_se.approx_eq(est.se(), EPSILON), "se={}", est.se());
+
+        // Random subsamples when there are more than `max_subsets`: C(9, 3) = 84.
+        let est = jackknife_delete_d(&DAT, median, 3, 50, 7).unwrap();
+        assert!((est.se() - exp_se).abs() < 0.3 * exp_se, "se={}", est.se());
+        assert_eq!(est, jackknife_delete_d(&DAT, median, 3, 50, 7).unwrap());
+    }
+}
@@ -0,0 +1,848 @@
+//! Kolmogorov-Smirnov tests, which compare whole distributions rather than just their locations.
+//!
+//! - For the two-sample test of the null hypothesis that two samples come from the same distribution, use
+//!   [`KsTwoSample`], which is constructed from sorted samples in the same ways as
+//!   [`RankSum`](crate::wilcoxon::RankSum).
+//! - For the one-sample test of the null hypothesis that a sample comes from a given continuous distribution,
+//!   use `ks_1samp_*` functions, which take the distribution's cumulative distribution function (CDF) as a closure.
+//!
+//! For alternative hypothesis [`AltHyp::Gt`], the (first) sample tends to have larger values, i.e., its CDF lies
+//! below the other CDF, and the statistic is `D- = max(F_other - F_sample)`. For [`AltHyp::Lt`], the sample tends
+//! to have smaller values and the statistic is `D+ = max(F_sample - F_other)`. For [`AltHyp::Ne`], the statistic
+//! is `D = max(D+, D-)`. Note that `R`'s `ks.test` function names the alternatives by the direction of the CDFs
+//! instead, so its `alternative = "less"` corresponds to [`AltHyp::Gt`] and vice versa.
+//!
+//! This module is included by default. However, if `default-features = false` is specified in the dependency
+//! declaration for this library, then inclusion of this module is gated by feature "**ks**".
+//!
+//! # Example
+//!
+//! ```
+#![doc = include_str!("../examples/ks.rs")]
+//! ```
+
+use crate::core::{
+    AltHyp, HypTestResult, StatsError, StatsResult, check_alpha_in_open_0_1, iter_with_counts,
+};
+use std::f64::consts::PI;
+
+/// Product of sample sizes below which [`KsTwoSample::p`] computes the exact p-value, as in `R`'s `ks.test`.
+const EXACT_2SAMP_MAX_N_PROD: u64 = 10000;
+
+/// Sample size below which [`ks_1samp_p`] computes the exact p-value, as in `R`'s `ks.test`.
+const EXACT_1SAMP_MAX_N: usize = 100;
+
+/// Survival function of the Kolmogorov distribution, the limiting distribution of `sqrt(n) * D`.
+fn kolmogorov_sf(x: f64) -> f64 {
+    if x <= 0. {
+        return 1.;
+    }
+    if x < 1. {
+        // Series for the CDF, which converges fast for small `x`.
+        let z = -PI * PI / (8. * x * x);
+        let cdf = (1..=10)
+            .map(|k| ((2 * k - 1) as f64).powi(2) * z)
+            .map(f64::exp)
+            .sum::<f64>()
+            * (2. * PI).sqrt()
+            / x;
+        1. - cdf
+    } else {
+        let z = -2. * x * x;
+        (1..=100)
+            .map(|k| {
+                let sign = if k % 2 == 1 { 1. } else { -1. };
+                sign * (z * (k * k) as f64).exp()
+            })
+            .sum::<f64>()
+            * 2.
+    }
+}
+
+/// Asymptotic p-value for statistic `d` with effective sample size `n_eff`.
+fn asymptotic_p(d: f64, n_eff: f64, alt_hyp: AltHyp) -> f64 {
+    match alt_hyp {
+        AltHyp::Ne => kolmogorov_sf(n_eff.sqrt() * d).clamp(0., 1.),
+        AltHyp::Lt | AltHyp::Gt => (-2. * n_eff * d * d).exp(),
+    }
+}
+
+/// Encapsulates the two-sample Kolmogorov-Smirnov computations on two data samples.
+/// This struct's methods implement the two-sample Kolmogorov-Smirnov test and related statistics.
+#[derive(Debug)]
+pub struct KsTwoSample {
+    n_x: u64,
+    n_y: u64,
+    /// Counts of values from each sample, for each distinct value of the combined sample in increasing order.
+    groups: Vec<(u64, u64)>,
+    /// `D+` scaled by `n_x * n_y`.
+    d_plus: u64,
+    /// `D-` scaled by `n_x * n_y`.
+    d_minus: u64,
+}
+
+impl KsTwoSample {
+    /// Instantiates `Self` from two samples in the form of two iterators of pairs. Each item returned
+    /// by the iterators is a pair whose first component is a data value and the second component is the
+    /// number of occurrences of the value in the sample.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an iterator does not yield data values in strictly increasing order.
+    pub fn from_iters_with_counts(
+        itc_x: impl Iterator<Item = (f64, u64)>,
+        itc_y: impl Iterator<Item = (f64, u64)>,
+    ) -> Result<KsTwoSample, StatsError> {
+        fn next_checked(
+            itc: &mut impl Iterator<Item = (f64, u64)>,
+            prev: Option<f64>,
+        ) -> StatsResult<Option<(f64, u64)>> {
+            let item = itc.next();
+            match (prev, item) {
+                (Some(prev), Some((curr, _))) if prev < curr => Ok(item),
+                (Some(_), Some(_)) => Err(StatsError(
+                    "invalid iterator argument: items not ordered properly",
+                )),
+                _ => Ok(item),
+            }
+        }
+
+        let (mut itc_x, mut itc_y) = (itc_x, itc_y);
+        let mut item_x = next_checked(&mut itc_x, None)?;
+        let mut item_y = next_checked(&mut itc_y, None)?;
+        let mut groups = Vec::new();
+        loop {
+            let (count_x, count_y) = match (item_x, item_y) {
+                (None, None) => break,
+                (Some((v_x, c_x)), Some((v_y, _))) if v_x < v_y => {
+                    item_x = next_checked(&mut itc_x, Some(v_x))?;
+                    (c_x, 0)
+                }
+                (Some((v_x, c_x)), None) => {
+                    item_x = next_checked(&mut itc_x, Some(v_x))?;
+                    (c_x, 0)
+                }
+                (Some((v_x, _)), Some((v_y, c_y))) if v_x > v_y => {
+                    item_y = next_checked(&mut itc_y, Some(v_y))?;
+                    (0, c_y)
+                }
+                (None, Some((v_y, c_y))) => {
+                    item_y = next_checked(&mut itc_y, Some(v_y))?;
+                    (0, c_y)
+                }
+                // if the values are equal
+                (Some((v_x, c_x)), Some((v_y, c_y))) => {
+                    item_x = next_checked(&mut itc_x, Some(v_x))?;
+                    item_y = next_checked(&mut itc_y, Some(v_y))?;
+                    (c_x, c_y)
+                }
+            };
+            groups.push((count_x, count_y));
+        }
+
+        let n_x = groups.iter().map(|g| g.0).sum::<u64>();
+        let n_y = groups.iter().map(|g| g.1).sum::<u64>();
+        let (mut i, mut j) = (0, 0);
+        let (mut d_plus, mut d_minus) = (0, 0);
+        for (c_x, c_y) in &groups {
+            i += c_x;
+            j += c_y;
+            d_plus = d_plus.max((i * n_y).saturating_sub(j * n_x));
+            d_minus = d_minus.max((j * n_x).saturating_sub(i * n_y));
+        }
+
+        Ok(KsTwoSample {
+            n_x,
+            n_y,
+            groups,
+            d_plus,
+            d_minus,
+        })
+    }
+
+    /// Instantiates `Self` from two samples in the form of two iterators. Each item returned
+    /// by the iterators is a data value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an iterator does not yield data values in non-decreasing order.
+    pub fn from_iters(
+        it_x: impl Iterator<Item = f64>,
+        it_y: impl Iterator<Item = f64>,
+    ) -> Result<KsTwoSample, StatsError> {
+        let itc_x = iter_with_counts(it_x);
+        let itc_y = iter_with_counts(it_y);
+        Self::from_iters_with_counts(itc_x, itc_y)
+    }
+
+    /// Instantiates `Self` from two samples in the form of two slices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a slice is not sorted in non-decreasing order.
+    pub fn from_slices(x: &[f64], y: &[f64]) -> Result<KsTwoSample, StatsError> {
+        Self::from_iters(x.iter().cloned(), y.iter().cloned())
+    }
+
+    /// Size of first sample (X).
+    pub fn n_x(&self) -> u64 {
+        self.n_x
+    }
+
+    /// Size of second sample (Y).
+    pub fn n_y(&self) -> u64 {
+        self.n_y
+    }
+
+    /// Statistic scaled by `n_x * n_y`, which is an integer.
+    fn d_scaled(&self, alt_hyp: AltHyp) -> u64 {
+        match alt_hyp {
+            AltHyp::Lt => self.d_plus,
+            AltHyp::Gt => self.d_minus,
+            AltHyp::Ne => self.d_plus.max(self.d_minus),
+        }
+    }
+
+    fn check_n(&self) -> StatsResult<()> {
+        if self.n_x == 0 || self.n_y == 0 {
+            return Err(StatsError(
+                "`self.n_x` and `self.n_y` must both be positive",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Kolmogorov-Smirnov statistic for alternative hypothesis `alt_hyp`: `D-` for [`AltHyp::Gt`], `D+` for
+    /// [`AltHyp::Lt`], and `D` for [`AltHyp::Ne`] (see the [module](self) documentation).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `self.n_x == 0` or `self.n_y == 0`.
+    pub fn d(&self, alt_hyp: AltHyp) -> StatsResult<f64> {
+        self.check_n()?;
+        Ok(self.d_scaled(alt_hyp) as f64 / (self.n_x * self.n_y) as f64)
+    }
+
+    /// Exact p-value, conditional on the pattern of ties in the combined sample if there are any.
+    ///
+    /// It is computed by counting lattice paths in `O(n_x * n_y)` time, as in `R`'s `psmirnov` function.
+    ///
+    /// Arguments:
+    /// - `alt_hyp`: alternative hypothesis.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `self.n_x == 0` or `self.n_y == 0`.
+    pub fn exact_p(&self, alt_hyp: AltHyp) -> StatsResult<f64> {
+        self.check_n()?;
+        let (n_x, n_y) = (self.n_x, self.n_y);
+        let d = self.d_scaled(alt_hyp) as i128;
+
+        // The statistic can only be attained at the ends of the groups of tied values.
+        let mut at_group_end = vec![false; (n_x + n_y + 1) as usize];
+        at_group_end[0] = true;
+        let mut end = 0;
+        for (c_x, c_y) in &self.groups {
+            end += c_x + c_y;
+            at_group_end[end as usize] = true;
+        }
+
+        let reaches_d = |i: u64, j: u64| {
+            let diff = (i * n_y) as i128 - (j * n_x) as i128;
+            at_group_end[(i + j) as usize]
+                && match alt_hyp {
+                    AltHyp::Lt => diff >= d,
+                    AltHyp::Gt => -diff >= d,
+                    AltHyp::Ne => diff.abs() >= d,
+                }
+        };
+
+        // `hit[j]` is the fraction of the paths from `(0, 0)` to `(i, j)` that reach the statistic's value, where
+        // `i` and `j` are the numbers of values from X and Y. All paths are equally likely under the null hypothesis.
+        let mut hit = vec![0.; (n_y + 1) as usize];
+        for i in 0..=n_x {
+            for j in 0..=n_y {
+                let ju = j as usize;
+                hit[ju] = if reaches_d(i, j) {
+                    1.
+                } else if i == 0 && j == 0 {
+                    0.
+                } else {
+                    let s = (i + j) as f64;
+                    let from_x = if i > 0 { hit[ju] * i as f64 / s } else { 0. };
+                    let from_y = if j > 0 {
+                        hit[ju - 1] * j as f64 / s
+                    } else {
+                        0.
+                    };
+                    from_x + from_y
+                };
+            }
+        }
+        Ok(hit[n_y as usize].clamp(0., 1.))
+    }
+
+    /// Asymptotic p-value, from the limiting Kolmogorov distribution for [`AltHyp::Ne`] and from
+    /// `exp(-2 * n_x * n_y / (n_x + n_y) * D^2)` for one-sided alternatives.
+    ///
+    /// Arguments:
+    /// - `alt_hyp`: alternative hypothesis.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `self.n_x == 0` or `self.n_y == 0`.
+    pub fn asymptotic_p(&self, alt_hyp: AltHyp) -> StatsResult<f64> {
+        let d = self.d(alt_hyp)?;
+        let (n_x, n_y) = (self.n_x as f64, self.n_y as f64);
+        Ok(asymptotic_p(d, n_x * n_y / (n_x + n_y), alt_hyp))
+    }
+
+    /// p-value of the two-sample Kolmogorov-Smirnov test: [`exact_p`](Self::exact_p) if `n_x * n_y < 10000` and
+    /// [`asymptotic_p`](Self::asymptotic_p) otherwise, as in `R`'s `ks.test` function.
+    ///
+    /// Arguments:
+    /// - `alt_hyp`: alternative hypothesis.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `self.n_x == 0` or `self.n_y == 0`.
+    pub fn p(&self, alt_hyp: AltHyp) -> StatsResult<f64> {
+        if self.n_x.saturating_mul(self.n_y) < EXACT_2SAMP_MAX_N_PROD {
+            self.exact_p(alt_hyp)
+        } else {
+            self.asymptotic_p(alt_hyp)
+        }
+    }
+
+    /// Two-sample Kolmogorov-Smirnov test, with p-value computed by [`p`](Self::p).
+    ///
+    /// Arguments:
+    /// - `alt_hyp`: alternative hypothesis.
+    /// - `alpha`: confidence level = `1 - alpha`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in any of these conditions:
+    /// - `self.n_x == 0` or `self.n_y == 0`.
+    /// - `alpha` not in interval `(0, 1)`.
+    pub fn test(&self, alt_hyp: AltHyp, alpha: f64) -> StatsResult<HypTestResult> {
+        check_alpha_in_open_0_1(alpha)?;
+        let p = self.p(alt_hyp)?;
+        Ok(HypTestResult::new(p, alpha, alt_hyp))
+    }
+}
+
+/// Sorted sample and the values of `cdf` at its values.
+fn sorted_with_cdf(dataset: &[f64], cdf: impl Fn(f64) -> f64) -> StatsResult<(Vec<f64>, Vec<f64>)> {
+    if dataset.is_empty() {
+        return Err(StatsError("arg `dataset` must not be empty"));
+    }
+    if dataset.iter().any(|v| v.is_nan()) {
+        return Err(StatsError("arg `dataset` must not contain NaN"));
+    }
+    let mut x = dataset.to_vec();
+    x.sort_by(f64::total_cmp);
+    let f: Vec<f64> = x.iter().map(|&v| cdf(v)).collect();
+    if f.iter().any(|v| !(0. ..=1.).contains(v)) {
+        return Err(StatsError(
+            "arg `cdf` must return values in interval [0, 1]",
+        ));
+    }
+    Ok((x, f))
+}
+
+fn ks_1samp_d_of_cdf_values(f: &[f64], alt_hyp: AltHyp) -> f64 {
+    let n = f.len() as f64;
+    let d_plus = || {
+        f.iter()
+            .enumerate()
+            .map(|(i, v)| (i + 1) as f64 / n - v)
+            .fold(0., f64::max)
+    };
+    let d_minus = || {
+        f.iter()
+            .enumerate()
+            .map(|(i, v)| v - i as f64 / n)
+            .fold(0., f64::max)
+    };
+    match alt_hyp {
+        AltHyp::Lt => d_plus(),
+        AltHyp::Gt => d_minus(),
+        AltHyp::Ne => d_plus().max(d_minus()),
+    }
+}
+
+/// One-sample Kolmogorov-Smirnov statistic for alternative hypothesis `alt_hyp`: `D-` for [`AltHyp::Gt`], `D+` for
+/// [`AltHyp::Lt`], and `D` for [`AltHyp::Ne`] (see the [module](self) documentation).
+///
+/// Arguments:
+/// - `dataset`: sample values, in any order.
+/// - `cdf`: cumulative distribution function of the hypothesized continuous distribution.
+/// - `alt_hyp`: alternative hypothesis.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset` is empty.
+/// - `dataset` contains `NaN`.
+/// - `cdf` returns a value that is not in interval `[0, 1]` at a value of `dataset`.
+pub fn ks_1samp_d(dataset: &[f64], cdf: impl Fn(f64) -> f64, alt_hyp: AltHyp) -> StatsResult<f64> {
+    let (_, f) = sorted_with_cdf(dataset, cdf)?;
+    Ok(ks_1samp_d_of_cdf_values(&f, alt_hyp))
+}
+
+/// Product of matrices `a` and `b` of size `m x m`, stored by rows.
+fn mat_mul(a: &[f64], b: &[f64], m: usize) -> Vec<f64> {
+    let mut c = vec![0.; m * m];
+    for i in 0..m {
+        for k in 0..m {
+            let a_ik = a[i * m + k];
+            if a_ik != 0. {
+                for j in 0..m {
+                    c[i * m + j] += a_ik * b[k * m + j];
+                }
+            }
+        }
+    }
+    c
+}
+
+/// Power `n` of matrix `a` of size `m x m`, as a matrix and a decimal exponent by which it must be scaled.
+fn mat_pow(a: &[f64], m: usize, n: usize) -> (Vec<f64>, i32) {
+    if n == 1 {
+        return (a.to_vec(), 0);
+    }
+    let (v, e) = mat_pow(a, m, n / 2);
+    let (mut v, mut e) = {
+        let b = mat_mul(&v, &v, m);
+        if n.is_multiple_of(2) {
+            (b, 2 * e)
+        } else {
+            (mat_mul(a, &b, m), 2 * e)
+        }
+    };
+    if v[(m / 2) * m + m / 2] > 1e140 {
+        v.iter_mut().for_each(|x| *x *= 1e-140);
+        e += 140;
+    }
+    (v, e)
+}
+
+/// Exact CDF of the two-sided statistic `D` for sample size `n`, at `d`.
+///
+/// In the right tail, where `n * d^2 > 7.24`, or `n * d^2 > 3.76` and `n > 99`, it uses the authors' asymptotic
+/// shortcut instead, which is accurate to about 7 digits there and avoids raising large matrices to large powers.
+///
+/// Reference: G. Marsaglia, W. W. Tsang, and J. Wang, "Evaluating Kolmogorov's distribution", Journal of
+/// Statistical Software, 8(18), 2003.
+fn kolmogorov_exact_cdf(n: usize, d: f64) -> f64 {
+    let nf = n as f64;
+    let s = nf * d * d;
+    if s > 7.24 || (s > 3.76 && n > 99) {
+        return 1. - 2. * (-(2.000071 + 0.331 / nf.sqrt() + 1.409 / nf) * s).exp();
+    }
+    let k = (nf * d) as usize + 1;
+    let m = 2 * k - 1;
+    let h = k as f64 - nf * d;
+
+    let mut hm = vec![0.; m * m];
+    for i in 0..m {
+        for j in 0..m {
+            if i + 1 >= j {
+                hm[i * m + j] = 1.;
+            }
+        }
+    }
+    for i in 0..m {
+        hm[i * m] -= h.powi(i as i32 + 1);
+        hm[(m - 1) * m + i] -= h.powi((m - i) as i32);
+    }
+    if 2. * h - 1. > 0. {
+        hm[(m - 1) * m] += (2. * h - 1.).powi(m as i32);
+    }
+    for i in 0..m {
+        for j in 0..m {
+            if i + 1 > j {
+                for g in 1..=(i + 1 - j) {
+                    hm[i * m + j] /= g as f64;
+                }
+            }
+        }
+    }
+
+    let (q, mut e) = mat_pow(&hm, m, n);
+    let mut s = q[(k - 1) * m + k - 1];
+    for i in 1..=n {
+        s = s * i as f64 / nf;
+        if s < 1e-140 {
+            s *= 1e140;
+            e -= 140;
+        }
+    }
+    s * 10_f64.powi(e)
+}
+
+/// Exact p-value of the one-sided statistic `D+` (or `D-`) for sample size `n`, at `d`.
+///
+/// Reference: Z. W. Birnbaum and F. H. Tingey, "One-sided confidence contours for probability distribution
+/// functions", Annals of Mathematical Statistics, 22(4), 1951.
+fn smirnov_exact_sf(n: usize, d: f64) -> f64 {
+    if d <= 0. {
+        return 1.;
+    }
+    if d >= 1. {
+        return 0.;
+    }
+    let nf = n as f64;
+    let j_max = (nf * (1. - d)).floor() as usize;
+    let mut ln_choose = 0.;
+    let mut sum = 0.;
+    for j in 0..=j_max {
+        let jf = j as f64;
+        if j > 0 {
+            ln_choose += ((n - j + 1) as f64).ln() - jf.ln();
+        }
+        sum += (ln_choose + (nf - jf) * (1. - d - jf / nf).ln() + (jf - 1.) * (d + jf / nf).ln())
+            .exp();
+    }
+    (d * sum).clamp(0., 1.)
+}
+
+/// Exact p-value of the one-sample statistic for alternative hypothesis `alt_hyp` and sample size `n`, at `d`.
+fn exact_1samp_p(n: usize, d: f64, alt_hyp: AltHyp) -> f64 {
+    let p = match alt_hyp {
+        AltHyp::Ne => 1. - kolmogorov_exact_cdf(n, d),
+        AltHyp::Lt | AltHyp::Gt => smirnov_exact_sf(n, d),
+    };
+    p.clamp(0., 1.)
+}
+
+/// Exact p-value of the one-sample Kolmogorov-Smirnov test, for a continuous hypothesized distribution.
+///
+/// For [`AltHyp::Ne`], it is computed with the algorithm of Marsaglia, Tsang, and Wang, including the authors'
+/// asymptotic shortcut for small p-values (`n * D^2 > 7.24`, or `n * D^2 > 3.76` and `n > 99`), which is accurate to
+/// about 7 digits and keeps the computation fast for large samples. For one-sided alternatives, it is computed with
+/// the formula of Birnbaum and Tingey.
+///
+/// Arguments:
+/// - `dataset`: sample values, in any order.
+/// - `cdf`: cumulative distribution function of the hypothesized continuous distribution.
+/// - `alt_hyp`: alternative hypothesis.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset` is empty.
+/// - `dataset` contains `NaN`.
+/// - `cdf` returns a value that is not in interval `[0, 1]` at a value of `dataset`.
+pub fn ks_1samp_exact_p(
+    dataset: &[f64],
+    cdf: impl Fn(f64) -> f64,
+    alt_hyp: AltHyp,
+) -> StatsResult<f64> {
+    let d = ks_1samp_d(dataset, cdf, alt_hyp)?;
+    Ok(exact_1samp_p(dataset.len(), d, alt_hyp))
+}
+
+/// Asymptotic p-value of the one-sample Kolmogorov-Smirnov test, from the limiting Kolmogorov distribution for
+/// [`AltHyp::Ne`] and from `exp(-2 * n * D^2)` for one-sided alternatives.
+///
+/// Arguments:
+/// - `dataset`: sample values, in any order.
+/// - `cdf`: cumulative distribution function of the hypothesized continuous distribution.
+/// - `alt_hyp`: alternative hypothesis.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset` is empty.
+/// - `dataset` contains `NaN`.
+/// - `cdf` returns a value that is not in interval `[0, 1]` at a value of `dataset`.
+pub fn ks_1samp_asymptotic_p(
+    dataset: &[f64],
+    cdf: impl Fn(f64) -> f64,
+    alt_hyp: AltHyp,
+) -> StatsResult<f64> {
+    let d = ks_1samp_d(dataset, cdf, alt_hyp)?;
+    Ok(asymptotic_p(d, dataset.len() as f64, alt_hyp))
+}
+
+/// p-value of the one-sample Kolmogorov-Smirnov test: [`ks_1samp_exact_p`] if the sample has fewer than 100
+/// values and no ties, and [`ks_1samp_asymptotic_p`] otherwise, as in `R`'s `ks.test` function.
+///
+/// Arguments:
+/// - `dataset`: sample values, in any order.
+/// - `cdf`: cumulative distribution function of the hypothesized continuous distribution.
+/// - `alt_hyp`: alternative hypothesis.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset` is empty.
+/// - `dataset` contains `NaN`.
+/// - `cdf` returns a value that is not in interval `[0, 1]` at a value of `dataset`.
+pub fn ks_1samp_p(dataset: &[f64], cdf: impl Fn(f64) -> f64, alt_hyp: AltHyp) -> StatsResult<f64> {
+    let (x, f) = sorted_with_cdf(dataset, cdf)?;
+    let d = ks_1samp_d_of_cdf_values(&f, alt_hyp);
+    let n = x.len();
+    let has_ties = x.windows(2).any(|w| w[0] == w[1]);
+    if n < EXACT_1SAMP_MAX_N && !has_ties {
+        Ok(exact_1samp_p(n, d, alt_hyp))
+    } else {
+        Ok(asymptotic_p(d, n as f64, alt_hyp))
+    }
+}
+
+/// One-sample Kolmogorov-Smirnov test of the null hypothesis that the sample comes from the continuous
+/// distribution with CDF `cdf`, with p-value computed by [`ks_1samp_p`].
+///
+/// Arguments:
+/// - `dataset`: sample values, in any order.
+/// - `cdf`: cumulative distribution function of the hypothesized continuous distribution.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset` is empty.
+/// - `dataset` contains `NaN`.
+/// - `cdf` returns a value that is not in interval `[0, 1]` at a value of `dataset`.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn ks_1samp_test(
+    dataset: &[f64],
+    cdf: impl Fn(f64) -> f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p = ks_1samp_p(dataset, cdf, alt_hyp)?;
+    Ok(HypTestResult::new(p, alpha, alt_hyp))
+}
+
+#[cfg(test)]
+mod test {
+    //! Expected exact p-values for the two-sample test were computed by enumerating all the assignments of the
+    //! combined sample to the two samples. Other expected values were computed with high precision (using the
+    //! `mpmath` Python library). The exact two-sided one-sample CDF agrees with the example in Marsaglia, Tsang, and
+    //! Wang's paper.
+
+    use super::*;
+    use crate::dev_utils::ApproxEq;
+
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn test_two_sample() {
+        #[allow(clippy::type_complexity)]
+        let cases: [(&[f64], &[f64], [(AltHyp, f64, f64, f64); 3]); 4] = [
+            (
+                &[0.61, 1.2, 2.3, 3.1, 4.4],
+                &[1.5, 2.8, 3.6, 5.2, 6.1, 7.7],
+                [
+                    (AltHyp::Lt, 0.5, 0.17965367965367965, 0.255729159913101),
+                    (AltHyp::Gt, 0., 1., 1.),
+                    (AltHyp::Ne, 0.5, 0.35714285714285715, 0.502914033641132),
+                ],
+            ),
+            (
+                &[1., 2., 2., 3., 5.],
+                &[2., 3., 3., 4., 6., 6., 8.],
+                [
+                    (
+                        AltHyp::Lt,
+                        0.45714285714285713,
+                        0.18686868686868688,
+                        0.295511472445308,
+                    ),
+                    (AltHyp::Gt, 0., 1., 1.),
+                    (
+                        AltHyp::Ne,
+                        0.45714285714285713,
+                        0.37373737373737376,
+                        0.57580528921741,
+                    ),
+                ],
+            ),
+            (
+                &[3.1, 4.2, 5.5, 6.0, 7.3, 8.8, 9.1],
+                &[1.0, 1.7, 2.2, 3.4, 4.0, 5.1],
+                [
+                    (AltHyp::Lt, 0., 1., 1.),
+                    (
+                        AltHyp::Gt,
+                        0.7142857142857143,
+                        0.019230769230769232,
+                        0.037004960909027,
+                    ),
+                    (
+                        AltHyp::Ne,
+                        0.7142857142857143,
+                        0.038461538461538464,
+                        0.0740061714856305,
+                    ),
+                ],
+            ),
+            (
+                &[1., 3., 5., 7., 9., 11., 13.],
+                &[2., 2.5, 8., 8.5, 14., 15.],
+                [
+                    (
+                        AltHyp::Lt,
+                        0.3333333333333333,
+                        0.4166666666666667,
+                        0.487751746894483,
+                    ),
+                    (
+                        AltHyp::Gt,
+                        0.19047619047619047,
+                        0.6730769230769231,
+                        0.791019835983469,
+                    ),
+                    (
+                        AltHyp::Ne,
+                        0.3333333333333333,
+                        0.7803030303030303,
+                        0.865413283499468,
+                    ),
+                ],
+            ),
+        ];
+
+        for (x, y, alt_cases) in cases {
+            let ks = KsTwoSample::from_slices(x, y).unwrap();
+            assert_eq!(x.len() as u64, ks.n_x());
+            assert_eq!(y.len() as u64, ks.n_y());
+            for (alt_hyp, exp_d, exp_exact_p, exp_asymptotic_p) in alt_cases {
+                let ctx = format!("x={x:?}, y={y:?}, alt_hyp={alt_hyp:?}");
+                let d = ks.d(alt_hyp).unwrap();
+                let exact_p = ks.exact_p(alt_hyp).unwrap();
+                let asymptotic_p = ks.asymptotic_p(alt_hyp).unwrap();
+                assert!(exp_d.approx_eq(d, EPSILON), "{ctx} -> exp_d={exp_d}, d={d}");
+                assert!(
+                    exp_exact_p.approx_eq(exact_p, EPSILON),
+                    "{ctx} -> exp_exact_p={exp_exact_p}, exact_p={exact_p}"
+                );
+                assert!(
+                    exp_asymptotic_p.approx_eq(asymptotic_p, EPSILON),
+                    "{ctx} -> exp_asymptotic_p={exp_asymptotic_p}, asymptotic_p={asymptotic_p}"
+                );
+                assert_eq!(exact_p, ks.p(alt_hyp).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_two_sample_large() {
+        // Large samples use the asymptotic p-value.
+        let x: Vec<f64> = (0..100).map(|i| i as f64).collect();
+        let y: Vec<f64> = (0..100).map(|i| i as f64 + 20.5).collect();
+        let ks = KsTwoSample::from_slices(&x, &y).unwrap();
+        assert_eq!(
+            ks.asymptotic_p(AltHyp::Ne).unwrap(),
+            ks.p(AltHyp::Ne).unwrap()
+        );
+        assert!(0.21.approx_eq(ks.d(AltHyp::Lt).unwrap(), EPSILON));
+        assert_eq!(0., ks.d(AltHyp::Gt).unwrap());
+    }
+
+    #[test]
+    fn test_kolmogorov_exact_cdf() {
+        let cdf = kolmogorov_exact_cdf(10, 0.274);
+        assert!(0.6284796154565043.approx_eq(cdf, 1e-14), "cdf={cdf}");
+
+        // Asymptotic shortcut in the right tail, compared with the matrix computation.
+        let sf = 1. - kolmogorov_exact_cdf(100, 0.2);
+        assert!(5.551927328041328e-4.approx_eq(sf, 2e-7), "sf={sf}");
+        let sf = 1. - kolmogorov_exact_cdf(1000, 0.07);
+        assert!(1.0494206287037233e-4.approx_eq(sf, 5e-7), "sf={sf}");
+        // A matrix of order about 2000 would be raised to power 100000 without the shortcut.
+        let sf = 1. - kolmogorov_exact_cdf(100_000, 0.01);
+        assert!(4.075913846868282e-9.approx_eq(sf, 1e-15), "sf={sf}");
+    }
+
+    #[test]
+    fn test_one_sample() {
+        let uniform_cdf = |v: f64| v.clamp(0., 1.);
+        let exp_cdf = |v: f64| 1. - (-v).exp();
+        let stretched_cdf = |v: f64| (v / 9.).clamp(0., 1.);
+        let squares: Vec<f64> = (1..=30).map(|i| 0.01 * (i * i) as f64).collect();
+
+        #[allow(clippy::type_complexity)]
+        let cases: [(&[f64], &dyn Fn(f64) -> f64, [(AltHyp, f64, f64, f64); 3]); 3] = [
+            (
+                &[0.05, 0.12, 0.31, 0.33, 0.47, 0.52, 0.6, 0.71, 0.93, 0.98],
+                &uniform_cdf,
+                [
+                    (AltHyp::Lt, 0.1, 0.7642052309, 0.818730753077982),
+                    (AltHyp::Gt, 0.13, 0.658878581236162, 0.713195287898282),
+                    (AltHyp::Ne, 0.13, 0.987482934660939, 0.99588096530338),
+                ],
+            ),
+            (
+                &[0.9, 1.7, 2.2, 0.4, 3.1, 1.2, 2.8, 0.7, 4.5, 1.9, 2.5, 3.6],
+                &exp_cdf,
+                [
+                    (
+                        AltHyp::Lt,
+                        0.0111089965382423,
+                        0.987455523209698,
+                        0.997042546608856,
+                    ),
+                    (
+                        AltHyp::Gt,
+                        0.483983142613932,
+                        0.00206615292904547,
+                        0.00361829493684393,
+                    ),
+                    (
+                        AltHyp::Ne,
+                        0.483983142613932,
+                        0.00413230585809094,
+                        0.00723658953088388,
+                    ),
+                ],
+            ),
+            (
+                &squares,
+                &stretched_cdf,
+                [
+                    (AltHyp::Lt, 0.25, 0.0193621464062736, 0.0235177458560091),
+                    (
+                        AltHyp::Gt,
+                        0.0333333333333333,
+                        0.913731652391281,
+                        0.935506985031618,
+                    ),
+                    (AltHyp::Ne, 0.25, 0.0387241567011045, 0.0470348799073816),
+                ],
+            ),
+        ];
+
+        for (dataset, cdf, alt_cases) in cases {
+            for (alt_hyp, exp_d, exp_exact_p, exp_asymptotic_p) in alt_cases {
+                let ctx = format!("dataset={dataset:?}, alt_hyp={alt_hyp:?}");
+                let d = ks_1samp_d(dataset, cdf, alt_hyp).unwrap();
+                let exact_p = ks_1samp_exact_p(dataset, cdf, alt_hyp).unwrap();
+                let asymptotic_p = ks_1samp_asymptotic_p(dataset, cdf, alt_hyp).unwrap();
+                assert!(exp_d.approx_eq(d, EPSILON), "{ctx} -> exp_d={exp_d}, d={d}");
+                assert!(
+                    exp_exact_p.approx_eq(exact_p, EPSILON),
+                    "{ctx} -> exp_exact_p={exp_exact_p}, exact_p={exact_p}"
+                );
+                assert!(
+                    exp_asymptotic_p.approx_eq(asymptotic_p, EPSILON),
+                    "{ctx} -> exp_asymptotic_p={exp_asymptotic_p}, asymptotic_p={asymptotic_p}"
+                );
+                assert_eq!(exact_p, ks_1samp_p(dataset, cdf, alt_hyp).unwrap());
+            }
+        }
+
+        // Samples with ties use the asymptotic p-value.
+        let tied = [0.1, 0.2, 0.2, 0.7];
+        assert_eq!(
+            ks_1samp_asymptotic_p(&tied, uniform_cdf, AltHyp::Ne).unwrap(),
+            ks_1samp_p(&tied, uniform_cdf, AltHyp::Ne).unwrap()
+        );
+    }
+}
@@ -14,5 +14,44 @@ pub mod binomial;
 #[cfg(feature = "wilcoxon")]
 pub mod wilcoxon;
 
+#[cfg(feature = "combine")]
+pub mod combine;
+
+#[cfg(feature = "contingency")]
+pub mod contingency;
+
+#[cfg(feature = "multinomial")]
+pub mod multinomial;
+
+#[cfg(feature = "poisson")]
+pub mod poisson;
+
+#[cfg(feature = "sequential")]
+pub mod sequential;
+
+#[cfg(feature = "bayes")]
+pub mod bayes;
+
+#[cfg(feature = "normality")]
+pub mod normality;
+
+#[cfg(feature = "ks")]
+pub mod ks;
+
+#[cfg(feature = "bootstrap")]
+pub mod bootstrap;
+
+#[cfg(feature = "permutation")]
+pub mod permutation;
+
+#[cfg(feature = "jackknife")]
+pub mod jackknife;
+
+#[cfg(feature = "robust")]
+pub mod robust;
+
+#[cfg(feature = "outliers")]
+pub mod outliers;
+
 #[doc(hidden)]
 pub mod dev_utils;
@@ -0,0 +1,394 @@
+//! Goodness-of-fit tests for samples of a categorical distribution with `k` categories. The multinomial distribution
+//! with parameters `n` and `probs` is the distribution of the counts of each category in `n` independent draws from
+//! a categorical distribution with category probabilities `probs`.
+//!
+//! All functions in this module take a slice `counts` with the observed count of each category and a slice `probs`
+//! with the probability of each category under the null hypothesis. The alternative hypothesis is that the
+//! category probabilities are not all equal to `probs`. Accordingly, the [`HypTestResult`]s returned by the
+//! `*_test` functions in this module have an alternative hypothesis of [`AltHyp::Ne`].
+//!
+//! - For Pearson's chi-square goodness-of-fit test, use `multinomial_chi2_*` functions.
+//! - For the exact multinomial test, suitable for small totals, use `exact_multinomial_*` functions.
+//!
+//! This module is included by default. However, if `default-features = false` is specified in the dependency
+//! declaration for this library, then inclusion of this module is gated by feature "**multinomial**".
+//!
+//! # Example
+//!
+//! ```
+#![doc = include_str!("../examples/multinomial.rs")]
+//! ```
+
+use crate::core::{
+    AltHyp, HypTestResult, StatsError, StatsResult, check_alpha_in_open_0_1, n_choose_k_up_to,
+};
+use statrs::{
+    distribution::{ChiSquared, ContinuousCDF},
+    function::factorial::ln_factorial,
+};
+
+/// Tolerance for the sum of `probs` to differ from `1`, same as `R`'s `chisq.test`.
+const PROBS_SUM_TOL: f64 = 1.490_116_119_384_765_6e-8;
+
+/// Maximum number of outcomes enumerated by [`exact_multinomial_p`].
+pub const EXACT_MULTINOMIAL_MAX_OUTCOMES: u64 = 10_000_000;
+
+fn check_counts(counts: &[u64]) -> StatsResult<u64> {
+    if counts.len() < 2 {
+        return Err(StatsError("arg `counts` must have at least 2 items"));
+    }
+    let n = counts.iter().sum();
+    if n == 0 {
+        return Err(StatsError("sum of arg `counts` must be positive"));
+    }
+    Ok(n)
+}
+
+fn check_probs(counts: &[u64], probs: &[f64], lo_open: bool) -> StatsResult<()> {
+    if counts.len() != probs.len() {
+        return Err(StatsError(
+            "args `counts` and `probs` must have the same length",
+        ));
+    }
+    let in_range = |p: f64| (if lo_open { 0. < p } else { 0. <= p }) && p <= 1.;
+    if !probs.iter().all(|&p| in_range(p)) {
+        return Err(if lo_open {
+            StatsError("items of arg `probs` must be in interval (0, 1]")
+        } else {
+            StatsError("items of arg `probs` must be in interval [0, 1]")
+        });
+    }
+    if (probs.iter().sum::<f64>() - 1.).abs() > PROBS_SUM_TOL {
+        return Err(StatsError("items of arg `probs` must sum to 1"));
+    }
+    Ok(())
+}
+
+/// Pearson's chi-square goodness-of-fit statistic, `sum((counts[i] - e[i])^2 / e[i])`, where
+/// `e[i] = n * probs[i]` is the expected count of category `i` and `n` is the sum of `counts`.
+///
+/// Arguments:
+/// - `counts`: observed count of each category.
+/// - `probs`: probability of each category under the null hypothesis.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `counts` has fewer than `2` items or its items sum to `0`.
+/// - `counts` and `probs` have different lengths.
+/// - Any item of `probs` is not in interval `(0, 1]`.
+/// - The items of `probs` do not sum to `1`.
+pub fn multinomial_chi2(counts: &[u64], probs: &[f64]) -> StatsResult<f64> {
+    let n = check_counts(counts)? as f64;
+    check_probs(counts, probs, true)?;
+    let chi2 = counts
+        .iter()
+        .zip(probs)
+        .map(|(&o, &p)| {
+            let e = n * p;
+            (o as f64 - e).powi(2) / e
+        })
+        .sum();
+    Ok(chi2)
+}
+
+/// Degrees of freedom of the chi-square distribution of [`multinomial_chi2`] under the null hypothesis,
+/// `counts.len() - 1`.
+///
+/// Arguments:
+/// - `counts`: observed count of each category.
+///
+/// # Errors
+///
+/// Returns an error if `counts` has fewer than `2` items or its items sum to `0`.
+pub fn multinomial_chi2_df(counts: &[u64]) -> StatsResult<f64> {
+    check_counts(counts)?;
+    Ok((counts.len() - 1) as f64)
+}
+
+/// p-value of Pearson's chi-square goodness-of-fit test.
+///
+/// Arguments:
+/// - `counts`: observed count of each category.
+/// - `probs`: probability of each category under the null hypothesis.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `counts` has fewer than `2` items or its items sum to `0`.
+/// - `counts` and `probs` have different lengths.
+/// - Any item of `probs` is not in interval `(0, 1]`.
+/// - The items of `probs` do not sum to `1`.
+pub fn multinomial_chi2_p(counts: &[u64], probs: &[f64]) -> StatsResult<f64> {
+    let chi2 = multinomial_chi2(counts, probs)?;
+    let df = multinomial_chi2_df(counts)?;
+    let chi_squared = ChiSquared::new(df)
+        .expect("`ChiSquared::new` arg `freedom` should be guaranteed to be positive");
+    Ok(chi_squared.sf(chi2))
+}
+
+/// Pearson's chi-square goodness-of-fit test.
+///
+/// Arguments:
+/// - `counts`: observed count of each category.
+/// - `probs`: probability of each category under the null hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `counts` has fewer than `2` items or its items sum to `0`.
+/// - `counts` and `probs` have different lengths.
+/// - Any item of `probs` is not in interval `(0, 1]`.
+/// - The items of `probs` do not sum to `1`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn multinomial_chi2_test(
+    counts: &[u64],
+    probs: &[f64],
+    alpha: f64,
+) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p = multinomial_chi2_p(counts, probs)?;
+    Ok(HypTestResult::new(p, alpha, AltHyp::Ne))
+}
+
+/// Natural log of the multinomial probability of `counts`, or `None` if the probability is `0`.
+fn ln_pmf(counts: &[u64], ln_probs: &[f64], ln_fact: &[f64]) -> Option<f64> {
+    let n: u64 = counts.iter().sum();
+    let mut ret = ln_fact[n as usize];
+    for (&x, &ln_p) in counts.iter().zip(ln_probs) {
+        if x > 0 {
+            if ln_p == f64::NEG_INFINITY {
+                return None;
+            }
+            ret += x as f64 * ln_p - ln_fact[x as usize];
+        }
+    }
+    Some(ret)
+}
+
+/// Sums the probabilities not greater than `threshold` of all possible outcomes, where `acc` is the accumulated
+/// log-probability of the counts already assigned to the categories preceding `ln_probs`.
+fn sum_prob_le(n_left: u64, ln_probs: &[f64], ln_fact: &[f64], acc: f64, threshold: f64) -> f64 {
+    match ln_probs {
+        [] => unreachable!("`ln_probs` should not be empty"),
+        [ln_p] => {
+            let ln_prob = match n_left {
+                0 => acc,
+                _ if *ln_p == f64::NEG_INFINITY => return 0.,
+                _ => acc + n_left as f64 * ln_p - ln_fact[n_left as usize],
+            };
+            if ln_prob <= threshold {
+                ln_prob.exp()
+            } else {
+                0.
+            }
+        }
+        [ln_p, rest @ ..] => {
+            if *ln_p == f64::NEG_INFINITY {
+                return sum_prob_le(n_left, rest, ln_fact, acc, threshold);
+            }
+            (0..=n_left)
+                .map(|x| {
+                    let acc = acc + x as f64 * ln_p - ln_fact[x as usize];
+                    sum_prob_le(n_left - x, rest, ln_fact, acc, threshold)
+                })
+                .sum()
+        }
+    }
+}
+
+/// p-value of the exact multinomial goodness-of-fit test. The p-value is the sum of the probabilities,
+/// under the null hypothesis, of all outcomes whose probability is not greater than that of `counts`.
+///
+/// The computation enumerates all possible outcomes, whose number is the binomial coefficient
+/// `(n + k - 1) choose (k - 1)`, where `n` is the sum of `counts` and `k` is the number of categories with
+/// positive probability. This function is therefore only suitable for small values of `n` and `k`, and it returns
+/// an error if the number of outcomes exceeds [`EXACT_MULTINOMIAL_MAX_OUTCOMES`]. For large values, use
+/// [`multinomial_chi2_p`] instead.
+///
+/// Arguments:
+/// - `counts`: observed count of each category.
+/// - `probs`: probability of each category under the null hypothesis.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `counts` has fewer than `2` items or its items sum to `0`.
+/// - `counts` and `probs` have different lengths.
+/// - Any item of `probs` is not in interval `[0, 1]`.
+/// - The items of `probs` do not sum to `1`.
+/// - The number of outcomes exceeds [`EXACT_MULTINOMIAL_MAX_OUTCOMES`].
+pub fn exact_multinomial_p(counts: &[u64], probs: &[f64]) -> StatsResult<f64> {
+    let n = check_counts(counts)?;
+    check_probs(counts, probs, false)?;
+
+    let k_pos = probs.iter().filter(|&&p| p > 0.).count() as u64;
+    if k_pos == 1 {
+        // The only possible outcome has all the counts in the category with probability `1`.
+        let possible = counts.iter().zip(probs).all(|(&x, &p)| x == 0 || p > 0.);
+        return Ok(if possible { 1. } else { 0. });
+    }
+    if n_choose_k_up_to(
+        n.saturating_add(k_pos - 1),
+        k_pos - 1,
+        EXACT_MULTINOMIAL_MAX_OUTCOMES,
+    )
+    .is_none()
+    {
+        return Err(StatsError(
+            "too many outcomes for the exact test, use `multinomial_chi2_p` instead",
+        ));
+    }
+
+    let ln_probs = probs.iter().map(|p| p.ln()).collect::<Vec<_>>();
+    let ln_fact = (0..=n).map(ln_factorial).collect::<Vec<_>>();
+
+    let Some(ln_prob_obs) = ln_pmf(counts, &ln_probs, &ln_fact) else {
+        // Observed outcome is impossible under the null hypothesis.
+        return Ok(0.);
+    };
+
+    // Relative tolerance for ties, same as `R`'s `binom.test`.
+    let threshold = ln_prob_obs + 1e-7_f64.ln_1p();
+    let p = sum_prob_le(n, &ln_probs, &ln_fact, ln_fact[n as usize], threshold);
+    Ok(p.min(1.))
+}
+
+/// Exact multinomial goodness-of-fit test. See [`exact_multinomial_p`] for details and computational cost.
+///
+/// Arguments:
+/// - `counts`: observed count of each category.
+/// - `probs`: probability of each category under the null hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `counts` has fewer than `2` items or its items sum to `0`.
+/// - `counts` and `probs` have different lengths.
+/// - Any item of `probs` is not in interval `[0, 1]`.
+/// - The items of `probs` do not sum to `1`.
+/// - The number of outcomes exceeds [`EXACT_MULTINOMIAL_MAX_OUTCOMES`].
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn exact_multinomial_test(
+    counts: &[u64],
+    probs: &[f64],
+    alpha: f64,
+) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p = exact_multinomial_p(counts, probs)?;
+    Ok(HypTestResult::new(p, alpha, AltHyp::Ne))
+}
+
+#[cfg(test)]
+mod test {
+    //! Expected chi-square values follow `R`'s chisq.test function. Expected exact values were computed by
+    //! full enumeration with high precision (using the `mpmath` Python library).
+
+    use super::*;
+    use crate::{core::Hyp, dev_utils::ApproxEq};
+
+    const ALPHA: f64 = 0.05;
+    const EPSILON: f64 = 0.000005;
+
+    fn check_chi2(counts: &[u64], probs: &[f64], exp_chi2: f64, exp_p: f64, exp_accept_hyp: Hyp) {
+        let chi2 = multinomial_chi2(counts, probs).unwrap();
+        let df = multinomial_chi2_df(counts).unwrap();
+        let p = multinomial_chi2_p(counts, probs).unwrap();
+        let res = multinomial_chi2_test(counts, probs, ALPHA).unwrap();
+
+        assert!(
+            exp_chi2.approx_eq(chi2, EPSILON),
+            "counts={counts:?} -> exp_chi2={exp_chi2}, chi2={chi2}"
+        );
+        assert_eq!((counts.len() - 1) as f64, df);
+        assert!(
+            exp_p.approx_eq(p, EPSILON),
+            "counts={counts:?} -> exp_p={exp_p}, p={p}"
+        );
+        assert_eq!(p, res.p());
+        assert_eq!(AltHyp::Ne, res.alt_hyp());
+        assert_eq!(exp_accept_hyp, res.accepted(), "counts={counts:?}");
+    }
+
+    fn check_exact(counts: &[u64], probs: &[f64], exp_p: f64, exp_accept_hyp: Hyp) {
+        let p = exact_multinomial_p(counts, probs).unwrap();
+        let res = exact_multinomial_test(counts, probs, ALPHA).unwrap();
+
+        assert!(
+            exp_p.approx_eq(p, EPSILON),
+            "counts={counts:?} -> exp_p={exp_p}, p={p}"
+        );
+        assert_eq!(p, res.p());
+        assert_eq!(AltHyp::Ne, res.alt_hyp());
+        assert_eq!(exp_accept_hyp, res.accepted(), "counts={counts:?}");
+    }
+
+    #[test]
+    fn test_chi2() {
+        // `R`'s chisq.test documentation example.
+        check_chi2(
+            &[89, 37, 30, 28, 2],
+            &[0.40, 0.20, 0.20, 0.15, 0.05],
+            9.990143369,
+            0.04059404334,
+            Hyp::Alt(AltHyp::Ne),
+        );
+        check_chi2(
+            &[3, 1, 6],
+            &[0.2, 0.3, 0.5],
+            2.033333333,
+            0.3617989288,
+            Hyp::Null,
+        );
+        check_chi2(
+            &[2, 3, 4, 1],
+            &[0.25, 0.25, 0.25, 0.25],
+            2.,
+            0.5724067045,
+            Hyp::Null,
+        );
+        check_chi2(
+            &[5, 0, 0],
+            &[1. / 3., 1. / 3., 1. / 3.],
+            10.,
+            0.006737946999,
+            Hyp::Alt(AltHyp::Ne),
+        );
+    }
+
+    #[test]
+    fn test_exact() {
+        check_exact(&[3, 1, 6], &[0.2, 0.3, 0.5], 0.3755575, Hyp::Null);
+        check_exact(
+            &[2, 3, 4, 1],
+            &[0.25, 0.25, 0.25, 0.25],
+            0.7196197510,
+            Hyp::Null,
+        );
+        check_exact(
+            &[5, 0, 0],
+            &[1. / 3., 1. / 3., 1. / 3.],
+            0.01234567901,
+            Hyp::Alt(AltHyp::Ne),
+        );
+        check_exact(
+            &[10, 11, 9],
+            &[1. / 3., 1. / 3., 1. / 3.],
+            0.9730391652,
+            Hyp::Null,
+        );
+    }
+
+    #[test]
+    fn test_exact_zero_probs() {
+        // Categories with zero probability reduce the test to an exact binomial test.
+        check_exact(&[4, 0, 2], &[0.5, 0., 0.5], 0.6875, Hyp::Null);
+        check_exact(&[4, 2, 0], &[0.5, 0.5, 0.], 0.6875, Hyp::Null);
+        check_exact(&[0, 6, 0], &[0., 1., 0.], 1., Hyp::Null);
+
+        // Observed outcome impossible under the null hypothesis.
+        check_exact(&[4, 1, 2], &[0.5, 0., 0.5], 0., Hyp::Alt(AltHyp::Ne));
+    }
+}
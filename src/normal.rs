@@ -6,6 +6,8 @@
 //! - For the paired-sample t-test and related statistics, first construct the [`SampleMoments`] for
 //!   the paired samples (using, e.g., [`SampleMoments::from_paired_iters`] or [`SampleMoments::from_paired_slices`])
 //!   and then use `student_1samp_*` functions.
+//! - For power analysis and sample size determination of the above t-tests, use `t_test_*` functions with the
+//!   appropriate [`TTestDesign`].
 //!
 //! This module is included by default. However, if `default-features = false` is specified in the dependency
 //! declaration for this library, then inclusion of this module is gated by feature "**normal**".
@@ -18,9 +20,12 @@
 
 use crate::core::{
     AltHyp, AsStatsResult, Ci, HypTestResult, SampleMoments, StatsError, StatsResult,
-    check_alpha_in_open_0_1,
+    check_alpha_in_open_0_1, find_root,
+};
+use statrs::{
+    distribution::{ContinuousCDF, Normal, StudentsT},
+    function::{beta::beta_reg, gamma::ln_gamma},
 };
-use statrs::distribution::{ContinuousCDF, Normal, StudentsT};
 
 /// Returns the the probability that the standard normal distribution will produce a more extreme value
 /// than the argument `z`, with alternative hypothesis `alt_hyp`.
@@ -403,11 +408,394 @@ pub fn student_1samp_test(
     Ok(HypTestResult::new(p, alpha, alt_hyp))
 }
 
+/// Cumulative distribution function of the noncentral t distribution with `df` degrees of freedom and
+/// noncentrality parameter `ncp`.
+///
+/// Port of algorithm AS 243 (R. V. Lenth, "Cumulative distribution function of the non-central t distribution",
+/// Applied Statistics 38, 1989), with the refinements of `R`'s `pnt` function.
+fn noncentral_t_cdf(t: f64, df: f64, ncp: f64) -> f64 {
+    const ITR_MAX: u32 = 1000;
+    const ERR_MAX: f64 = 1e-12;
+
+    let normal = Normal::standard();
+    if ncp == 0. {
+        let stud = StudentsT::new(0., 1., df).expect("`df` should be positive");
+        return stud.cdf(t);
+    }
+
+    let (negdel, tt, del) = if t >= 0. {
+        (false, t, ncp)
+    } else {
+        (true, -t, -ncp)
+    };
+
+    // Normal approximation for large degrees of freedom or noncentrality.
+    if df > 4e5 || del * del > 2. * std::f64::consts::LN_2 * 1021. {
+        let s = 1. / (4. * df);
+        let z = (tt * (1. - s) - del) / (1. + tt * tt * 2. * s).sqrt();
+        let lower = normal.cdf(z);
+        return if negdel { 1. - lower } else { lower };
+    }
+
+    let x = t * t / (t * t + df);
+    let mut tnc = 0.;
+    if x > 0. {
+        let lambda = del * del;
+        let mut p = 0.5 * (-0.5 * lambda).exp();
+        let mut q = (2. / std::f64::consts::PI).sqrt() * p * del;
+        let mut s = 0.5 - p;
+        if s < 1e-7 {
+            s = -0.5 * (-0.5 * lambda).exp_m1();
+        }
+        let mut a = 0.5;
+        let b = 0.5 * df;
+        let rxb = (1. - x).powf(b);
+        let albeta = 0.5 * std::f64::consts::PI.ln() + ln_gamma(b) - ln_gamma(0.5 + b);
+        let mut xodd = beta_reg(a, b, x);
+        let mut godd = 2. * rxb * (a * x.ln() - albeta).exp();
+        let bx = b * x;
+        let mut xeven = if bx < f64::EPSILON { bx } else { 1. - rxb };
+        let mut geven = bx * rxb;
+        tnc = p * xodd + q * xeven;
+
+        for it in 1..=ITR_MAX {
+            a += 1.;
+            xodd -= godd;
+            xeven -= geven;
+            godd *= x * (a + b - 1.) / a;
+            geven *= x * (a + b - 0.5) / (a + 0.5);
+            p *= lambda / (2 * it) as f64;
+            q *= lambda / (2 * it + 1) as f64;
+            tnc += p * xodd + q * xeven;
+            s -= p;
+            if s < -1e-10 || (s <= 0. && it > 1) {
+                break;
+            }
+            let errbd = 2. * s * (xodd - godd);
+            if errbd.abs() < ERR_MAX {
+                break;
+            }
+        }
+    }
+
+    tnc += normal.cdf(-del);
+    let tnc = tnc.min(1.);
+    if negdel { 1. - tnc } else { tnc }
+}
+
+/// Degrees of freedom above which [`power_t_crit`] uses the Cornish–Fisher expansion.
+const CORNISH_FISHER_MIN_DF: f64 = 1e4;
+
+/// Upper `alpha` critical value of Student's t distribution with `df` degrees of freedom, for power analysis.
+///
+/// For large `df`, where `statrs`'s `StudentsT::inverse_cdf` loses accuracy and eventually fails to return, it uses
+/// the Cornish–Fisher expansion around the normal critical value, which is accurate to about `1e-13` there for
+/// `alpha >= 1e-6`.
+fn power_t_crit(df: f64, alpha: f64) -> f64 {
+    if df <= CORNISH_FISHER_MIN_DF {
+        return t_alpha(df, alpha).expect("`df` and `alpha` should be valid");
+    }
+    let z = z_alpha(alpha).expect("`alpha` should be valid");
+    let (z2, nu) = (z * z, df);
+    let g1 = z * (z2 + 1.) / 4.;
+    let g2 = z * ((5. * z2 + 16.) * z2 + 3.) / 96.;
+    let g3 = z * (((3. * z2 + 19.) * z2 + 17.) * z2 - 15.) / 384.;
+    z + g1 / nu + g2 / nu.powi(2) + g3 / nu.powi(3)
+}
+
+/// Design of a t-test, for power analysis and sample size determination with the `t_test_*` functions.
+///
+/// In all designs, `n` is the number of observations in each sample (or the number of pairs for
+/// [`TTestDesign::Paired`]), `delta` is the actual difference between the mean and the hypothesized mean (or
+/// between the means of the two samples), and `sd` is the actual standard deviation of the observations (or of the
+/// paired differences for [`TTestDesign::Paired`], or of the first sample for [`TTestDesign::Welch`]).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TTestDesign {
+    /// Student's one-sample t-test ([`student_1samp_test`]).
+    OneSample,
+    /// Paired-sample t-test (`student_1samp_*` functions applied to the paired differences).
+    Paired,
+    /// Student's two-sample t-test with pooled variance and equal sample sizes.
+    Pooled,
+    /// Welch's two-sample t-test ([`welch_test`]) with equal sample sizes, where `sd_ratio` is the ratio of the
+    /// standard deviation of the second sample to that of the first.
+    Welch { sd_ratio: f64 },
+}
+
+impl TTestDesign {
+    fn check(self) -> StatsResult<()> {
+        match self {
+            TTestDesign::Welch { sd_ratio } if !(sd_ratio.is_finite() && sd_ratio > 0.) => Err(
+                StatsError("`TTestDesign::Welch` field `sd_ratio` must be finite and positive"),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Degrees of freedom and noncentrality parameter of the t statistic for sample size `n` and standardized
+    /// effect size `d = delta / sd`.
+    fn df_ncp(self, n: f64, d: f64) -> (f64, f64) {
+        match self {
+            TTestDesign::OneSample | TTestDesign::Paired => (n - 1., d * n.sqrt()),
+            TTestDesign::Pooled => (2. * (n - 1.), d * (n / 2.).sqrt()),
+            TTestDesign::Welch { sd_ratio } => {
+                let r2 = sd_ratio * sd_ratio;
+                let df = (n - 1.) * (1. + r2).powi(2) / (1. + r2 * r2);
+                (df, d * (n / (1. + r2)).sqrt())
+            }
+        }
+    }
+
+    /// Power for sample size `n` and standardized effect size `d = delta / sd`.
+    fn power(self, n: f64, d: f64, alt_hyp: AltHyp, alpha: f64) -> f64 {
+        let (df, ncp) = self.df_ncp(n, d);
+        let t_crit = |a: f64| power_t_crit(df, a);
+        match alt_hyp {
+            AltHyp::Lt => noncentral_t_cdf(-t_crit(alpha), df, ncp),
+            AltHyp::Gt => 1. - noncentral_t_cdf(t_crit(alpha), df, ncp),
+            AltHyp::Ne => {
+                let t0 = t_crit(alpha / 2.);
+                noncentral_t_cdf(-t0, df, ncp) + 1. - noncentral_t_cdf(t0, df, ncp)
+            }
+        }
+    }
+
+    /// Smallest standardized effect size, with the sign required by `alt_hyp`, for which the power with sample size
+    /// `n` is at least `power`.
+    fn min_d(self, n: u64, alt_hyp: AltHyp, alpha: f64, power: f64) -> StatsResult<f64> {
+        if power <= alpha {
+            return Err(StatsError(
+                "power target is unattainable: arg `power` must be greater than arg `alpha`",
+            ));
+        }
+        let sign = if let AltHyp::Lt = alt_hyp { -1. } else { 1. };
+        let f = |d: f64| self.power(n as f64, sign * d, alt_hyp, alpha) - power;
+        // Bracket the result within a factor of `2`, so that the tolerance is relative even for large `n`.
+        let mut hi = 1.;
+        while f(hi) < 0. {
+            hi *= 2.;
+        }
+        while f(hi / 2.) >= 0. {
+            hi /= 2.;
+        }
+        let d = find_root(f, hi / 2., hi, 1e-10 * hi)
+            .expect("`f` should have opposite signs at the interval ends");
+        Ok(sign * d)
+    }
+}
+
+fn check_power_args(alpha: f64, power: f64) -> StatsResult<()> {
+    check_alpha_in_open_0_1(alpha)?;
+    if !(0. < power && power < 1.) {
+        return Err(StatsError("arg `power` must be in interval (0, 1)"));
+    }
+    Ok(())
+}
+
+fn check_n_at_least_2(n: u64) -> StatsResult<()> {
+    if n < 2 {
+        return Err(StatsError("arg `n` must be at least 2"));
+    }
+    Ok(())
+}
+
+fn check_sd(sd: f64) -> StatsResult<()> {
+    if !(sd.is_finite() && sd > 0.) {
+        return Err(StatsError("arg `sd` must be finite and positive"));
+    }
+    Ok(())
+}
+
+/// Checks that `delta` has the sign for which the power tends to `1` as the sample size grows.
+fn check_delta_direction(delta: f64, alt_hyp: AltHyp) -> StatsResult<()> {
+    if !delta.is_finite() {
+        return Err(StatsError("arg `delta` must be finite"));
+    }
+    match alt_hyp {
+        AltHyp::Lt if delta >= 0. => Err(StatsError(
+            "power target is unattainable: arg `delta` must be negative for `AltHyp::Lt`",
+        )),
+        AltHyp::Gt if delta <= 0. => Err(StatsError(
+            "power target is unattainable: arg `delta` must be positive for `AltHyp::Gt`",
+        )),
+        AltHyp::Ne if delta == 0. => Err(StatsError(
+            "power target is unattainable: arg `delta` must be non-zero for `AltHyp::Ne`",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Power of a t-test, computed with the noncentral t distribution.
+/// Equivalent to `R`'s `power.t.test` function with `strict = TRUE` for the designs it supports.
+///
+/// Arguments:
+/// - `design`: design of the t-test.
+/// - `n`: number of observations in each sample (see [`TTestDesign`]).
+/// - `delta`: actual difference of means.
+/// - `sd`: actual standard deviation.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `design` is [`TTestDesign::Welch`] with `sd_ratio` not finite and positive.
+/// - `n < 2`.
+/// - `delta` is not finite.
+/// - `sd` is not finite and positive.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn t_test_power(
+    design: TTestDesign,
+    n: u64,
+    delta: f64,
+    sd: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<f64> {
+    design.check()?;
+    check_n_at_least_2(n)?;
+    if !delta.is_finite() {
+        return Err(StatsError("arg `delta` must be finite"));
+    }
+    check_sd(sd)?;
+    check_alpha_in_open_0_1(alpha)?;
+    Ok(design.power(n as f64, delta / sd, alt_hyp, alpha))
+}
+
+/// Smallest number of observations in each sample (see [`TTestDesign`]) for which the power of a t-test is at least
+/// `power`. This is the value computed by `R`'s `power.t.test` function with `strict = TRUE`, rounded up.
+///
+/// Arguments:
+/// - `design`: design of the t-test.
+/// - `delta`: actual difference of means.
+/// - `sd`: actual standard deviation.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+/// - `power`: target power.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `design` is [`TTestDesign::Welch`] with `sd_ratio` not finite and positive.
+/// - `delta` is not finite.
+/// - `sd` is not finite and positive.
+/// - `alpha` is not in interval `(0, 1)`.
+/// - `power` is not in interval `(0, 1)`.
+/// - The target is unattainable: `delta >= 0` for [`AltHyp::Lt`], `delta <= 0` for [`AltHyp::Gt`], or
+///   `delta == 0` for [`AltHyp::Ne`].
+/// - The required sample size exceeds `u64::MAX`.
+pub fn t_test_sample_size(
+    design: TTestDesign,
+    delta: f64,
+    sd: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+    power: f64,
+) -> StatsResult<u64> {
+    design.check()?;
+    check_delta_direction(delta, alt_hyp)?;
+    check_sd(sd)?;
+    check_power_args(alpha, power)?;
+
+    // Power is increasing in `n`, so bracket the result by doubling and then bisect.
+    let d = delta / sd;
+    let reaches = |n: u64| design.power(n as f64, d, alt_hyp, alpha) >= power;
+    let (mut lo, mut hi) = (1, 2);
+    while !reaches(hi) {
+        lo = hi;
+        hi = hi.checked_mul(2).ok_or(StatsError(
+            "power target is out of range: required sample size exceeds `u64::MAX`",
+        ))?;
+    }
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if reaches(mid) { hi = mid } else { lo = mid }
+    }
+    Ok(hi)
+}
+
+/// Smallest difference of means detectable by a t-test with power at least `power`.
+/// The result is negative for [`AltHyp::Lt`] and positive otherwise.
+/// This is the value computed by `R`'s `power.t.test` function with `strict = TRUE`.
+///
+/// Arguments:
+/// - `design`: design of the t-test.
+/// - `n`: number of observations in each sample (see [`TTestDesign`]).
+/// - `sd`: actual standard deviation.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+/// - `power`: target power.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `design` is [`TTestDesign::Welch`] with `sd_ratio` not finite and positive.
+/// - `n < 2`.
+/// - `sd` is not finite and positive.
+/// - `alpha` is not in interval `(0, 1)`.
+/// - `power` is not in interval `(0, 1)`.
+/// - The target is unattainable: `power <= alpha`.
+pub fn t_test_min_delta(
+    design: TTestDesign,
+    n: u64,
+    sd: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+    power: f64,
+) -> StatsResult<f64> {
+    design.check()?;
+    check_n_at_least_2(n)?;
+    check_sd(sd)?;
+    check_power_args(alpha, power)?;
+    Ok(design.min_d(n, alt_hyp, alpha, power)? * sd)
+}
+
+/// Largest standard deviation for which a t-test has power at least `power`.
+/// This is the value computed by `R`'s `power.t.test` function with `strict = TRUE`.
+///
+/// Arguments:
+/// - `design`: design of the t-test.
+/// - `n`: number of observations in each sample (see [`TTestDesign`]).
+/// - `delta`: actual difference of means.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+/// - `power`: target power.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `design` is [`TTestDesign::Welch`] with `sd_ratio` not finite and positive.
+/// - `n < 2`.
+/// - `delta` is not finite.
+/// - `alpha` is not in interval `(0, 1)`.
+/// - `power` is not in interval `(0, 1)`.
+/// - The target is unattainable: `power <= alpha`, `delta >= 0` for [`AltHyp::Lt`], `delta <= 0` for
+///   [`AltHyp::Gt`], or `delta == 0` for [`AltHyp::Ne`].
+pub fn t_test_max_sd(
+    design: TTestDesign,
+    n: u64,
+    delta: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+    power: f64,
+) -> StatsResult<f64> {
+    design.check()?;
+    check_n_at_least_2(n)?;
+    check_delta_direction(delta, alt_hyp)?;
+    check_power_args(alpha, power)?;
+    Ok(delta / design.min_d(n, alt_hyp, alpha, power)?)
+}
+
 #[cfg(test)]
 #[allow(clippy::too_many_arguments)]
 mod test {
     //! Used R's t.test function to generate expected values.
     //! https://www.rdocumentation.org/packages/stats/versions/3.6.2/topics/t.test
+    //!
+    //! Expected values for power analysis were computed with high precision (using the `mpmath` Python library)
+    //! by integrating the noncentral t density. They agree with R's power.t.test function (e.g.,
+    //! `power.t.test(n = 20, delta = 1)` gives power 0.8689528 and `power.t.test(power = .90, delta = 1)` gives
+    //! `n = 22.02110`).
 
     use super::*;
     use crate::{
@@ -826,4 +1214,118 @@ mod test {
             .unwrap();
         }
     }
+
+    #[test]
+    fn test_noncentral_t_cdf() {
+        let cases = [
+            (2., 10., 1., 0.8076115625),
+            (-1., 5., 2., 0.002316375654),
+            (3., 3., -1., 0.9962769530),
+            (25., 30., 20., 0.9256775570),
+            (1.5, 8., 0., 0.9139983540),
+        ];
+        for (t, df, ncp, exp_cdf) in cases {
+            let cdf = noncentral_t_cdf(t, df, ncp);
+            assert!(
+                exp_cdf.approx_eq(cdf, 1e-8),
+                "t={t}, df={df}, ncp={ncp} -> exp_cdf={exp_cdf}, cdf={cdf}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_t_test_power() {
+        use TTestDesign::*;
+        const EPSILON: f64 = 1e-6;
+        let welch = Welch { sd_ratio: 2. };
+
+        let cases = [
+            (Pooled, 20, 1., 1., AltHyp::Ne, 0.8689530277),
+            (Pooled, 20, 1., 1., AltHyp::Gt, 0.9279024734),
+            (OneSample, 10, 0.8, 1., AltHyp::Gt, 0.7544247592),
+            (OneSample, 10, -0.8, 1., AltHyp::Lt, 0.7544247592),
+            (Paired, 10, 1.6, 2., AltHyp::Ne, 0.6162327635),
+            (OneSample, 10, -0.3, 1., AltHyp::Gt, 0.005752062000),
+            (welch, 15, 1.5, 2., AltHyp::Ne, 0.2361264383),
+            (welch, 15, 1.5, 2., AltHyp::Gt, 0.3488130790),
+        ];
+        for (design, n, delta, sd, alt_hyp, exp_power) in cases {
+            let power = t_test_power(design, n, delta, sd, alt_hyp, ALPHA).unwrap();
+            assert!(
+                exp_power.approx_eq(power, EPSILON),
+                "{design:?}, n={n}, delta={delta}, sd={sd}, {alt_hyp:?} -> exp_power={exp_power}, power={power}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_t_test_solve() {
+        use TTestDesign::*;
+        const EPSILON: f64 = 1e-6;
+
+        assert_eq!(
+            23,
+            t_test_sample_size(Pooled, 1., 1., AltHyp::Ne, ALPHA, 0.9).unwrap()
+        );
+        assert_eq!(
+            18,
+            t_test_sample_size(Pooled, 1., 1., AltHyp::Gt, ALPHA, 0.9).unwrap()
+        );
+        assert_eq!(
+            101,
+            t_test_sample_size(Paired, -0.5, 2., AltHyp::Lt, ALPHA, 0.8).unwrap()
+        );
+        assert_eq!(
+            104,
+            t_test_sample_size(Welch { sd_ratio: 1.5 }, 0.5, 1., AltHyp::Ne, ALPHA, 0.8).unwrap()
+        );
+        assert!(t_test_sample_size(Pooled, 1., 1., AltHyp::Lt, ALPHA, 0.9).is_err());
+
+        let min_delta = t_test_min_delta(Pooled, 20, 1., AltHyp::Ne, ALPHA, 0.9).unwrap();
+        assert!(
+            1.051992948.approx_eq(min_delta, EPSILON),
+            "min_delta={min_delta}"
+        );
+        let min_delta = t_test_min_delta(OneSample, 10, 2., AltHyp::Lt, ALPHA, 0.8).unwrap();
+        assert!(
+            (-1.705675024).approx_eq(min_delta, EPSILON),
+            "min_delta={min_delta}"
+        );
+        let min_delta =
+            t_test_min_delta(Welch { sd_ratio: 0.5 }, 30, 1., AltHyp::Gt, ALPHA, 0.9).unwrap();
+        assert!(
+            0.6070879194.approx_eq(min_delta, EPSILON),
+            "min_delta={min_delta}"
+        );
+        assert!(t_test_min_delta(Pooled, 20, 1., AltHyp::Ne, ALPHA, ALPHA).is_err());
+
+        let max_sd = t_test_max_sd(Paired, 25, 3., AltHyp::Ne, ALPHA, 0.8).unwrap();
+        assert!(5.136751704.approx_eq(max_sd, EPSILON), "max_sd={max_sd}");
+        let max_sd = t_test_max_sd(Pooled, 40, 2., AltHyp::Gt, ALPHA, 0.95).unwrap();
+        assert!(2.695111324.approx_eq(max_sd, EPSILON), "max_sd={max_sd}");
+        assert!(t_test_max_sd(Pooled, 40, -2., AltHyp::Gt, ALPHA, 0.95).is_err());
+    }
+
+    #[test]
+    fn test_t_test_large_n() {
+        // Tiny effects need sample sizes with degrees of freedom beyond the range where `statrs`'s
+        // `StudentsT::inverse_cdf` is accurate or returns at all. Expected values come from the normal
+        // approximation, which is accurate well within the tolerances at these sample sizes.
+        use TTestDesign::*;
+
+        let n = t_test_sample_size(OneSample, 1e-4, 1., AltHyp::Ne, ALPHA, 0.8).unwrap();
+        assert!((n as f64 - 784886050.9326198).abs() < 10., "n={n}");
+        let n = t_test_sample_size(Pooled, 1e-6, 1., AltHyp::Gt, ALPHA, 0.8).unwrap();
+        assert!(n > 10_000_000_000_000, "n={n}");
+
+        let power = t_test_power(OneSample, 1_000_000_000, 1e-4, 1., AltHyp::Ne, ALPHA).unwrap();
+        assert!(0.8853791407623513.approx_eq(power, 1e-9), "power={power}");
+
+        let min_delta =
+            t_test_min_delta(OneSample, 1_000_000_000, 1., AltHyp::Gt, ALPHA, 0.8).unwrap();
+        assert!(
+            7.862923904006556e-5.approx_eq(min_delta, 1e-12),
+            "min_delta={min_delta}"
+        );
+    }
 }
@@ -0,0 +1,569 @@
+//! Tests of the null hypothesis that a sample comes from a Normal distribution, which is assumed by t-tests
+//! (e.g., [`student_1samp_test`](crate::normal::student_1samp_test) and [`welch_test`](crate::normal::welch_test))
+//! and matters most for small samples.
+//!
+//! The alternative hypothesis is that the sample does not come from a Normal distribution. Accordingly, the
+//! [`HypTestResult`]s returned by the `*_test` functions in this module have an alternative hypothesis of
+//! [`AltHyp::Ne`].
+//!
+//! - For the Shapiro-Wilk test, use `shapiro_wilk_*` functions.
+//! - For the Anderson-Darling and Cramér-von Mises tests, which are based on the empirical distribution function and
+//!   suit larger samples, use `anderson_darling_*` and `cramer_von_mises_*` functions.
+//! - For the Jarque-Bera test, which is based on sample skewness and kurtosis and requires only a
+//!   [`HigherMoments`] accumulator rather than the whole sample, use `jarque_bera_*` functions.
+//!
+//! This module is included by default. However, if `default-features = false` is specified in the dependency
+//! declaration for this library, then inclusion of this module is gated by feature "**normality**".
+//!
+//! # Example
+//!
+//! ```
+#![doc = include_str!("../examples/normality.rs")]
+//! ```
+
+use crate::core::{
+    AltHyp, HigherMoments, HypTestResult, SampleMoments, StatsError, StatsResult,
+    check_alpha_in_open_0_1,
+};
+use statrs::distribution::{ContinuousCDF, Normal};
+use std::{
+    cmp::Ordering,
+    f64::consts::{FRAC_1_SQRT_2, FRAC_PI_3, PI},
+};
+
+/// Smallest sample size supported by the Shapiro-Wilk test.
+const SHAPIRO_WILK_MIN_N: usize = 3;
+
+/// Largest sample size supported by the Shapiro-Wilk test.
+const SHAPIRO_WILK_MAX_N: usize = 5000;
+
+/// Evaluates the polynomial with coefficients `coefs`, in increasing order of degree, at `x`.
+fn poly(coefs: &[f64], x: f64) -> f64 {
+    coefs.iter().rev().fold(0., |acc, c| acc * x + c)
+}
+
+/// Coefficients `a_1, ..., a_{n/2}` of the Shapiro-Wilk statistic for sample size `n`, in Royston's approximation.
+/// The coefficient of the `i`-th smallest value is `-a_i` and that of the `i`-th largest value is `a_i`.
+fn shapiro_wilk_coefs(n: usize) -> Vec<f64> {
+    const C1: [f64; 6] = [0., 0.221157, -0.147981, -2.07119, 4.434685, -2.706056];
+    const C2: [f64; 6] = [0., 0.042981, -0.293762, -1.752461, 5.682633, -3.582633];
+
+    if n == 3 {
+        return vec![FRAC_1_SQRT_2];
+    }
+
+    let normal = Normal::standard();
+    let nf = n as f64;
+    let m: Vec<f64> = (1..=n / 2)
+        .map(|i| normal.inverse_cdf((i as f64 - 0.375) / (nf + 0.25)))
+        .collect();
+    let summ2 = 2. * m.iter().map(|v| v * v).sum::<f64>();
+    let ssumm2 = summ2.sqrt();
+    let rsn = 1. / nf.sqrt();
+
+    let mut a = vec![0.; n / 2];
+    a[0] = poly(&C1, rsn) - m[0] / ssumm2;
+    let (i1, fac) = if n > 5 {
+        a[1] = poly(&C2, rsn) - m[1] / ssumm2;
+        let num = summ2 - 2. * m[0] * m[0] - 2. * m[1] * m[1];
+        let den = 1. - 2. * a[0] * a[0] - 2. * a[1] * a[1];
+        (2, (num / den).sqrt())
+    } else {
+        let num = summ2 - 2. * m[0] * m[0];
+        let den = 1. - 2. * a[0] * a[0];
+        (1, (num / den).sqrt())
+    };
+    for i in i1..n / 2 {
+        a[i] = -m[i] / fac;
+    }
+    a
+}
+
+/// Shapiro-Wilk statistic W and its p-value.
+fn shapiro_wilk(dataset: &[f64]) -> StatsResult<(f64, f64)> {
+    const G: [f64; 2] = [-2.273, 0.459];
+    const C3: [f64; 4] = [0.544, -0.39978, 0.025054, -6.714e-4];
+    const C4: [f64; 4] = [1.3822, -0.77857, 0.062767, -0.0020322];
+    const C5: [f64; 4] = [-1.5861, -0.31082, -0.083751, 0.0038915];
+    const C6: [f64; 3] = [-0.4803, -0.082676, 0.0030302];
+
+    let n = dataset.len();
+    if !(SHAPIRO_WILK_MIN_N..=SHAPIRO_WILK_MAX_N).contains(&n) {
+        return Err(StatsError("sample size must be between 3 and 5000"));
+    }
+    if dataset.iter().any(|v| !v.is_finite()) {
+        return Err(StatsError("arg `dataset` must contain only finite values"));
+    }
+    let mut x = dataset.to_vec();
+    x.sort_by(f64::total_cmp);
+    let range = x[n - 1] - x[0];
+    if range == 0. {
+        return Err(StatsError("all values of arg `dataset` are identical"));
+    }
+
+    // W is the squared correlation between the sorted sample and the coefficients. It is computed with the
+    // sample scaled by its range, and as `1 - w1` for accuracy when W is close to 1.
+    let a = shapiro_wilk_coefs(n);
+    let nf = n as f64;
+    let coef = |i: usize| {
+        let j = n - 1 - i;
+        match i.cmp(&j) {
+            Ordering::Less => -a[i],
+            Ordering::Greater => a[j],
+            Ordering::Equal => 0.,
+        }
+    };
+    let sa = (0..n).map(coef).sum::<f64>() / nf;
+    let sx = x.iter().map(|v| v / range).sum::<f64>() / nf;
+    let (mut ssa, mut ssx, mut sax) = (0., 0., 0.);
+    for (i, v) in x.iter().enumerate() {
+        let asa = coef(i) - sa;
+        let xsx = v / range - sx;
+        ssa += asa * asa;
+        ssx += xsx * xsx;
+        sax += asa * xsx;
+    }
+    let ssassx = (ssa * ssx).sqrt();
+    let w1 = (ssassx - sax) * (ssassx + sax) / (ssa * ssx);
+    let w = 1. - w1;
+
+    if n == 3 {
+        let p = (6. / PI * (w.sqrt().asin() - FRAC_PI_3)).max(0.);
+        return Ok((w, p));
+    }
+
+    let y = w1.ln();
+    let (y, m, s) = if n <= 11 {
+        let gamma = poly(&G, nf);
+        if y >= gamma {
+            return Ok((w, 1e-99));
+        }
+        (-(gamma - y).ln(), poly(&C3, nf), poly(&C4, nf).exp())
+    } else {
+        let ln_n = nf.ln();
+        (y, poly(&C5, ln_n), poly(&C6, ln_n).exp())
+    };
+    let p = Normal::new(m, s)
+        .expect("`Normal::new` args should be finite with positive `std_dev`")
+        .sf(y);
+    Ok((w, p))
+}
+
+/// Shapiro-Wilk statistic W, which is close to `1` for samples from a Normal distribution.
+///
+/// The coefficients of the statistic are computed with Royston's approximation, as in `R`'s `shapiro.test`
+/// function.
+///
+/// Arguments:
+/// - `dataset`: sample values, in any order.
+///
+/// Reference: P. Royston, "Remark AS R94: A remark on algorithm AS 181: The W-test for normality",
+/// Applied Statistics, 44(4), 1995.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len()` is not in interval `[3, 5000]`.
+/// - `dataset` contains a value that is not finite.
+/// - All values of `dataset` are identical.
+pub fn shapiro_wilk_w(dataset: &[f64]) -> StatsResult<f64> {
+    shapiro_wilk(dataset).map(|(w, _)| w)
+}
+
+/// p-value of the Shapiro-Wilk test, computed with Royston's approximation, as in `R`'s `shapiro.test` function.
+///
+/// Arguments:
+/// - `dataset`: sample values, in any order.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len()` is not in interval `[3, 5000]`.
+/// - `dataset` contains a value that is not finite.
+/// - All values of `dataset` are identical.
+pub fn shapiro_wilk_p(dataset: &[f64]) -> StatsResult<f64> {
+    shapiro_wilk(dataset).map(|(_, p)| p)
+}
+
+/// Shapiro-Wilk test of the null hypothesis that the sample comes from a Normal distribution.
+///
+/// The returned result's alternative hypothesis is [`AltHyp::Ne`].
+///
+/// Arguments:
+/// - `dataset`: sample values, in any order.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len()` is not in interval `[3, 5000]`.
+/// - `dataset` contains a value that is not finite.
+/// - All values of `dataset` are identical.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn shapiro_wilk_test(dataset: &[f64], alpha: f64) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p = shapiro_wilk_p(dataset)?;
+    Ok(HypTestResult::new(p, alpha, AltHyp::Ne))
+}
+
+/// Smallest sample size supported by the Anderson-Darling and Cramér-von Mises tests, as in the `nortest` `R`
+/// package.
+const EDF_MIN_N: usize = 8;
+
+/// Values of the Normal CDF, with mean and standard deviation estimated from the sample, at the sorted sample
+/// values.
+fn fitted_normal_cdfs(dataset: &[f64]) -> StatsResult<Vec<f64>> {
+    if dataset.len() < EDF_MIN_N {
+        return Err(StatsError("sample size must be at least 8"));
+    }
+    if dataset.iter().any(|v| !v.is_finite()) {
+        return Err(StatsError("arg `dataset` must contain only finite values"));
+    }
+    let moments = SampleMoments::from_slice(dataset);
+    let mean = moments.mean()?;
+    let sd = moments.stdev()?;
+    if sd == 0. {
+        return Err(StatsError("all values of arg `dataset` are identical"));
+    }
+    let normal =
+        Normal::new(mean, sd).expect("`Normal::new` args should be finite with positive `std_dev`");
+    let mut x = dataset.to_vec();
+    x.sort_by(f64::total_cmp);
+    Ok(x.into_iter().map(|v| normal.cdf(v)).collect())
+}
+
+/// Anderson-Darling statistic A² for the composite hypothesis of normality, with mean and variance estimated from
+/// the sample.
+///
+/// Arguments:
+/// - `dataset`: sample values, in any order.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len() < 8`.
+/// - `dataset` contains a value that is not finite.
+/// - All values of `dataset` are identical.
+pub fn anderson_darling_a2(dataset: &[f64]) -> StatsResult<f64> {
+    let p = fitted_normal_cdfs(dataset)?;
+    let n = p.len();
+    let s = (0..n)
+        .map(|i| (2 * i + 1) as f64 * (p[i].ln() + (-p[n - 1 - i]).ln_1p()))
+        .sum::<f64>();
+    Ok(-(n as f64) - s / n as f64)
+}
+
+/// p-value of the Anderson-Darling normality test, from the approximation for the statistic adjusted for sample
+/// size used by the `ad.test` function of the `nortest` `R` package.
+///
+/// Arguments:
+/// - `dataset`: sample values, in any order.
+///
+/// Reference: R. B. D'Agostino and M. A. Stephens, "Goodness-of-Fit Techniques", Marcel Dekker, 1986, Table 4.9.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len() < 8`.
+/// - `dataset` contains a value that is not finite.
+/// - All values of `dataset` are identical.
+pub fn anderson_darling_p(dataset: &[f64]) -> StatsResult<f64> {
+    let a2 = anderson_darling_a2(dataset)?;
+    let n = dataset.len() as f64;
+    let aa = (1. + 0.75 / n + 2.25 / (n * n)) * a2;
+    let p = if aa < 0.2 {
+        1. - (-13.436 + 101.14 * aa - 223.73 * aa * aa).exp()
+    } else if aa < 0.34 {
+        1. - (-8.318 + 42.796 * aa - 59.938 * aa * aa).exp()
+    } else if aa < 0.6 {
+        (0.9177 - 4.279 * aa - 1.38 * aa * aa).exp()
+    } else if aa < 10. {
+        (1.2937 - 5.709 * aa + 0.0186 * aa * aa).exp()
+    } else {
+        3.7e-24
+    };
+    Ok(p.clamp(0., 1.))
+}
+
+/// Anderson-Darling test of the null hypothesis that the sample comes from a Normal distribution.
+///
+/// The returned result's alternative hypothesis is [`AltHyp::Ne`].
+///
+/// Arguments:
+/// - `dataset`: sample values, in any order.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len() < 8`.
+/// - `dataset` contains a value that is not finite.
+/// - All values of `dataset` are identical.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn anderson_darling_test(dataset: &[f64], alpha: f64) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p = anderson_darling_p(dataset)?;
+    Ok(HypTestResult::new(p, alpha, AltHyp::Ne))
+}
+
+/// Cramér-von Mises statistic W² for the composite hypothesis of normality, with mean and variance estimated from
+/// the sample.
+///
+/// Arguments:
+/// - `dataset`: sample values, in any order.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len() < 8`.
+/// - `dataset` contains a value that is not finite.
+/// - All values of `dataset` are identical.
+pub fn cramer_von_mises_w2(dataset: &[f64]) -> StatsResult<f64> {
+    let p = fitted_normal_cdfs(dataset)?;
+    let n = p.len() as f64;
+    let s = p
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v - (2 * i + 1) as f64 / (2. * n)).powi(2))
+        .sum::<f64>();
+    Ok(1. / (12. * n) + s)
+}
+
+/// p-value of the Cramér-von Mises normality test, from the approximation for the statistic adjusted for sample
+/// size used by the `cvm.test` function of the `nortest` `R` package.
+///
+/// Arguments:
+/// - `dataset`: sample values, in any order.
+///
+/// Reference: R. B. D'Agostino and M. A. Stephens, "Goodness-of-Fit Techniques", Marcel Dekker, 1986, Table 4.9.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len() < 8`.
+/// - `dataset` contains a value that is not finite.
+/// - All values of `dataset` are identical.
+pub fn cramer_von_mises_p(dataset: &[f64]) -> StatsResult<f64> {
+    let w2 = cramer_von_mises_w2(dataset)?;
+    let n = dataset.len() as f64;
+    let ww = (1. + 0.5 / n) * w2;
+    let p = if ww < 0.0275 {
+        1. - (-13.953 + 775.5 * ww - 12542.61 * ww * ww).exp()
+    } else if ww < 0.051 {
+        1. - (-5.903 + 179.546 * ww - 1515.29 * ww * ww).exp()
+    } else if ww < 0.092 {
+        (0.886 - 31.62 * ww + 10.897 * ww * ww).exp()
+    } else if ww < 1.1 {
+        (1.111 - 34.242 * ww + 12.832 * ww * ww).exp()
+    } else {
+        7.37e-10
+    };
+    Ok(p.clamp(0., 1.))
+}
+
+/// Cramér-von Mises test of the null hypothesis that the sample comes from a Normal distribution.
+///
+/// The returned result's alternative hypothesis is [`AltHyp::Ne`].
+///
+/// Arguments:
+/// - `dataset`: sample values, in any order.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len() < 8`.
+/// - `dataset` contains a value that is not finite.
+/// - All values of `dataset` are identical.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn cramer_von_mises_test(dataset: &[f64], alpha: f64) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p = cramer_von_mises_p(dataset)?;
+    Ok(HypTestResult::new(p, alpha, AltHyp::Ne))
+}
+
+/// Jarque-Bera statistic `n / 6 * (g1^2 + g2^2 / 4)`, where `g1` and `g2` are the sample skewness and excess
+/// kurtosis. It is computed from the moments alone, so it supports samples accumulated in a streaming fashion.
+///
+/// Arguments:
+/// - `moments`: sample higher moments struct.
+///
+/// # Errors
+///
+/// Returns an error if the sample is empty or all its values are equal.
+pub fn jarque_bera_jb(moments: &HigherMoments) -> StatsResult<f64> {
+    let g1 = moments.skewness()?;
+    let g2 = moments.excess_kurtosis()?;
+    Ok(moments.nf() / 6. * (g1 * g1 + g2 * g2 / 4.))
+}
+
+/// p-value of the Jarque-Bera normality test, from the asymptotic chi-square distribution with 2 degrees of
+/// freedom, as in the `jarque.bera.test` function of the `tseries` `R` package. The test is intended for large
+/// samples.
+///
+/// Arguments:
+/// - `moments`: sample higher moments struct.
+///
+/// # Errors
+///
+/// Returns an error if the sample is empty or all its values are equal.
+pub fn jarque_bera_p(moments: &HigherMoments) -> StatsResult<f64> {
+    let jb = jarque_bera_jb(moments)?;
+    // Survival function of the chi-square distribution with 2 degrees of freedom.
+    Ok((-jb / 2.).exp())
+}
+
+/// Jarque-Bera test of the null hypothesis that the sample comes from a Normal distribution.
+///
+/// The returned result's alternative hypothesis is [`AltHyp::Ne`].
+///
+/// Arguments:
+/// - `moments`: sample higher moments struct.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - The sample is empty or all its values are equal.
+/// - `alpha` not in interval `(0, 1)`.
+pub fn jarque_bera_test(moments: &HigherMoments, alpha: f64) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p = jarque_bera_p(moments)?;
+    Ok(HypTestResult::new(p, alpha, AltHyp::Ne))
+}
+
+#[cfg(test)]
+mod test {
+    //! Expected values were computed with an independent high-precision implementation of Royston's algorithm
+    //! (using the `mpmath` Python library). They agree with `R`'s `shapiro.test` function for the `ToothGrowth$len`
+    //! and `mtcars$mpg` datasets and for the example in Shapiro and Wilk's paper.
+    //!
+    //! Expected values for the Anderson-Darling, Cramér-von Mises, and Jarque-Bera tests were computed likewise,
+    //! from the formulas in the `nortest` and `tseries` `R` packages. For `mtcars$mpg`, they agree with `ad.test`
+    //! and `jarque.bera.test`.
+
+    use super::*;
+    use crate::dev_utils::ApproxEq;
+
+    const EPSILON: f64 = 1e-9;
+
+    const TOOTH_GROWTH_LEN: [f64; 60] = [
+        4.2, 11.5, 7.3, 5.8, 6.4, 10., 11.2, 11.2, 5.2, 7., 16.5, 16.5, 15.2, 17.3, 22.5, 17.3,
+        13.6, 14.5, 18.8, 15.5, 23.6, 18.5, 33.9, 25.5, 26.4, 32.5, 26.7, 21.5, 23.3, 29.5, 15.2,
+        21.5, 17.6, 9.7, 14.5, 10., 8.2, 9.4, 16.5, 9.7, 19.7, 23.3, 23.6, 26.4, 20., 25.2, 25.8,
+        21.2, 14.5, 27.3, 25.5, 26.4, 22.4, 24.5, 24.8, 30.9, 26.4, 27.3, 29.4, 23.,
+    ];
+
+    #[test]
+    fn test_shapiro_wilk() {
+        let exp_growth: Vec<f64> = (0..25).map(|i| 0.1 * 1.5_f64.powi(i)).collect();
+        let cases: [(&[f64], f64, f64); 8] = [
+            (&[4., 1., 2.], 0.964285714285714, 0.63688684502897),
+            (&[2.1, 3.5, 0.7, 9.], 0.889531836724075, 0.380944004898219),
+            (&[1., 2., 3., 4., 10.], 0.835788316420997, 0.153612583765516),
+            (
+                &[3.1, 2.9, 3.3, 3., 3.2, 2.8, 3.4],
+                0.978001629412101,
+                0.949288562353616,
+            ),
+            (
+                &[
+                    148., 154., 158., 160., 161., 162., 166., 170., 182., 195., 236.,
+                ],
+                0.788814694835387,
+                0.00670381405650301,
+            ),
+            (
+                &[
+                    0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0., 2., 1.9, 0.8, 1.1, 0.1, -0.1,
+                    4.4, 5.5, 1.6, 4.6, 3.4,
+                ],
+                0.946072762509969,
+                0.311375416723219,
+            ),
+            (&exp_growth, 0.568031138545812, 1.8844869519858e-7),
+            (&TOOTH_GROWTH_LEN, 0.967428643853889, 0.109100494688908),
+        ];
+
+        for (dataset, exp_w, exp_p) in cases {
+            let n = dataset.len();
+            let w = shapiro_wilk_w(dataset).unwrap();
+            let p = shapiro_wilk_p(dataset).unwrap();
+            assert!(exp_w.approx_eq(w, EPSILON), "n={n} -> exp_w={exp_w}, w={w}");
+            assert!(
+                exp_p.approx_eq(p, EPSILON * exp_p.max(1e-3)),
+                "n={n} -> exp_p={exp_p}, p={p}"
+            );
+        }
+    }
+
+    const MTCARS_MPG: [f64; 32] = [
+        21.0, 21.0, 22.8, 21.4, 18.7, 18.1, 14.3, 24.4, 22.8, 19.2, 17.8, 16.4, 17.3, 15.2, 10.4,
+        10.4, 14.7, 32.4, 30.4, 33.9, 21.5, 15.5, 15.2, 13.3, 19.2, 27.3, 26.0, 30.4, 15.8, 19.7,
+        15.0, 21.4,
+    ];
+
+    #[test]
+    fn test_edf_and_moment_tests() {
+        let exp_growth: Vec<f64> = (0..25).map(|i| 0.1 * 1.5_f64.powi(i)).collect();
+        let large_mean: Vec<f64> = (0..200)
+            .map(|i| 1000. + ((i * 37) % 101) as f64 / 10.)
+            .collect();
+
+        // (dataset, (A², AD p), (W², CvM p), (JB, JB p))
+        #[allow(clippy::type_complexity)]
+        let cases: [(&[f64], (f64, f64), (f64, f64), (f64, f64)); 5] = [
+            (
+                &MTCARS_MPG,
+                (0.579680713977765, 0.120737061441899),
+                (0.0882042524355173, 0.155805273975959),
+                (2.24115526128469, 0.326091379838003),
+            ),
+            (
+                &[
+                    148., 154., 158., 160., 161., 162., 166., 170., 182., 195., 236.,
+                ],
+                (0.946771879598887, 0.0104540240051477),
+                (0.163917060193648, 0.0125235937045558),
+                (6.98284823734464, 0.0304574662245819),
+            ),
+            (
+                &[0.1, -0.5, 1.2, 0.3, -1.1, 0.8, -0.2, 0.05, -0.7, 0.4],
+                (0.111791168343688, 0.987659479633524),
+                (0.0158234130438719, 0.989226948424871),
+                (0.220896761926863, 0.895432550362117),
+            ),
+            (
+                &exp_growth,
+                (4.43233718262804, 2.35862274774434e-11),
+                (0.875528974832834, 4.4307882815963e-9),
+                (57.1375809297094, 3.91500162325777e-13),
+            ),
+            (
+                &large_mean,
+                (2.2392666960814, 1.07033373696165e-5),
+                (0.303929252682333, 0.00029430348569079),
+                (12.1019985268007, 0.00235550705808689),
+            ),
+        ];
+
+        for (dataset, (exp_a2, exp_ad_p), (exp_w2, exp_cvm_p), (exp_jb, exp_jb_p)) in cases {
+            let n = dataset.len();
+            let moments = HigherMoments::from_slice(dataset);
+            let actuals = [
+                ("A²", exp_a2, anderson_darling_a2(dataset).unwrap()),
+                ("AD p", exp_ad_p, anderson_darling_p(dataset).unwrap()),
+                ("W²", exp_w2, cramer_von_mises_w2(dataset).unwrap()),
+                ("CvM p", exp_cvm_p, cramer_von_mises_p(dataset).unwrap()),
+                ("JB", exp_jb, jarque_bera_jb(&moments).unwrap()),
+                ("JB p", exp_jb_p, jarque_bera_p(&moments).unwrap()),
+            ];
+            for (name, exp, act) in actuals {
+                assert!(
+                    exp.approx_eq(act, EPSILON * exp.max(1e-3)),
+                    "n={n}, {name} -> exp={exp}, act={act}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,808 @@
+//! Outlier detection, for reporting suspicious items, such as benchmark timings disturbed by a background task,
+//! and optionally excluding them before computing [`SampleMoments`](crate::core::SampleMoments).
+//!
+//! - Rules of thumb: Tukey's fences ([`tukey_outliers`]) and MAD-based modified z-scores
+//!   ([`modified_z_outliers`]), which make no distributional assumption beyond a roughly symmetric bulk.
+//! - Tests that assume the non-outlying items come from a Normal distribution: Grubbs' test for a single outlier,
+//!   one- or two-sided and optionally iterated (`grubbs_*` functions), Dixon's Q test for samples of `3` to `10`
+//!   items ([`dixon_outliers`]), and Rosner's generalized ESD test for up to a given number of outliers
+//!   ([`generalized_esd_outliers`]).
+//!
+//! The `*_outliers` functions return an [`Outliers`] report with the indices and values of the flagged items, the
+//! statistics and critical values that flagged them, and the remaining items ([`Outliers::inliers`]).
+//!
+//! The functions return an error if the data contains a `NaN` value.
+//!
+//! This module is included by default. However, if `default-features = false` is specified in the dependency
+//! declaration for this library, then inclusion of this module is gated by feature "**outliers**".
+//!
+//! # Example
+//!
+//! ```
+#![doc = include_str!("../examples/outliers.rs")]
+//! ```
+
+use crate::{
+    core::{AltHyp, HypTestResult, StatsError, StatsResult, check_alpha_in_open_0_1},
+    normal::{t_alpha, t_to_p},
+    robust::{MAD_NORMAL_CONSTANT, mad, median},
+};
+
+/// Tukey's fence multiplier for "outside" items.
+pub const TUKEY_K_OUTSIDE: f64 = 1.5;
+
+/// Tukey's fence multiplier for "far out" items.
+pub const TUKEY_K_FAR_OUT: f64 = 3.;
+
+/// Threshold on the absolute modified z-score recommended by Iglewicz & Hoaglin.
+pub const MODIFIED_Z_THRESHOLD: f64 = 3.5;
+
+/// Critical values of Dixon's Q statistic for sample sizes `3` to `10`, at `alpha` = `0.1`, `0.05`, and `0.01`.
+const DIXON_Q_CRIT: [[f64; 8]; 3] = [
+    [0.941, 0.765, 0.642, 0.560, 0.507, 0.468, 0.437, 0.412],
+    [0.970, 0.829, 0.710, 0.625, 0.568, 0.526, 0.493, 0.466],
+    [0.994, 0.926, 0.821, 0.740, 0.680, 0.634, 0.598, 0.568],
+];
+
+/// Report of the items flagged as outliers by one of the `*_outliers` functions of this module.
+///
+/// The procedures examine candidate items in steps. [`Self::stats`] and [`Self::crit_values`] hold the statistic
+/// and critical value of each step, and the first [`Self::len`] steps are the ones that flagged the items of
+/// [`Self::indices`]. For the sequential tests ([`grubbs_outliers`] and [`generalized_esd_outliers`]), the steps
+/// that did not flag an item are also reported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outliers {
+    indices: Vec<usize>,
+    values: Vec<f64>,
+    stats: Vec<f64>,
+    crit_values: Vec<f64>,
+    inliers: Vec<f64>,
+}
+
+impl Outliers {
+    /// Builds the report from the steps, given as `(index, value, stat, crit_value)`, of which the first `n_flagged`
+    /// flagged their items.
+    fn new(dataset: &[f64], steps: Vec<(usize, f64, f64, f64)>, n_flagged: usize) -> Self {
+        let indices: Vec<usize> = steps[..n_flagged].iter().map(|s| s.0).collect();
+        let inliers = dataset
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !indices.contains(i))
+            .map(|(_, &v)| v)
+            .collect();
+        Self {
+            values: steps[..n_flagged].iter().map(|s| s.1).collect(),
+            stats: steps.iter().map(|s| s.2).collect(),
+            crit_values: steps.iter().map(|s| s.3).collect(),
+            indices,
+            inliers,
+        }
+    }
+
+    /// Number of items flagged as outliers.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Whether no item was flagged as an outlier.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Indices of the flagged items in the dataset, in the order they were flagged.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Values of the flagged items, in the same order as [`Self::indices`].
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Statistic of each step of the procedure.
+    pub fn stats(&self) -> &[f64] {
+        &self.stats
+    }
+
+    /// Critical value of each step of the procedure.
+    pub fn crit_values(&self) -> &[f64] {
+        &self.crit_values
+    }
+
+    /// Items of the dataset that were not flagged, in their original order.
+    pub fn inliers(&self) -> &[f64] {
+        &self.inliers
+    }
+}
+
+/// Checks that `dataset` has no `NaN` values and returns its `(index, value)` pairs sorted by value.
+fn sorted_with_indices(dataset: &[f64]) -> StatsResult<Vec<(usize, f64)>> {
+    if dataset.iter().any(|v| v.is_nan()) {
+        return Err(StatsError("arg `dataset` must not contain NaN values"));
+    }
+    let mut v: Vec<(usize, f64)> = dataset.iter().copied().enumerate().collect();
+    v.sort_by(|a, b| a.1.total_cmp(&b.1));
+    Ok(v)
+}
+
+/// Type 7 (linear interpolation) quantile of sorted non-empty `v`, as `R`'s default `quantile`.
+fn quantile_sorted(v: &[(usize, f64)], q: f64) -> f64 {
+    let h = (v.len() - 1) as f64 * q;
+    let lo = h.floor() as usize;
+    let hi = (lo + 1).min(v.len() - 1);
+    v[lo].1 + (h - lo as f64) * (v[hi].1 - v[lo].1)
+}
+
+/// Tukey's fences `(Q1 - k * IQR, Q3 + k * IQR)`, where `Q1` and `Q3` are the sample quartiles (computed as
+/// `R`'s default `quantile`) and `IQR = Q3 - Q1`.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `k`: fence multiplier, e.g., [`TUKEY_K_OUTSIDE`] or [`TUKEY_K_FAR_OUT`].
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset` is empty.
+/// - `dataset` contains a `NaN` value.
+/// - `k` is negative or `NaN`.
+pub fn tukey_fences(dataset: &[f64], k: f64) -> StatsResult<(f64, f64)> {
+    let (q1, q3) = quartiles(dataset, k)?;
+    let iqr = q3 - q1;
+    Ok((q1 - k * iqr, q3 + k * iqr))
+}
+
+/// Validates the arguments of the Tukey functions and returns the sample quartiles.
+fn quartiles(dataset: &[f64], k: f64) -> StatsResult<(f64, f64)> {
+    if dataset.is_empty() {
+        return Err(StatsError("sample size must be positive"));
+    }
+    if k.is_nan() || k < 0. {
+        return Err(StatsError("arg `k` must be non-negative"));
+    }
+    let v = sorted_with_indices(dataset)?;
+    Ok((quantile_sorted(&v, 0.25), quantile_sorted(&v, 0.75)))
+}
+
+/// Flags the items outside [Tukey's fences](tukey_fences).
+///
+/// The statistic of a flagged item is its distance from the nearest quartile in units of the interquartile range,
+/// negative below `Q1`, and its critical value is `k`. Items are reported in dataset order.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `k`: fence multiplier, e.g., [`TUKEY_K_OUTSIDE`] or [`TUKEY_K_FAR_OUT`].
+///
+/// Reference: Tukey, J. W. (1977). *Exploratory Data Analysis*. Addison-Wesley.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset` is empty.
+/// - `dataset` contains a `NaN` value.
+/// - `k` is negative or `NaN`.
+pub fn tukey_outliers(dataset: &[f64], k: f64) -> StatsResult<Outliers> {
+    let (q1, q3) = quartiles(dataset, k)?;
+    let iqr = q3 - q1;
+    let (lo, hi) = (q1 - k * iqr, q3 + k * iqr);
+    let steps: Vec<_> = dataset
+        .iter()
+        .enumerate()
+        .filter(|&(_, &x)| x < lo || x > hi)
+        .map(|(i, &x)| {
+            let stat = if x > hi {
+                (x - q3) / iqr
+            } else {
+                (x - q1) / iqr
+            };
+            (i, x, stat, k)
+        })
+        .collect();
+    let n_flagged = steps.len();
+    Ok(Outliers::new(dataset, steps, n_flagged))
+}
+
+/// Modified z-scores `(x_i - median) / (MAD_NORMAL_CONSTANT * MAD)` of the sample items, where `MAD` is the raw
+/// median absolute deviation, so that they are comparable to z-scores for normal data.
+///
+/// Reference: Iglewicz, B. & Hoaglin, D. C. (1993). *How to Detect and Handle Outliers*. ASQC Quality Press.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset` is empty.
+/// - `dataset` contains a `NaN` value.
+/// - The median absolute deviation is zero.
+pub fn modified_z_scores(dataset: &[f64]) -> StatsResult<Vec<f64>> {
+    let med = median(dataset)?;
+    let scale = mad(dataset, MAD_NORMAL_CONSTANT)?;
+    if scale == 0. {
+        return Err(StatsError("median absolute deviation is zero"));
+    }
+    Ok(dataset.iter().map(|x| (x - med) / scale).collect())
+}
+
+/// Flags the items whose [modified z-score](modified_z_scores) exceeds `threshold` in absolute value.
+///
+/// The statistic of a flagged item is its modified z-score and its critical value is `threshold`. Items are
+/// reported in dataset order.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `threshold`: threshold on the absolute modified z-score, e.g., [`MODIFIED_Z_THRESHOLD`].
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset` is empty.
+/// - `dataset` contains a `NaN` value.
+/// - The median absolute deviation is zero.
+/// - `threshold` is negative or `NaN`.
+pub fn modified_z_outliers(dataset: &[f64], threshold: f64) -> StatsResult<Outliers> {
+    if threshold.is_nan() || threshold < 0. {
+        return Err(StatsError("arg `threshold` must be non-negative"));
+    }
+    let steps: Vec<_> = modified_z_scores(dataset)?
+        .into_iter()
+        .enumerate()
+        .filter(|(_, z)| z.abs() > threshold)
+        .map(|(i, z)| (i, dataset[i], z, threshold))
+        .collect();
+    let n_flagged = steps.len();
+    Ok(Outliers::new(dataset, steps, n_flagged))
+}
+
+/// Position in `v`, which is sorted by value, of the candidate outlier for `alt_hyp` and its Grubbs statistic.
+/// Returns `None` if the sample variance is zero.
+fn grubbs_candidate(v: &[(usize, f64)], alt_hyp: AltHyp) -> Option<(usize, f64)> {
+    let n = v.len() as f64;
+    let mean = v.iter().map(|p| p.1).sum::<f64>() / n;
+    let s = (v.iter().map(|p| (p.1 - mean).powi(2)).sum::<f64>() / (n - 1.)).sqrt();
+    if s == 0. {
+        return None;
+    }
+    let last = v.len() - 1;
+    let (g_min, g_max) = ((mean - v[0].1) / s, (v[last].1 - mean) / s);
+    let value = match alt_hyp {
+        AltHyp::Lt => (0, g_min),
+        AltHyp::Gt => (last, g_max),
+        AltHyp::Ne if g_min > g_max => (0, g_min),
+        AltHyp::Ne => (last, g_max),
+    };
+    Some(value)
+}
+
+/// Checks the dataset for the Grubbs and ESD functions and returns it sorted by value.
+fn sorted_for_grubbs(dataset: &[f64]) -> StatsResult<Vec<(usize, f64)>> {
+    if dataset.len() < 3 {
+        return Err(StatsError("sample size must be at least `3`"));
+    }
+    sorted_with_indices(dataset)
+}
+
+/// Grubbs' statistic: `(max - mean) / s` for `alt_hyp == Gt`, `(mean - min) / s` for `alt_hyp == Lt`, and the
+/// larger of the two for `alt_hyp == Ne`, where `s` is the sample standard deviation.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `alt_hyp`: alternative hypothesis: the maximum (`Gt`), the minimum (`Lt`), or the more extreme of the two
+///   (`Ne`) is an outlier.
+///
+/// Reference: Grubbs, F. E. (1969). Procedures for detecting outlying observations in samples.
+/// *Technometrics*, 11(1), 1–21.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len() < 3`.
+/// - `dataset` contains a `NaN` value.
+/// - The sample variance is zero.
+pub fn grubbs_g(dataset: &[f64], alt_hyp: AltHyp) -> StatsResult<f64> {
+    let v = sorted_for_grubbs(dataset)?;
+    let (_, g) = grubbs_candidate(&v, alt_hyp).ok_or(StatsError("sample variance is zero"))?;
+    Ok(g)
+}
+
+/// p-value of Grubbs' statistic `g` for sample size `n`, from the Bonferroni bound on the Student's t
+/// distribution, which is exact for one-sided tests at the usual significance levels.
+fn grubbs_g_to_p(g: f64, n: usize, alt_hyp: AltHyp) -> StatsResult<f64> {
+    let nf = n as f64;
+    let den = (nf - 1.).powi(2) - nf * g * g;
+    if den <= 0. {
+        return Ok(0.);
+    }
+    let t = (nf * (nf - 2.) * g * g / den).sqrt();
+    let tails = if alt_hyp == AltHyp::Ne { 2. } else { 1. };
+    let p = tails * nf * t_to_p(t, nf - 2., AltHyp::Gt)?;
+    Ok(p.min(1.))
+}
+
+/// p-value of Grubbs' test for a single outlier, as in `R`'s `outliers::grubbs.test`.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `alt_hyp`: alternative hypothesis: the maximum (`Gt`), the minimum (`Lt`), or the more extreme of the two
+///   (`Ne`) is an outlier.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len() < 3`.
+/// - `dataset` contains a `NaN` value.
+/// - The sample variance is zero.
+pub fn grubbs_p(dataset: &[f64], alt_hyp: AltHyp) -> StatsResult<f64> {
+    let g = grubbs_g(dataset, alt_hyp)?;
+    grubbs_g_to_p(g, dataset.len(), alt_hyp)
+}
+
+/// Critical value of Grubbs' statistic for sample size `n`: `(n - 1) / sqrt(n) * sqrt(t² / (n - 2 + t²))`, where
+/// `t` is the upper `alpha / n` (`alpha / (2 * n)` for `alt_hyp == Ne`) critical value of the Student's t
+/// distribution with `n - 2` degrees of freedom.
+///
+/// Arguments:
+/// - `n`: sample size.
+/// - `alt_hyp`: alternative hypothesis, as in [`grubbs_g`].
+/// - `alpha`: significance level.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n < 3`.
+/// - `alpha` not in open interval `(0, 1)`.
+pub fn grubbs_g_crit(n: usize, alt_hyp: AltHyp, alpha: f64) -> StatsResult<f64> {
+    check_alpha_in_open_0_1(alpha)?;
+    if n < 3 {
+        return Err(StatsError("sample size must be at least `3`"));
+    }
+    let nf = n as f64;
+    let tails = if alt_hyp == AltHyp::Ne { 2. } else { 1. };
+    let t = t_alpha(nf - 2., alpha / (tails * nf))?;
+    Ok((nf - 1.) / nf.sqrt() * (t * t / (nf - 2. + t * t)).sqrt())
+}
+
+/// Grubbs' test for a single outlier.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `alt_hyp`: alternative hypothesis: the maximum (`Gt`), the minimum (`Lt`), or the more extreme of the two
+///   (`Ne`) is an outlier.
+/// - `alpha`: significance level.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len() < 3`.
+/// - `dataset` contains a `NaN` value.
+/// - The sample variance is zero.
+/// - `alpha` not in open interval `(0, 1)`.
+pub fn grubbs_test(dataset: &[f64], alt_hyp: AltHyp, alpha: f64) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p = grubbs_p(dataset, alt_hyp)?;
+    Ok(HypTestResult::new(p, alpha, alt_hyp))
+}
+
+/// Runs up to `max_steps` steps of Grubbs' test, each one on the sample remaining after removing the candidates
+/// of the previous steps, and returns the steps as `(index, value, g, g_crit)`. Stops early if the remaining sample
+/// variance is zero or, when `stop_on_accept`, after the first step whose statistic does not exceed its critical
+/// value.
+fn grubbs_steps(
+    dataset: &[f64],
+    alt_hyp: AltHyp,
+    alpha: f64,
+    max_steps: usize,
+    stop_on_accept: bool,
+) -> StatsResult<Vec<(usize, f64, f64, f64)>> {
+    check_alpha_in_open_0_1(alpha)?;
+    let mut v = sorted_for_grubbs(dataset)?;
+    if max_steps == 0 || max_steps > dataset.len() - 2 {
+        return Err(StatsError(
+            "arg `max_outliers` must be in interval [1, n - 2]",
+        ));
+    }
+    let mut steps = Vec::with_capacity(max_steps);
+    for step in 0..max_steps {
+        let Some((pos, g)) = grubbs_candidate(&v, alt_hyp) else {
+            if step == 0 {
+                return Err(StatsError("sample variance is zero"));
+            }
+            break;
+        };
+        let g_crit = grubbs_g_crit(v.len(), alt_hyp, alpha)?;
+        let (i, x) = v.remove(pos);
+        steps.push((i, x, g, g_crit));
+        if stop_on_accept && g <= g_crit {
+            break;
+        }
+    }
+    Ok(steps)
+}
+
+/// Iterated Grubbs' test: repeatedly applies [Grubbs' test](grubbs_test) at significance level `alpha`, removing
+/// the flagged item after each step, until a step does not flag its candidate or `max_outliers` items have been
+/// flagged.
+///
+/// The statistic and critical value of each step are [`grubbs_g`] and [`grubbs_g_crit`] for the remaining sample.
+/// The final step, if it did not flag its candidate, is also reported. Iterating the test is subject to masking,
+/// where several similar outliers inflate the standard deviation and hide each other; prefer
+/// [`generalized_esd_outliers`] when more than one outlier is expected.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `alt_hyp`: alternative hypothesis: the maximum (`Gt`), the minimum (`Lt`), or the more extreme of the two
+///   (`Ne`) is an outlier at each step.
+/// - `alpha`: significance level of each step.
+/// - `max_outliers`: maximum number of items to flag; use `1` for the non-iterated test.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len() < 3`.
+/// - `dataset` contains a `NaN` value.
+/// - The sample variance is zero.
+/// - `alpha` not in open interval `(0, 1)`.
+/// - `max_outliers` not in interval `[1, dataset.len() - 2]`.
+pub fn grubbs_outliers(
+    dataset: &[f64],
+    alt_hyp: AltHyp,
+    alpha: f64,
+    max_outliers: usize,
+) -> StatsResult<Outliers> {
+    let steps = grubbs_steps(dataset, alt_hyp, alpha, max_outliers, true)?;
+    let n_flagged = steps.iter().take_while(|s| s.2 > s.3).count();
+    Ok(Outliers::new(dataset, steps, n_flagged))
+}
+
+/// Rosner's generalized extreme Studentized deviate (ESD) test for up to `max_outliers` outliers.
+///
+/// Step `i` computes the statistic `R_i` and critical value `λ_i` of the two-sided Grubbs' test on the sample
+/// remaining after removing the candidates of the previous steps (see [`grubbs_g`] and [`grubbs_g_crit`]). The
+/// number of outliers is the largest `i` such that `R_i > λ_i`, and the candidates of steps `1` to `i` are flagged.
+/// Unlike [iterated Grubbs' test](grubbs_outliers), this is not subject to masking. All steps performed are
+/// reported; fewer than `max_outliers` are performed if the remaining sample variance becomes zero.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `alpha`: significance level.
+/// - `max_outliers`: maximum number of items to flag.
+///
+/// Reference: Rosner, B. (1983). Percentage points for a generalized ESD many-outlier procedure.
+/// *Technometrics*, 25(2), 165–172.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len() < 3`.
+/// - `dataset` contains a `NaN` value.
+/// - The sample variance is zero.
+/// - `alpha` not in open interval `(0, 1)`.
+/// - `max_outliers` not in interval `[1, dataset.len() - 2]`.
+pub fn generalized_esd_outliers(
+    dataset: &[f64],
+    alpha: f64,
+    max_outliers: usize,
+) -> StatsResult<Outliers> {
+    let steps = grubbs_steps(dataset, AltHyp::Ne, alpha, max_outliers, false)?;
+    let n_flagged = steps.iter().rposition(|s| s.2 > s.3).map_or(0, |i| i + 1);
+    Ok(Outliers::new(dataset, steps, n_flagged))
+}
+
+/// Dixon's Q statistic and the `(index, value)` of the suspicious item.
+fn dixon_q_parts(dataset: &[f64]) -> StatsResult<(f64, (usize, f64))> {
+    let n = dataset.len();
+    if !(3..=10).contains(&n) {
+        return Err(StatsError("sample size must be in interval [3, 10]"));
+    }
+    let v = sorted_with_indices(dataset)?;
+    let range = v[n - 1].1 - v[0].1;
+    if range == 0. {
+        return Err(StatsError("sample range is zero"));
+    }
+    let (gap_lo, gap_hi) = (v[1].1 - v[0].1, v[n - 1].1 - v[n - 2].1);
+    let value = if gap_lo > gap_hi {
+        (gap_lo / range, v[0])
+    } else {
+        (gap_hi / range, v[n - 1])
+    };
+    Ok(value)
+}
+
+/// Dixon's Q statistic: the gap between the more suspicious extreme item (the one with the larger gap, or the
+/// maximum if the gaps are equal) and its nearest neighbor, divided by the sample range.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len()` not in interval `[3, 10]`.
+/// - `dataset` contains a `NaN` value.
+/// - The sample range is zero.
+pub fn dixon_q(dataset: &[f64]) -> StatsResult<f64> {
+    Ok(dixon_q_parts(dataset)?.0)
+}
+
+/// Critical value of [Dixon's Q statistic](dixon_q) for sample size `n`, for a test of whether either extreme item
+/// is an outlier, from the table of the reference.
+///
+/// Arguments:
+/// - `n`: sample size.
+/// - `alpha`: significance level; only `0.1`, `0.05`, and `0.01` are tabulated.
+///
+/// Reference: Rorabacher, D. B. (1991). Statistical treatment for rejection of deviant values: critical values of
+/// Dixon's "Q" parameter and related subrange ratios at the 95% confidence level.
+/// *Analytical Chemistry*, 63(2), 139–146.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `n` not in interval `[3, 10]`.
+/// - `alpha` is not `0.1`, `0.05`, or `0.01`.
+pub fn dixon_q_crit(n: usize, alpha: f64) -> StatsResult<f64> {
+    if !(3..=10).contains(&n) {
+        return Err(StatsError("sample size must be in interval [3, 10]"));
+    }
+    let row = match alpha {
+        0.1 => 0,
+        0.05 => 1,
+        0.01 => 2,
+        _ => return Err(StatsError("arg `alpha` must be `0.1`, `0.05`, or `0.01`")),
+    };
+    Ok(DIXON_Q_CRIT[row][n - 3])
+}
+
+/// Dixon's Q test for a single outlier in a small sample: flags the more suspicious extreme item if
+/// [`dixon_q`] exceeds [`dixon_q_crit`].
+///
+/// The report has a single step, with the Q statistic and its critical value.
+///
+/// Arguments:
+/// - `dataset`: sample data.
+/// - `alpha`: significance level; only `0.1`, `0.05`, and `0.01` are tabulated.
+///
+/// Reference: Dean, R. B. & Dixon, W. J. (1951). Simplified statistics for small numbers of observations.
+/// *Analytical Chemistry*, 23(4), 636–638.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `dataset.len()` not in interval `[3, 10]`.
+/// - `dataset` contains a `NaN` value.
+/// - The sample range is zero.
+/// - `alpha` is not `0.1`, `0.05`, or `0.01`.
+pub fn dixon_outliers(dataset: &[f64], alpha: f64) -> StatsResult<Outliers> {
+    let q_crit = dixon_q_crit(dataset.len(), alpha)?;
+    let (q, (i, x)) = dixon_q_parts(dataset)?;
+    let n_flagged = if q > q_crit { 1 } else { 0 };
+    Ok(Outliers::new(dataset, vec![(i, x, q, q_crit)], n_flagged))
+}
+
+#[cfg(test)]
+mod test {
+    //! Expected values for the generalized ESD test agree with the example in the NIST/SEMATECH e-Handbook of
+    //! Statistical Methods (section 1.3.5.17.3), to the 3 decimals published there. Other expected values come from
+    //! a Python implementation of the definitions (with `mpmath` for Student's t distribution), and the Dixon's Q
+    //! critical values were checked by simulation.
+
+    use super::*;
+    use crate::{core::Hyp, dev_utils::ApproxEq};
+
+    const EPSILON: f64 = 1e-12;
+    const ALPHA: f64 = 0.05;
+
+    const ESD_DATA: [f64; 54] = [
+        -0.25, 0.68, 0.94, 1.15, 1.20, 1.26, 1.26, 1.34, 1.38, 1.43, 1.49, 1.49, 1.55, 1.56, 1.58,
+        1.65, 1.69, 1.70, 1.76, 1.77, 1.81, 1.91, 1.94, 1.96, 1.99, 2.06, 2.09, 2.10, 2.14, 2.15,
+        2.23, 2.24, 2.26, 2.35, 2.37, 2.40, 2.47, 2.54, 2.62, 2.64, 2.90, 2.92, 2.92, 2.93, 3.21,
+        3.26, 3.30, 3.59, 3.68, 4.30, 4.64, 5.34, 5.42, 6.01,
+    ];
+
+    fn check(exp: f64, actual: f64, eps: f64) {
+        assert!(exp.approx_eq(actual, eps), "exp={exp}, actual={actual}");
+    }
+
+    fn check_all(exp: &[f64], actual: &[f64], eps: f64) {
+        assert_eq!(exp.len(), actual.len());
+        for (&e, &a) in exp.iter().zip(actual) {
+            check(e, a, eps);
+        }
+    }
+
+    #[test]
+    fn test_tukey() {
+        let (lo, hi) = tukey_fences(&ESD_DATA, TUKEY_K_OUTSIDE).unwrap();
+        check(-0.3400000000000001, lo, EPSILON);
+        check(4.74, hi, EPSILON);
+
+        let out = tukey_outliers(&ESD_DATA, TUKEY_K_OUTSIDE).unwrap();
+        assert_eq!(&[51, 52, 53], out.indices());
+        assert_eq!(&[5.34, 5.42, 6.01], out.values());
+        check_all(
+            &[1.9724409448818896, 2.0354330708661417, 2.5],
+            out.stats(),
+            EPSILON,
+        );
+        assert_eq!(&[1.5; 3], out.crit_values());
+        assert_eq!(&ESD_DATA[..51], out.inliers());
+
+        assert!(
+            tukey_outliers(&ESD_DATA, TUKEY_K_FAR_OUT)
+                .unwrap()
+                .is_empty()
+        );
+
+        let out = tukey_outliers(&[5., -20., 1., 2., 3., 4.], TUKEY_K_OUTSIDE).unwrap();
+        assert_eq!(&[1], out.indices());
+        assert!(out.stats()[0] < 0.);
+    }
+
+    #[test]
+    fn test_modified_z() {
+        let z = modified_z_scores(&ESD_DATA).unwrap();
+        check(-2.9021623196510298, z[0], EPSILON);
+        check(4.845187838564512, z[53], EPSILON);
+
+        let out = modified_z_outliers(&ESD_DATA, MODIFIED_Z_THRESHOLD).unwrap();
+        assert_eq!(&[51, 52, 53], out.indices());
+        check_all(
+            &[4.015998604378504, 4.115006274132058, 4.845187838564512],
+            out.stats(),
+            EPSILON,
+        );
+        assert_eq!(51, out.inliers().len());
+
+        assert_eq!(
+            &[0, 49, 50, 51, 52, 53],
+            modified_z_outliers(&ESD_DATA, 2.5).unwrap().indices()
+        );
+    }
+
+    #[test]
+    fn test_grubbs() {
+        let dat = [2.1, 2.3, 2.2, 2.4, 9.0];
+        check(
+            1.7876287193811147,
+            grubbs_g(&dat, AltHyp::Ne).unwrap(),
+            EPSILON,
+        );
+        check(
+            0.0001076349094093292,
+            grubbs_p(&dat, AltHyp::Ne).unwrap(),
+            1e-9,
+        );
+        check(
+            5.381745470466458e-5,
+            grubbs_p(&dat, AltHyp::Gt).unwrap(),
+            1e-9,
+        );
+        check(1., grubbs_p(&dat, AltHyp::Lt).unwrap(), EPSILON);
+        check(
+            1.7150373123433635,
+            grubbs_g_crit(5, AltHyp::Ne, ALPHA).unwrap(),
+            1e-9,
+        );
+        check(
+            1.6713856694849,
+            grubbs_g_crit(5, AltHyp::Gt, ALPHA).unwrap(),
+            1e-9,
+        );
+        assert_eq!(
+            Hyp::Alt(AltHyp::Ne),
+            grubbs_test(&dat, AltHyp::Ne, ALPHA).unwrap().accepted()
+        );
+
+        check(
+            3.118906048982441,
+            grubbs_g(&ESD_DATA, AltHyp::Gt).unwrap(),
+            EPSILON,
+        );
+        check(
+            0.02949236355796966,
+            grubbs_p(&ESD_DATA, AltHyp::Gt).unwrap(),
+            1e-9,
+        );
+        check(
+            0.05898472711593932,
+            grubbs_p(&ESD_DATA, AltHyp::Ne).unwrap(),
+            1e-9,
+        );
+        check(
+            2.1733085921079343,
+            grubbs_g(&ESD_DATA, AltHyp::Lt).unwrap(),
+            EPSILON,
+        );
+
+        // Iterated one-sided test: flags the maximum, then stops.
+        let out = grubbs_outliers(&ESD_DATA, AltHyp::Gt, ALPHA, 10).unwrap();
+        assert_eq!(&[53], out.indices());
+        assert_eq!(&[6.01], out.values());
+        check_all(
+            &[3.118906048982441, 2.9429731136435064],
+            out.stats(),
+            EPSILON,
+        );
+        check_all(
+            &[2.9868080398667765, 2.979607732147852],
+            out.crit_values(),
+            1e-9,
+        );
+        assert_eq!(&ESD_DATA[..53], out.inliers());
+
+        // Iterated two-sided test: masking hides all outliers.
+        let out = grubbs_outliers(&ESD_DATA, AltHyp::Ne, ALPHA, 10).unwrap();
+        assert!(out.is_empty());
+        assert_eq!(1, out.stats().len());
+
+        // Stops when the remaining sample variance is zero.
+        let out = grubbs_outliers(&[1., 1., 1., 1., 100.], AltHyp::Gt, ALPHA, 3).unwrap();
+        assert_eq!(&[4], out.indices());
+        assert_eq!(1, out.stats().len());
+    }
+
+    #[test]
+    fn test_generalized_esd() {
+        let out = generalized_esd_outliers(&ESD_DATA, ALPHA, 10).unwrap();
+        assert_eq!(&[53, 52, 51], out.indices());
+        assert_eq!(&[6.01, 5.42, 5.34], out.values());
+        check_all(
+            &[
+                3.118906048982441,
+                2.9429731136435064,
+                3.1794239367178356,
+                2.81018114442759,
+                2.8155795634442784,
+                2.8481716279303417,
+                2.2793270549903424,
+                2.3103660590542994,
+                2.101580651024145,
+                2.0671780780253637,
+            ],
+            out.stats(),
+            EPSILON,
+        );
+        check_all(
+            &[
+                3.1587939408874943,
+                3.1514300233160126,
+                3.1438896850319953,
+                3.1361649560577933,
+                3.1282473343309976,
+                3.1201277383148156,
+                3.1117964542899896,
+                3.1032430776022797,
+                3.0944564470233904,
+                3.0854245712431023,
+            ],
+            out.crit_values(),
+            1e-9,
+        );
+        assert_eq!(&ESD_DATA[..51], out.inliers());
+
+        let out = generalized_esd_outliers(&ESD_DATA, ALPHA, 2).unwrap();
+        assert!(out.is_empty());
+        assert_eq!(ESD_DATA.len(), out.inliers().len());
+    }
+
+    #[test]
+    fn test_dixon() {
+        let dat = [
+            0.189, 0.167, 0.187, 0.183, 0.186, 0.182, 0.181, 0.184, 0.181, 0.177,
+        ];
+        check(0.4545454545454546, dixon_q(&dat).unwrap(), 1e-9);
+        assert_eq!(0.412, dixon_q_crit(10, 0.1).unwrap());
+        assert_eq!(0.970, dixon_q_crit(3, ALPHA).unwrap());
+
+        let out = dixon_outliers(&dat, 0.1).unwrap();
+        assert_eq!(&[1], out.indices());
+        assert_eq!(&[0.167], out.values());
+        assert_eq!(&[0.412], out.crit_values());
+        assert_eq!(9, out.inliers().len());
+
+        let out = dixon_outliers(&dat, ALPHA).unwrap();
+        assert!(out.is_empty());
+        assert_eq!(&[0.466], out.crit_values());
+        assert_eq!(&dat, out.inliers());
+
+        // Equal gaps: the maximum is the suspicious item.
+        let out = dixon_outliers(&[0., 1., 2.], 0.1).unwrap();
+        assert!(out.is_empty());
+        check(0.5, out.stats()[0], EPSILON);
+    }
+}
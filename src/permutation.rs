@@ -0,0 +1,382 @@
+//! Two-sample permutation tests for any statistic, for when neither the normality assumption of
+//! [`welch_test`](crate::normal::welch_test) nor the location-shift model of
+//! [`RankSum`](crate::wilcoxon::RankSum) fits the data.
+//!
+//! The null hypothesis is that the two samples come from the same distribution, so that the assignment of the
+//! pooled values to the samples is exchangeable. The statistic is supplied as a closure over the two samples;
+//! [`mean_diff`], the difference of means, is the usual choice.
+//!
+//! If the number of distinct reassignments of the pooled values does not exceed the requested number of
+//! resamples, all of them are enumerated and the p-value is exact. Otherwise, the p-value is estimated from
+//! random reassignments drawn with the seedable [`SeededRng`], so results are reproducible given a seed, and
+//! [`PermutationTestResult::p_se`] reports the Monte Carlo standard error of the estimate.
+//!
+//! For alternative hypothesis [`AltHyp::Gt`], the statistic tends to be larger than under the null hypothesis,
+//! and vice versa for [`AltHyp::Lt`]. For [`AltHyp::Ne`], the p-value is twice the smaller one-sided p-value,
+//! capped at `1`, which does not require the statistic's null distribution to be symmetric.
+//!
+//! This module is included by default. However, if `default-features = false` is specified in the dependency
+//! declaration for this library, then inclusion of this module is gated by feature "**permutation**".
+//!
+//! # Example
+//!
+//! ```
+#![doc = include_str!("../examples/permutation.rs")]
+//! ```
+
+use crate::core::{
+    AltHyp, HypTestResult, SeededRng, StatsError, StatsResult, check_alpha_in_open_0_1,
+    n_choose_k_up_to, next_combination,
+};
+
+/// Relative tolerance used to treat permuted statistics as equal to the observed one, so that ties are not
+/// broken by floating point rounding (as in `scipy`'s `permutation_test`).
+const TIE_REL_TOL: f64 = 100. * f64::EPSILON;
+
+/// Difference of the means of `x` and `y`, the usual statistic for permutation tests of location.
+///
+/// Returns NaN if a sample is empty.
+pub fn mean_diff(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().sum::<f64>() / x.len() as f64 - y.iter().sum::<f64>() / y.len() as f64
+}
+
+/// Result of a permutation test.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PermutationTestResult {
+    test_result: HypTestResult,
+    p_se: f64,
+    exact: bool,
+}
+
+impl PermutationTestResult {
+    /// Hypothesis test result.
+    pub fn test_result(&self) -> HypTestResult {
+        self.test_result
+    }
+
+    /// Monte Carlo standard error of the p-value; `0` if the p-value is exact.
+    pub fn p_se(&self) -> f64 {
+        self.p_se
+    }
+
+    /// Whether the p-value was computed by exact enumeration of all reassignments.
+    pub fn is_exact(&self) -> bool {
+        self.exact
+    }
+}
+
+/// Counts of permuted statistics that are `<=` and `>=` the observed one, with the number of permutations.
+struct PermCounts {
+    n_le: u64,
+    n_ge: u64,
+    n_perms: u64,
+}
+
+impl PermCounts {
+    fn new() -> Self {
+        Self {
+            n_le: 0,
+            n_ge: 0,
+            n_perms: 0,
+        }
+    }
+
+    fn collect(&mut self, value: f64, observed: f64, tol: f64) {
+        if value <= observed + tol {
+            self.n_le += 1;
+        }
+        if value >= observed - tol {
+            self.n_ge += 1;
+        }
+        self.n_perms += 1;
+    }
+}
+
+/// Enumerates all `C(n_x + n_y, n_x)` assignments of the pooled values to the samples.
+fn exact_counts(
+    pooled: &[f64],
+    n_x: usize,
+    stat: &impl Fn(&[f64], &[f64]) -> f64,
+    observed: f64,
+    tol: f64,
+) -> PermCounts {
+    let n = pooled.len();
+    let mut counts = PermCounts::new();
+    let mut idx: Vec<usize> = (0..n_x).collect();
+    let mut in_x = vec![false; n];
+    let mut x = Vec::with_capacity(n_x);
+    let mut y = Vec::with_capacity(n - n_x);
+
+    loop {
+        in_x.iter_mut().for_each(|b| *b = false);
+        idx.iter().for_each(|&i| in_x[i] = true);
+        x.clear();
+        y.clear();
+        for (v, &b) in pooled.iter().zip(in_x.iter()) {
+            if b { x.push(*v) } else { y.push(*v) }
+        }
+        counts.collect(stat(&x, &y), observed, tol);
+
+        if !next_combination(&mut idx, n) {
+            break;
+        }
+    }
+    counts
+}
+
+/// Counts over `n_resamples` random reassignments of the pooled values to the samples.
+fn monte_carlo_counts(
+    pooled: &[f64],
+    n_x: usize,
+    stat: &impl Fn(&[f64], &[f64]) -> f64,
+    observed: f64,
+    tol: f64,
+    n_resamples: u64,
+    seed: u64,
+) -> PermCounts {
+    let mut rng = SeededRng::new(seed);
+    let mut counts = PermCounts::new();
+    let mut buf = pooled.to_vec();
+    for _ in 0..n_resamples {
+        rng.shuffle(&mut buf);
+        let (x, y) = buf.split_at(n_x);
+        counts.collect(stat(x, y), observed, tol);
+    }
+    counts
+}
+
+/// Returns the p-value, its Monte Carlo standard error, and whether it is exact.
+fn permutation_p_se(
+    x: &[f64],
+    y: &[f64],
+    stat: impl Fn(&[f64], &[f64]) -> f64,
+    alt_hyp: AltHyp,
+    n_resamples: u64,
+    seed: u64,
+) -> StatsResult<(f64, f64, bool)> {
+    if x.is_empty() || y.is_empty() {
+        return Err(StatsError("sample size must be positive"));
+    }
+    if n_resamples == 0 {
+        return Err(StatsError("arg `n_resamples` must be positive"));
+    }
+    let observed = stat(x, y);
+    if observed.is_nan() {
+        return Err(StatsError("statistic must not be NaN for the samples"));
+    }
+    let tol = if observed.is_finite() {
+        TIE_REL_TOL * observed.abs()
+    } else {
+        0.
+    };
+
+    let pooled: Vec<f64> = x.iter().chain(y.iter()).cloned().collect();
+    let n_x = x.len();
+    let n_perms_exact = n_choose_k_up_to(pooled.len() as u64, n_x as u64, n_resamples);
+    let exact = n_perms_exact.is_some();
+
+    let counts = if exact {
+        exact_counts(&pooled, n_x, &stat, observed, tol)
+    } else {
+        monte_carlo_counts(&pooled, n_x, &stat, observed, tol, n_resamples, seed)
+    };
+
+    // Exact p-values include the observed assignment among the enumerated ones; Monte Carlo estimates add it.
+    let (offset, denom) = if exact {
+        (0., counts.n_perms as f64)
+    } else {
+        (1., counts.n_perms as f64 + 1.)
+    };
+    let p_lt = (counts.n_le as f64 + offset) / denom;
+    let p_gt = (counts.n_ge as f64 + offset) / denom;
+    let p_side = match alt_hyp {
+        AltHyp::Lt => p_lt,
+        AltHyp::Gt => p_gt,
+        AltHyp::Ne => p_lt.min(p_gt),
+    };
+    let (p, side_factor) = match alt_hyp {
+        AltHyp::Ne => ((2. * p_side).min(1.), 2.),
+        _ => (p_side, 1.),
+    };
+
+    let p_se = if exact {
+        0.
+    } else {
+        side_factor * (p_side * (1. - p_side) / counts.n_perms as f64).sqrt()
+    };
+
+    Ok((p, p_se, exact))
+}
+
+/// Permutation test of the null hypothesis that `x` and `y` come from the same distribution, with the
+/// p-value's Monte Carlo standard error.
+///
+/// The p-value is exact if `C(n_x + n_y, n_x) <= n_resamples`. Otherwise, it is estimated as
+/// `(k + 1) / (n_resamples + 1)`, where `k` is the number of random reassignments whose statistic is at least
+/// as extreme as the observed one, which keeps the test valid at level `alpha`.
+///
+/// Arguments:
+/// - `x`: first sample.
+/// - `y`: second sample.
+/// - `stat`: statistic, computed from a slice of data from each sample, e.g., [`mean_diff`].
+/// - `alt_hyp`: alternative hypothesis.
+/// - `n_resamples`: maximum number of reassignments; the number of random reassignments if the p-value is
+///   not exact.
+/// - `seed`: seed of the pseudo-random number generator.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `alpha` not in open interval `(0, 1)`.
+/// - `x` or `y` is empty.
+/// - `n_resamples == 0`.
+/// - `stat` returns NaN for the samples.
+pub fn permutation_test(
+    x: &[f64],
+    y: &[f64],
+    stat: impl Fn(&[f64], &[f64]) -> f64,
+    alt_hyp: AltHyp,
+    n_resamples: u64,
+    seed: u64,
+    alpha: f64,
+) -> StatsResult<PermutationTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let (p, p_se, exact) = permutation_p_se(x, y, stat, alt_hyp, n_resamples, seed)?;
+    Ok(PermutationTestResult {
+        test_result: HypTestResult::new(p, alpha, alt_hyp),
+        p_se,
+        exact,
+    })
+}
+
+/// Permutation test p-value of the null hypothesis that `x` and `y` come from the same distribution.
+///
+/// See [`permutation_test`] for details.
+///
+/// Arguments:
+/// - `x`: first sample.
+/// - `y`: second sample.
+/// - `stat`: statistic, computed from a slice of data from each sample, e.g., [`mean_diff`].
+/// - `alt_hyp`: alternative hypothesis.
+/// - `n_resamples`: maximum number of reassignments; the number of random reassignments if the p-value is
+///   not exact.
+/// - `seed`: seed of the pseudo-random number generator.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `x` or `y` is empty.
+/// - `n_resamples == 0`.
+/// - `stat` returns NaN for the samples.
+pub fn permutation_p(
+    x: &[f64],
+    y: &[f64],
+    stat: impl Fn(&[f64], &[f64]) -> f64,
+    alt_hyp: AltHyp,
+    n_resamples: u64,
+    seed: u64,
+) -> StatsResult<f64> {
+    let (p, _, _) = permutation_p_se(x, y, stat, alt_hyp, n_resamples, seed)?;
+    Ok(p)
+}
+
+#[cfg(test)]
+mod test {
+    //! Exact p-values are checked by brute-force enumeration in Python with `itertools.combinations`.
+    //! Monte Carlo p-values are checked against the exact ones, within a few standard errors.
+
+    use super::*;
+    use crate::core::Hyp;
+    use crate::dev_utils::ApproxEq;
+
+    const EPSILON: f64 = 1e-12;
+    const ALPHA: f64 = 0.05;
+    const SEED: u64 = 2024;
+
+    const X: [f64; 7] = [19.8, 21.3, 20.4, 23.9, 22.1, 20.9, 24.6];
+    const Y: [f64; 6] = [18.2, 19.9, 20.1, 17.6, 21.0, 18.8];
+
+    fn median(s: &[f64]) -> f64 {
+        let mut v = s.to_vec();
+        v.sort_by(f64::total_cmp);
+        let n = v.len();
+        if n % 2 == 1 {
+            v[n / 2]
+        } else {
+            (v[n / 2 - 1] + v[n / 2]) / 2.
+        }
+    }
+
+    #[test]
+    fn test_exact() {
+        let cases = [
+            (AltHyp::Gt, 0.00641025641025641, Hyp::Alt(AltHyp::Gt)),
+            (AltHyp::Lt, 0.9947552447552448, Hyp::Null),
+            (AltHyp::Ne, 0.01282051282051282, Hyp::Alt(AltHyp::Ne)),
+        ];
+        for (alt_hyp, exp_p, exp_accepted) in cases {
+            let res = permutation_test(&X, &Y, mean_diff, alt_hyp, 10000, SEED, ALPHA).unwrap();
+            assert!(res.is_exact());
+            assert_eq!(0., res.p_se());
+            let p = res.test_result().p();
+            assert!(
+                exp_p.approx_eq(p, EPSILON),
+                "alt_hyp={alt_hyp:?}, exp_p={exp_p}, p={p}"
+            );
+            assert_eq!(exp_accepted, res.test_result().accepted());
+            assert_eq!(
+                p,
+                permutation_p(&X, &Y, mean_diff, alt_hyp, 10000, SEED).unwrap()
+            );
+        }
+
+        let exp_p = 0.018648018648018648;
+        let p = permutation_p(
+            &X,
+            &Y,
+            |x, y| median(x) - median(y),
+            AltHyp::Ne,
+            10000,
+            SEED,
+        )
+        .unwrap();
+        assert!(exp_p.approx_eq(p, EPSILON), "exp_p={exp_p}, p={p}");
+    }
+
+    #[test]
+    fn test_ties() {
+        // All reassignments of identical values give the observed statistic.
+        let res = permutation_test(
+            &[1., 1.],
+            &[1., 1., 1.],
+            mean_diff,
+            AltHyp::Ne,
+            100,
+            SEED,
+            ALPHA,
+        )
+        .unwrap();
+        assert_eq!(1., res.test_result().p());
+    }
+
+    #[test]
+    fn test_monte_carlo() {
+        for alt_hyp in [AltHyp::Gt, AltHyp::Lt, AltHyp::Ne] {
+            let exact = permutation_p(&X, &Y, mean_diff, alt_hyp, 10000, SEED).unwrap();
+            let res = permutation_test(&X, &Y, mean_diff, alt_hyp, 1000, SEED, ALPHA).unwrap();
+            assert!(!res.is_exact());
+            let p = res.test_result().p();
+            let se = res.p_se();
+            assert!(se > 0.);
+            assert!(
+                (p - exact).abs() < 4. * se + 1e-3,
+                "alt_hyp={alt_hyp:?}, exact={exact}, p={p}, se={se}"
+            );
+
+            let res_again =
+                permutation_test(&X, &Y, mean_diff, alt_hyp, 1000, SEED, ALPHA).unwrap();
+            assert_eq!(res, res_again);
+        }
+    }
+}
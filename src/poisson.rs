@@ -0,0 +1,860 @@
+//! Statistics related to samples of the Poisson distribution, for counts of events observed over a known exposure
+//! (e.g., errors per hour of operation or crashes per million requests). The number of events observed over
+//! exposure `t` has a Poisson distribution with mean `rate * t`.
+//!
+//! - For the estimate and confidence intervals of a single rate, use `poisson_rate_hat` and `poisson_*_ci`
+//!   functions.
+//! - For the exact test of a rate against a reference rate, use `exact_poisson_*` functions.
+//! - For the exact comparison of two rates, use `poisson_rate_ratio_*` functions.
+//!
+//! This module is included by default. However, if `default-features = false` is specified in the dependency
+//! declaration for this library, then inclusion of this module is gated by feature "**poisson**".
+//!
+//! # Example
+//!
+//! ```
+#![doc = include_str!("../examples/poisson.rs")]
+//! ```
+
+use crate::{
+    binomial::{binomial_cp_alt_hyp_ci, exact_binomial_p},
+    core::{
+        AltHyp, AsStatsResult, Ci, HypTestResult, StatsError, StatsResult, check_alpha_in_open_0_1,
+        find_root, first_true, tail_bounds_ci,
+    },
+    normal::z_alpha,
+};
+use statrs::{
+    distribution::{Discrete, DiscreteCDF, Poisson},
+    function::gamma::gamma_lr,
+};
+use std::cmp::Ordering;
+
+fn check_exposure(exposure: f64) -> StatsResult<()> {
+    if exposure.is_finite() && exposure > 0. {
+        return Ok(());
+    }
+    Err(StatsError("arg `exposure` must be finite and positive"))
+}
+
+/// Quantile function of the Gamma distribution with the given `shape` and rate `1`.
+fn gamma_quantile(shape: f64, p: f64) -> f64 {
+    let f = |x: f64| if x > 0. { gamma_lr(shape, x) } else { 0. } - p;
+    let mut hi = shape + 10. * shape.sqrt() + 10.;
+    while f(hi) < 0. {
+        hi *= 2.;
+    }
+    find_root(f, 0., hi, 1e-12 * hi).expect("`f` should have opposite signs at the interval ends")
+}
+
+/// Estimator of the rate of events, `count / exposure`.
+///
+/// Arguments:
+/// - `count`: number of events observed.
+/// - `exposure`: exposure (e.g., time or number of trials) over which the events were observed.
+///
+/// # Errors
+///
+/// Returns an error if `exposure` is not finite and positive.
+pub fn poisson_rate_hat(count: u64, exposure: f64) -> StatsResult<f64> {
+    check_exposure(exposure)?;
+    Ok(count as f64 / exposure)
+}
+
+/// Exact (Garwood) confidence interval for the rate of events, based on the relationship between the Poisson and
+/// Gamma distributions. Equivalent to the interval computed by `R`'s `poisson.test` function for one sample.
+///
+/// The high end of the interval is `f64::INFINITY` for [`AltHyp::Gt`].
+///
+/// Arguments:
+/// - `count`: number of events observed.
+/// - `exposure`: exposure (e.g., time or number of trials) over which the events were observed.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `exposure` is not finite and positive.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn poisson_garwood_alt_hyp_ci(
+    count: u64,
+    exposure: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    check_exposure(exposure)?;
+    let k = count as f64;
+    tail_bounds_ci(
+        alt_hyp,
+        alpha,
+        f64::INFINITY,
+        |a| {
+            if count == 0 {
+                Ok(0.)
+            } else {
+                Ok(gamma_quantile(k, a) / exposure)
+            }
+        },
+        |a| Ok(gamma_quantile(k + 1., 1. - a) / exposure),
+    )
+}
+
+/// Exact (Garwood) confidence interval for the rate of events, with the alternative hypothesis of inequality
+/// (two-sided).
+///
+/// See [`poisson_garwood_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `count`: number of events observed.
+/// - `exposure`: exposure (e.g., time or number of trials) over which the events were observed.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `exposure` is not finite and positive.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn poisson_garwood_ci(count: u64, exposure: f64, alpha: f64) -> StatsResult<Ci> {
+    poisson_garwood_alt_hyp_ci(count, exposure, AltHyp::Ne, alpha)
+}
+
+/// Normal approximation (Wald) confidence interval for the rate of events,
+/// `(count ± z * sqrt(count)) / exposure`, with the low end truncated at `0`.
+///
+/// This interval has poor coverage for small counts. In particular, it degenerates to a single point if
+/// `count == 0`. The high end of the interval is `f64::INFINITY` for [`AltHyp::Gt`].
+///
+/// Arguments:
+/// - `count`: number of events observed.
+/// - `exposure`: exposure (e.g., time or number of trials) over which the events were observed.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `exposure` is not finite and positive.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn poisson_wald_alt_hyp_ci(
+    count: u64,
+    exposure: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    check_exposure(exposure)?;
+    let k = count as f64;
+    tail_bounds_ci(
+        alt_hyp,
+        alpha,
+        f64::INFINITY,
+        |a| Ok((k - z_alpha(a)? * k.sqrt()).max(0.) / exposure),
+        |a| Ok((k + z_alpha(a)? * k.sqrt()) / exposure),
+    )
+}
+
+/// Normal approximation (Wald) confidence interval for the rate of events, with the alternative hypothesis of
+/// inequality (two-sided).
+///
+/// See [`poisson_wald_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `count`: number of events observed.
+/// - `exposure`: exposure (e.g., time or number of trials) over which the events were observed.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `exposure` is not finite and positive.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn poisson_wald_ci(count: u64, exposure: f64, alpha: f64) -> StatsResult<Ci> {
+    poisson_wald_alt_hyp_ci(count, exposure, AltHyp::Ne, alpha)
+}
+
+/// Score confidence interval for the rate of events. It consists of the rates for which the Normal approximation
+/// score test, with z-value `(count - rate * exposure) / sqrt(rate * exposure)`, is not rejected.
+///
+/// The high end of the interval is `f64::INFINITY` for [`AltHyp::Gt`].
+///
+/// Arguments:
+/// - `count`: number of events observed.
+/// - `exposure`: exposure (e.g., time or number of trials) over which the events were observed.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `exposure` is not finite and positive.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn poisson_score_alt_hyp_ci(
+    count: u64,
+    exposure: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    check_exposure(exposure)?;
+    let k = count as f64;
+    let bound = |a: f64, sign: f64| -> StatsResult<f64> {
+        let z = z_alpha(a)?;
+        let mid = k + z.powi(2) / 2.;
+        let delta = z * (k + z.powi(2) / 4.).sqrt();
+        Ok((mid + sign * delta).max(0.) / exposure)
+    };
+    tail_bounds_ci(
+        alt_hyp,
+        alpha,
+        f64::INFINITY,
+        |a| bound(a, -1.),
+        |a| bound(a, 1.),
+    )
+}
+
+/// Score confidence interval for the rate of events, with the alternative hypothesis of inequality (two-sided).
+///
+/// See [`poisson_score_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `count`: number of events observed.
+/// - `exposure`: exposure (e.g., time or number of trials) over which the events were observed.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `exposure` is not finite and positive.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn poisson_score_ci(count: u64, exposure: f64, alpha: f64) -> StatsResult<Ci> {
+    poisson_score_alt_hyp_ci(count, exposure, AltHyp::Ne, alpha)
+}
+
+/// Exact p-value for the rate of events under the hypothesis that the rate is `rate0`.
+/// Equivalent to the p-value computed by `R`'s `poisson.test` function for one sample.
+///
+/// The two-sided p-value is the sum of the probabilities of all counts with probability not greater than that
+/// of `count`.
+///
+/// Arguments:
+/// - `count`: number of events observed.
+/// - `exposure`: exposure (e.g., time or number of trials) over which the events were observed.
+/// - `rate0`: rate of events under the null hypothesis.
+/// - `alt_hyp`: alternative hypothesis.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `exposure` is not finite and positive.
+/// - `rate0` is not finite and positive.
+pub fn exact_poisson_p(count: u64, exposure: f64, rate0: f64, alt_hyp: AltHyp) -> StatsResult<f64> {
+    check_exposure(exposure)?;
+    if !(rate0.is_finite() && rate0 > 0.) {
+        return Err(StatsError("arg `rate0` must be finite and positive"));
+    }
+    let mean = rate0 * exposure;
+    let poisson = Poisson::new(mean)
+        .stats_result("product of args `rate0` and `exposure` must be positive")?;
+
+    let prob_le = || poisson.cdf(count);
+    let prob_ge = || {
+        if count == 0 {
+            1.
+        } else {
+            poisson.sf(count - 1)
+        }
+    };
+
+    // Sum the probabilities of all values with probability lower or equal to `count`'s.
+    // Based on `R`'s poisson.test code for PVAL.
+    let prob_ne = || {
+        let rel_err = 1. + 1e-7;
+        let threshold = poisson.pmf(count) * rel_err;
+
+        match (count as f64).total_cmp(&mean) {
+            Ordering::Equal => 1.,
+
+            Ordering::Less => {
+                // Probabilities decrease above the mode, so find the first value above the mean with probability
+                // not greater than `count`'s.
+                let lo = mean.ceil() as u64;
+                let mut hi = lo.max(1) * 2;
+                while poisson.pmf(hi) > threshold {
+                    hi *= 2;
+                }
+                let k = first_true(lo, hi, |k| poisson.pmf(k) <= threshold);
+                prob_le() + if k == 0 { 1. } else { poisson.sf(k - 1) }
+            }
+
+            Ordering::Greater => {
+                // Probabilities increase below the mode, so find the first value up to the mean with probability
+                // greater than `count`'s.
+                let hi = mean.floor() as u64;
+                let k = first_true(0, hi, |k| poisson.pmf(k) > threshold);
+                let other_tail = if k == 0 { 0. } else { poisson.cdf(k - 1) };
+                other_tail + prob_ge()
+            }
+        }
+        .min(1.)
+    };
+
+    let p_value = match alt_hyp {
+        AltHyp::Lt => prob_le(),
+        AltHyp::Gt => prob_ge(),
+        AltHyp::Ne => prob_ne(),
+    };
+
+    Ok(p_value)
+}
+
+/// Exact test for the rate of events against the reference rate `rate0`.
+/// Equivalent to `R`'s `poisson.test` function for one sample.
+///
+/// Arguments:
+/// - `count`: number of events observed.
+/// - `exposure`: exposure (e.g., time or number of trials) over which the events were observed.
+/// - `rate0`: rate of events under the null hypothesis.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `exposure` is not finite and positive.
+/// - `rate0` is not finite and positive.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn exact_poisson_test(
+    count: u64,
+    exposure: f64,
+    rate0: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p_value = exact_poisson_p(count, exposure, rate0, alt_hyp)?;
+    Ok(HypTestResult::new(p_value, alpha, alt_hyp))
+}
+
+fn check_two_samples(
+    count_x: u64,
+    exposure_x: f64,
+    count_y: u64,
+    exposure_y: f64,
+) -> StatsResult<()> {
+    if !(exposure_x.is_finite() && exposure_x > 0. && exposure_y.is_finite() && exposure_y > 0.) {
+        return Err(StatsError(
+            "args `exposure_x` and `exposure_y` must be finite and positive",
+        ));
+    }
+    if count_x + count_y == 0 {
+        return Err(StatsError(
+            "at least one of args `count_x` and `count_y` must be positive",
+        ));
+    }
+    Ok(())
+}
+
+/// Estimator of the ratio of the rates of events of two samples, `(count_x / exposure_x) / (count_y / exposure_y)`.
+///
+/// Returns `f64::INFINITY` if `count_y == 0`.
+///
+/// Arguments:
+/// - `count_x`: number of events observed in the first sample.
+/// - `exposure_x`: exposure over which the events of the first sample were observed.
+/// - `count_y`: number of events observed in the second sample.
+/// - `exposure_y`: exposure over which the events of the second sample were observed.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `exposure_x` or `exposure_y` is not finite and positive.
+/// - `count_x + count_y == 0`.
+pub fn poisson_rate_ratio_hat(
+    count_x: u64,
+    exposure_x: f64,
+    count_y: u64,
+    exposure_y: f64,
+) -> StatsResult<f64> {
+    check_two_samples(count_x, exposure_x, count_y, exposure_y)?;
+    if count_y == 0 {
+        return Ok(f64::INFINITY);
+    }
+    Ok((count_x as f64 / exposure_x) / (count_y as f64 / exposure_y))
+}
+
+/// Exact conditional p-value for the ratio of the rates of events of two samples under the hypothesis that the
+/// ratio is `ratio0`. Equivalent to the p-value computed by `R`'s `poisson.test` function for two samples.
+///
+/// Conditional on the total number of events, `count_x` has a Binomial distribution with probability of success
+/// `ratio0 * exposure_x / (ratio0 * exposure_x + exposure_y)` under the null hypothesis, so this is
+/// [`exact_binomial_p`] with `n = count_x + count_y` and `n_s = count_x`.
+///
+/// Arguments:
+/// - `count_x`: number of events observed in the first sample.
+/// - `exposure_x`: exposure over which the events of the first sample were observed.
+/// - `count_y`: number of events observed in the second sample.
+/// - `exposure_y`: exposure over which the events of the second sample were observed.
+/// - `ratio0`: ratio of the rates (first to second) under the null hypothesis.
+/// - `alt_hyp`: alternative hypothesis.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `exposure_x` or `exposure_y` is not finite and positive.
+/// - `count_x + count_y == 0`.
+/// - `ratio0` is not finite and positive.
+pub fn poisson_rate_ratio_p(
+    count_x: u64,
+    exposure_x: f64,
+    count_y: u64,
+    exposure_y: f64,
+    ratio0: f64,
+    alt_hyp: AltHyp,
+) -> StatsResult<f64> {
+    check_two_samples(count_x, exposure_x, count_y, exposure_y)?;
+    if !(ratio0.is_finite() && ratio0 > 0.) {
+        return Err(StatsError("arg `ratio0` must be finite and positive"));
+    }
+    let p0 = ratio0 * exposure_x / (ratio0 * exposure_x + exposure_y);
+    exact_binomial_p(count_x + count_y, count_x, p0, alt_hyp)
+}
+
+/// Exact conditional test for the ratio of the rates of events of two samples against the reference ratio `ratio0`.
+/// Equivalent to `R`'s `poisson.test` function for two samples.
+///
+/// See [`poisson_rate_ratio_p`].
+///
+/// Arguments:
+/// - `count_x`: number of events observed in the first sample.
+/// - `exposure_x`: exposure over which the events of the first sample were observed.
+/// - `count_y`: number of events observed in the second sample.
+/// - `exposure_y`: exposure over which the events of the second sample were observed.
+/// - `ratio0`: ratio of the rates (first to second) under the null hypothesis.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `exposure_x` or `exposure_y` is not finite and positive.
+/// - `count_x + count_y == 0`.
+/// - `ratio0` is not finite and positive.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn poisson_rate_ratio_test(
+    count_x: u64,
+    exposure_x: f64,
+    count_y: u64,
+    exposure_y: f64,
+    ratio0: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<HypTestResult> {
+    check_alpha_in_open_0_1(alpha)?;
+    let p_value = poisson_rate_ratio_p(count_x, exposure_x, count_y, exposure_y, ratio0, alt_hyp)?;
+    Ok(HypTestResult::new(p_value, alpha, alt_hyp))
+}
+
+/// Exact conditional confidence interval for the ratio of the rates of events of two samples, obtained by
+/// transforming the [Clopper–Pearson interval](binomial_cp_alt_hyp_ci) for the conditional Binomial
+/// probability of success. Equivalent to the interval computed by `R`'s `poisson.test` function for two samples.
+///
+/// The high end of the interval is `f64::INFINITY` for [`AltHyp::Gt`] or if `count_y == 0`.
+///
+/// Arguments:
+/// - `count_x`: number of events observed in the first sample.
+/// - `exposure_x`: exposure over which the events of the first sample were observed.
+/// - `count_y`: number of events observed in the second sample.
+/// - `exposure_y`: exposure over which the events of the second sample were observed.
+/// - `alt_hyp`: alternative hypothesis.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `exposure_x` or `exposure_y` is not finite and positive.
+/// - `count_x + count_y == 0`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn poisson_rate_ratio_alt_hyp_ci(
+    count_x: u64,
+    exposure_x: f64,
+    count_y: u64,
+    exposure_y: f64,
+    alt_hyp: AltHyp,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    check_two_samples(count_x, exposure_x, count_y, exposure_y)?;
+    let Ci(lo, hi) = binomial_cp_alt_hyp_ci(count_x + count_y, count_x, alt_hyp, alpha)?;
+    let to_ratio = |p: f64| {
+        if p == 1. {
+            f64::INFINITY
+        } else {
+            p / (1. - p) * exposure_y / exposure_x
+        }
+    };
+    Ok(Ci(to_ratio(lo), to_ratio(hi)))
+}
+
+/// Exact conditional confidence interval for the ratio of the rates of events of two samples, with the alternative
+/// hypothesis of inequality (two-sided).
+///
+/// See [`poisson_rate_ratio_alt_hyp_ci`].
+///
+/// Arguments:
+/// - `count_x`: number of events observed in the first sample.
+/// - `exposure_x`: exposure over which the events of the first sample were observed.
+/// - `count_y`: number of events observed in the second sample.
+/// - `exposure_y`: exposure over which the events of the second sample were observed.
+/// - `alpha`: confidence level = `1 - alpha`.
+///
+/// # Errors
+///
+/// Returns an error in any of these conditions:
+/// - `exposure_x` or `exposure_y` is not finite and positive.
+/// - `count_x + count_y == 0`.
+/// - `alpha` is not in interval `(0, 1)`.
+pub fn poisson_rate_ratio_ci(
+    count_x: u64,
+    exposure_x: f64,
+    count_y: u64,
+    exposure_y: f64,
+    alpha: f64,
+) -> StatsResult<Ci> {
+    poisson_rate_ratio_alt_hyp_ci(count_x, exposure_x, count_y, exposure_y, AltHyp::Ne, alpha)
+}
+
+#[cfg(test)]
+mod test {
+    //! Expected values follow `R`'s poisson.test function (e.g., for 137 events over exposure 24.19893, a rate of
+    //! 5.661407 with confidence interval (4.753125, 6.692709), and for 11 events over exposure 800 versus 21 events
+    //! over exposure 3011, a rate ratio of 1.971488 with p-value 0.07967 and confidence interval
+    //! (0.8584264, 4.2772659)). Other expected values were computed from their definitions with high precision
+    //! (using the `mpmath` Python library).
+
+    use super::*;
+    use crate::{core::Hyp, dev_utils::ApproxEq};
+
+    const ALPHA: f64 = 0.05;
+    const EPSILON: f64 = 0.000005;
+
+    fn rel_eq(exp: f64, act: f64) -> bool {
+        exp == act || exp.approx_eq(act, EPSILON * exp.abs().max(1.))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn check_one_sample(
+        (count, exposure, rate0): (u64, f64, f64),
+        alt_hyp: AltHyp,
+        exp_p: f64,
+        exp_garwood_ci: Ci,
+        exp_wald_ci: Ci,
+        exp_score_ci: Ci,
+        exp_accept_hyp: Hyp,
+    ) {
+        let res = exact_poisson_test(count, exposure, rate0, alt_hyp, ALPHA).unwrap();
+        let p = res.p();
+        let garwood_ci = poisson_garwood_alt_hyp_ci(count, exposure, alt_hyp, ALPHA).unwrap();
+        let wald_ci = poisson_wald_alt_hyp_ci(count, exposure, alt_hyp, ALPHA).unwrap();
+        let score_ci = poisson_score_alt_hyp_ci(count, exposure, alt_hyp, ALPHA).unwrap();
+
+        if alt_hyp == AltHyp::Ne {
+            assert_eq!(
+                garwood_ci,
+                poisson_garwood_ci(count, exposure, ALPHA).unwrap()
+            );
+            assert_eq!(wald_ci, poisson_wald_ci(count, exposure, ALPHA).unwrap());
+            assert_eq!(score_ci, poisson_score_ci(count, exposure, ALPHA).unwrap());
+        }
+
+        let ctx = format!("count={count}, exposure={exposure}, rate0={rate0}, alt_hyp={alt_hyp:?}");
+
+        assert!(exp_p.approx_eq(p, EPSILON), "{ctx} -> exp_p={exp_p}, p={p}");
+        for (name, exp_ci, ci) in [
+            ("garwood", exp_garwood_ci, garwood_ci),
+            ("wald", exp_wald_ci, wald_ci),
+            ("score", exp_score_ci, score_ci),
+        ] {
+            assert!(
+                rel_eq(exp_ci.0, ci.0),
+                "{ctx} -> {name}: exp_ci.0={}, ci.0={}",
+                exp_ci.0,
+                ci.0
+            );
+            assert!(
+                rel_eq(exp_ci.1, ci.1),
+                "{ctx} -> {name}: exp_ci.1={}, ci.1={}",
+                exp_ci.1,
+                ci.1
+            );
+        }
+
+        assert_eq!(alt_hyp, res.alt_hyp(), "{ctx} -> res.alt_hyp");
+        assert_eq!(exp_accept_hyp, res.accepted(), "{ctx} -> res.accepted");
+    }
+
+    #[test]
+    fn test_one_sample_137() {
+        let data = (137, 24.19893, 1.);
+        let rate_hat = poisson_rate_hat(data.0, data.1).unwrap();
+        assert!(
+            5.661407343.approx_eq(rate_hat, EPSILON),
+            "rate_hat={rate_hat}"
+        );
+
+        check_one_sample(
+            data,
+            AltHyp::Ne,
+            0.,
+            Ci(4.753124805, 6.692709334),
+            Ci(4.713398887, 6.6094158),
+            Ci(4.789454432, 6.692105241),
+            Hyp::Alt(AltHyp::Ne),
+        );
+        check_one_sample(
+            data,
+            AltHyp::Lt,
+            1.,
+            Ci(0., 6.524017866),
+            Ci(0., 6.457001119),
+            Ci(0., 6.514864803),
+            Hyp::Null,
+        );
+    }
+
+    #[test]
+    fn test_one_sample_3() {
+        let data = (3, 2., 0.5);
+
+        check_one_sample(
+            data,
+            AltHyp::Ne,
+            0.08030139707,
+            Ci(0.3093360614, 4.383636535),
+            Ci(0., 3.197378601),
+            Ci(0.5101353642, 4.410594046),
+            Hyp::Null,
+        );
+        check_one_sample(
+            data,
+            AltHyp::Gt,
+            0.08030139707,
+            Ci(0.4088457236, f64::INFINITY),
+            Ci(0.07551497355, f64::INFINITY),
+            Ci(0.5994726044, f64::INFINITY),
+            Hyp::Null,
+        );
+        check_one_sample(
+            data,
+            AltHyp::Lt,
+            0.9810118431,
+            Ci(0., 3.876828264),
+            Ci(0., 2.924485026),
+            Ci(0., 3.753299123),
+            Hyp::Null,
+        );
+    }
+
+    #[test]
+    fn test_one_sample_0() {
+        let data = (0, 10., 0.2);
+
+        check_one_sample(
+            data,
+            AltHyp::Ne,
+            0.2782118227,
+            Ci(0., 0.3688879454),
+            Ci(0., 0.),
+            Ci(0., 0.3841458821),
+            Hyp::Null,
+        );
+        check_one_sample(
+            data,
+            AltHyp::Lt,
+            0.1353352832,
+            Ci(0., 0.2995732274),
+            Ci(0., 0.),
+            Ci(0., 0.2705543454),
+            Hyp::Null,
+        );
+        check_one_sample(
+            data,
+            AltHyp::Gt,
+            1.,
+            Ci(0., f64::INFINITY),
+            Ci(0., f64::INFINITY),
+            Ci(0., f64::INFINITY),
+            Hyp::Null,
+        );
+    }
+
+    #[test]
+    fn test_one_sample_10() {
+        let data = (10, 2.5, 2.);
+
+        check_one_sample(
+            data,
+            AltHyp::Ne,
+            0.03856600431,
+            Ci(1.918155478, 7.356142417),
+            Ci(1.520819871, 6.479180129),
+            Ci(2.172794442, 7.363789086),
+            Hyp::Alt(AltHyp::Ne),
+        );
+        check_one_sample(
+            data,
+            AltHyp::Gt,
+            0.03182805731,
+            Ci(2.170162279, f64::INFINITY),
+            Ci(1.919406448, f64::INFINITY),
+            Ci(2.391302155, f64::INFINITY),
+            Hyp::Alt(AltHyp::Gt),
+        );
+    }
+
+    #[test]
+    fn test_one_sample_25() {
+        let data = (25, 100., 0.15);
+
+        check_one_sample(
+            data,
+            AltHyp::Ne,
+            0.01879667991,
+            Ci(0.1617868185, 0.369049317),
+            Ci(0.1520018008, 0.3479981992),
+            Ci(0.1693445523, 0.3690700359),
+            Hyp::Alt(AltHyp::Ne),
+        );
+        check_one_sample(
+            data,
+            AltHyp::Lt,
+            0.9938150962,
+            Ci(0., 0.3491608017),
+            Ci(0., 0.3322426813),
+            Ci(0., 0.3468755293),
+            Hyp::Null,
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn check_rate_ratio(
+        (count_x, exposure_x, count_y, exposure_y, ratio0): (u64, f64, u64, f64, f64),
+        alt_hyp: AltHyp,
+        exp_ratio_hat: f64,
+        exp_p: f64,
+        exp_ci: Ci,
+        exp_accept_hyp: Hyp,
+    ) {
+        let ratio_hat = poisson_rate_ratio_hat(count_x, exposure_x, count_y, exposure_y).unwrap();
+        let res = poisson_rate_ratio_test(
+            count_x, exposure_x, count_y, exposure_y, ratio0, alt_hyp, ALPHA,
+        )
+        .unwrap();
+        let p = res.p();
+        let ci =
+            poisson_rate_ratio_alt_hyp_ci(count_x, exposure_x, count_y, exposure_y, alt_hyp, ALPHA)
+                .unwrap();
+
+        if alt_hyp == AltHyp::Ne {
+            assert_eq!(
+                ci,
+                poisson_rate_ratio_ci(count_x, exposure_x, count_y, exposure_y, ALPHA).unwrap()
+            );
+        }
+
+        let ctx = format!(
+            "count_x={count_x}, exposure_x={exposure_x}, count_y={count_y}, exposure_y={exposure_y}, ratio0={ratio0}, alt_hyp={alt_hyp:?}"
+        );
+
+        assert!(
+            rel_eq(exp_ratio_hat, ratio_hat),
+            "{ctx} -> exp_ratio_hat={exp_ratio_hat}, ratio_hat={ratio_hat}"
+        );
+        assert!(exp_p.approx_eq(p, EPSILON), "{ctx} -> exp_p={exp_p}, p={p}");
+        assert!(
+            rel_eq(exp_ci.0, ci.0),
+            "{ctx} -> exp_ci.0={}, ci.0={}",
+            exp_ci.0,
+            ci.0
+        );
+        assert!(
+            rel_eq(exp_ci.1, ci.1),
+            "{ctx} -> exp_ci.1={}, ci.1={}",
+            exp_ci.1,
+            ci.1
+        );
+        assert_eq!(alt_hyp, res.alt_hyp(), "{ctx} -> res.alt_hyp");
+        assert_eq!(exp_accept_hyp, res.accepted(), "{ctx} -> res.accepted");
+    }
+
+    #[test]
+    fn test_rate_ratio_11_21() {
+        let data = (11, 800., 21, 3011., 1.);
+        let exp_ratio_hat = 1.971488095;
+
+        check_rate_ratio(
+            data,
+            AltHyp::Ne,
+            exp_ratio_hat,
+            0.07966863303,
+            Ci(0.8584264034, 4.277265944),
+            Hyp::Null,
+        );
+        check_rate_ratio(
+            data,
+            AltHyp::Lt,
+            exp_ratio_hat,
+            0.9759318232,
+            Ci(0., 3.827386408),
+            Hyp::Null,
+        );
+        check_rate_ratio(
+            data,
+            AltHyp::Gt,
+            exp_ratio_hat,
+            0.05600865979,
+            Ci(0.9775843475, f64::INFINITY),
+            Hyp::Null,
+        );
+    }
+
+    #[test]
+    fn test_rate_ratio_5_0() {
+        check_rate_ratio(
+            (5, 10., 0, 20., 1.),
+            AltHyp::Ne,
+            f64::INFINITY,
+            0.004115226337,
+            Ci(1.832711715, f64::INFINITY),
+            Hyp::Alt(AltHyp::Ne),
+        );
+    }
+
+    #[test]
+    fn test_rate_ratio_20_30() {
+        let data = (20, 1.5, 30, 3., 2.);
+        let exp_ratio_hat = 1.333333333;
+
+        check_rate_ratio(
+            data,
+            AltHyp::Ne,
+            exp_ratio_hat,
+            0.2026387511,
+            Ci(0.7176807783, 2.426796004),
+            Hyp::Null,
+        );
+        check_rate_ratio(
+            data,
+            AltHyp::Lt,
+            exp_ratio_hat,
+            0.1013193755,
+            Ci(0., 2.220475521),
+            Hyp::Null,
+        );
+        check_rate_ratio(
+            data,
+            AltHyp::Gt,
+            exp_ratio_hat,
+            0.9405397737,
+            Ci(0.7899084194, f64::INFINITY),
+            Hyp::Null,
+        );
+    }
+}
@@ -0,0 +1,612 @@
+//! Sequential tests, which consume observations one at a time and stop as soon as the data are conclusive,
+//! typically requiring far fewer observations on average than fixed-sample tests with the same error probabilities.
+//!
+//! - For Wald's sequential probability ratio test (SPRT) on Bernoulli outcomes, use [`BernoulliSprt`].
+//! - For always-valid p-values and confidence sequences for the difference of the means of two samples that are
+//!   monitored continuously (e.g., after every batch of a benchmark), use [`MixtureSprt`].
+//!
+//! This module is included by default. However, if `default-features = false` is specified in the dependency
+//! declaration for this library, then inclusion of this module is gated by feature "**sequential**".
+//!
+//! # Example
+//!
+//! ```
+#![doc = include_str!("../examples/sequential.rs")]
+//! ```
+
+use crate::core::{
+    AltHyp, Ci, HypTestResult, SampleMoments, StatsError, StatsResult, check_alpha_in_open_0_1,
+    find_root,
+};
+
+/// State of a sequential test after the observations consumed so far.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SprtDecision {
+    /// The data are not yet conclusive; more observations are needed.
+    Continue,
+    /// The test stopped and accepted the null hypothesis.
+    AcceptNull,
+    /// The test stopped and accepted the alternative hypothesis.
+    AcceptAlt,
+}
+
+/// Wald's sequential probability ratio test (SPRT) for the probability of success of Bernoulli outcomes, with
+/// null hypothesis `p == p0` against alternative hypothesis `p == p1`.
+///
+/// The test accumulates the log-likelihood ratio of the outcomes and stops when it crosses
+/// `ln((1 - beta) / alpha)` (accepting the alternative hypothesis) or `ln(beta / (1 - alpha))` (accepting the null
+/// hypothesis), which approximately bounds the probabilities of type I and type II errors by `alpha` and `beta`.
+///
+/// Reference: A. Wald, "Sequential Tests of Statistical Hypotheses", Annals of Mathematical Statistics 16, 1945.
+#[derive(Debug, Clone)]
+pub struct BernoulliSprt {
+    p0: f64,
+    p1: f64,
+    llr_lo: f64,
+    llr_hi: f64,
+    llr_s: f64,
+    llr_f: f64,
+    n: u64,
+    n_s: u64,
+    llr: f64,
+    decision: SprtDecision,
+}
+
+impl BernoulliSprt {
+    /// Creates a test with no observations.
+    ///
+    /// Arguments:
+    /// - `p0`: probability of success under the null hypothesis.
+    /// - `p1`: probability of success under the alternative hypothesis.
+    /// - `alpha`: target probability of accepting the alternative hypothesis when the null hypothesis is true.
+    /// - `beta`: target probability of accepting the null hypothesis when the alternative hypothesis is true.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in any of these conditions:
+    /// - `p0` or `p1` is not in interval `(0, 1)`.
+    /// - `p0 == p1`.
+    /// - `alpha` or `beta` is not in interval `(0, 1)`.
+    /// - `alpha + beta >= 1`.
+    pub fn new(p0: f64, p1: f64, alpha: f64, beta: f64) -> StatsResult<Self> {
+        if !(0. < p0 && p0 < 1. && 0. < p1 && p1 < 1.) {
+            return Err(StatsError("args `p0` and `p1` must be in interval (0, 1)"));
+        }
+        if p0 == p1 {
+            return Err(StatsError("args `p0` and `p1` must be different"));
+        }
+        if !(0. < alpha && alpha < 1. && 0. < beta && beta < 1.) {
+            return Err(StatsError(
+                "args `alpha` and `beta` must be in interval (0, 1)",
+            ));
+        }
+        if alpha + beta >= 1. {
+            return Err(StatsError(
+                "sum of args `alpha` and `beta` must be less than 1",
+            ));
+        }
+
+        Ok(Self {
+            p0,
+            p1,
+            llr_lo: (beta / (1. - alpha)).ln(),
+            llr_hi: ((1. - beta) / alpha).ln(),
+            llr_s: (p1 / p0).ln(),
+            llr_f: ((1. - p1) / (1. - p0)).ln(),
+            n: 0,
+            n_s: 0,
+            llr: 0.,
+            decision: SprtDecision::Continue,
+        })
+    }
+
+    /// Probability of success under the null hypothesis.
+    pub fn p0(&self) -> f64 {
+        self.p0
+    }
+
+    /// Probability of success under the alternative hypothesis.
+    pub fn p1(&self) -> f64 {
+        self.p1
+    }
+
+    /// Number of outcomes consumed by the test.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Number of successes among the outcomes consumed by the test.
+    pub fn n_s(&self) -> u64 {
+        self.n_s
+    }
+
+    /// Log-likelihood ratio (alternative to null) of the outcomes consumed by the test.
+    pub fn llr(&self) -> f64 {
+        self.llr
+    }
+
+    /// Lower and upper stopping boundaries for [`Self::llr`].
+    pub fn llr_bounds(&self) -> (f64, f64) {
+        (self.llr_lo, self.llr_hi)
+    }
+
+    /// Current decision of the test.
+    pub fn decision(&self) -> SprtDecision {
+        self.decision
+    }
+
+    /// Consumes one outcome (`true` for success) and returns the resulting decision.
+    ///
+    /// Once the test has stopped, i.e., the decision is not [`SprtDecision::Continue`], further outcomes are
+    /// ignored and the decision does not change.
+    pub fn push(&mut self, success: bool) -> SprtDecision {
+        if self.decision != SprtDecision::Continue {
+            return self.decision;
+        }
+
+        self.n += 1;
+        if success {
+            self.n_s += 1;
+        }
+        // Recompute from the counts rather than accumulate, to avoid drift over long streams.
+        self.llr = self.n_s as f64 * self.llr_s + (self.n - self.n_s) as f64 * self.llr_f;
+
+        self.decision = if self.llr >= self.llr_hi {
+            SprtDecision::AcceptAlt
+        } else if self.llr <= self.llr_lo {
+            SprtDecision::AcceptNull
+        } else {
+            SprtDecision::Continue
+        };
+        self.decision
+    }
+
+    /// Consumes outcomes from `outcomes` until the test stops or the outcomes are exhausted, and returns the
+    /// resulting decision. Outcomes after the one that stops the test are not consumed from the iterator.
+    pub fn push_iter(&mut self, outcomes: impl IntoIterator<Item = bool>) -> SprtDecision {
+        let mut outcomes = outcomes.into_iter();
+        while self.decision == SprtDecision::Continue {
+            match outcomes.next() {
+                Some(success) => self.push(success),
+                None => break,
+            };
+        }
+        self.decision
+    }
+
+    /// Mean and second moment of the log-likelihood ratio of one outcome with probability of success `p`.
+    fn llr_moments(&self, p: f64) -> (f64, f64) {
+        let mean = p * self.llr_s + (1. - p) * self.llr_f;
+        let mean2 = p * self.llr_s.powi(2) + (1. - p) * self.llr_f.powi(2);
+        (mean, mean2)
+    }
+
+    /// Whether the expected log-likelihood ratio of one outcome with probability of success `p` is so close to zero
+    /// that the limiting formulas for zero drift are more accurate than the general ones, which suffer from
+    /// cancellation.
+    fn near_zero_drift(&self, p: f64) -> bool {
+        let (mean, mean2) = self.llr_moments(p);
+        2. * mean.abs() / mean2 * (self.llr_hi - self.llr_lo) < 1e-8
+    }
+
+    /// Operating characteristic: probability that the test accepts the null hypothesis when the actual probability
+    /// of success is `p`, using Wald's approximation (which ignores the overshoot of the boundaries).
+    ///
+    /// At `p == p0`, this is `1 - alpha`, and at `p == p1`, this is `beta`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `p` is not in interval `[0, 1]`.
+    pub fn oc(&self, p: f64) -> StatsResult<f64> {
+        if !(0. ..=1.).contains(&p) {
+            return Err(StatsError("arg `p` must be in interval [0, 1]"));
+        }
+
+        if self.near_zero_drift(p) {
+            return Ok(self.llr_hi / (self.llr_hi - self.llr_lo));
+        }
+        let (mean, mean2) = self.llr_moments(p);
+        if p == 0. || p == 1. {
+            // Every outcome moves the log-likelihood ratio by the same amount.
+            return Ok(if mean < 0. { 1. } else { 0. });
+        }
+
+        // `h` is the non-zero root of `p * (p1 / p0)^h + (1 - p) * ((1 - p1) / (1 - p0))^h == 1`, which has the
+        // sign opposite to `mean`. The function is convex and negative between `0` and `h`.
+        let g = |h: f64| p * (h * self.llr_s).exp() + (1. - p) * (h * self.llr_f).exp() - 1.;
+        let dir = -mean.signum();
+        let h_approx = 2. * mean.abs() / mean2;
+        let near = dir * h_approx * 1e-3;
+        let mut far = dir * h_approx;
+        while g(far) <= 0. {
+            far *= 2.;
+        }
+        let h = find_root(g, near, far, 1e-14 * far.abs())
+            .expect("`g` should have opposite signs at the interval ends");
+
+        let num = (h * self.llr_hi).exp_m1();
+        let den = num - (h * self.llr_lo).exp_m1();
+        Ok(num / den)
+    }
+
+    /// Average sample number: expected number of outcomes consumed before the test stops when the actual
+    /// probability of success is `p`, using Wald's approximation (which ignores the overshoot of the boundaries).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `p` is not in interval `[0, 1]`.
+    pub fn asn(&self, p: f64) -> StatsResult<f64> {
+        let oc = self.oc(p)?;
+        let (mean, mean2) = self.llr_moments(p);
+        if self.near_zero_drift(p) {
+            return Ok(-self.llr_lo * self.llr_hi / mean2);
+        }
+        Ok((oc * self.llr_lo + (1. - oc) * self.llr_hi) / mean)
+    }
+}
+
+/// Mixture sequential probability ratio test (mSPRT) for the difference of the means of two samples
+/// (`mean_x - mean_y`), with null hypothesis `mean_x - mean_y == delta0` and two-sided alternative hypothesis.
+///
+/// Unlike [`crate::normal::welch_test`], whose error probabilities only hold if the test is performed once,
+/// this test yields always-valid p-values and confidence sequences: the probability that the p-value ever drops below
+/// `alpha`, or that any of the confidence intervals ever excludes the actual difference, is at most `alpha`, no matter
+/// how often the results are checked or when sampling is stopped.
+///
+/// The test is fed cumulative [`SampleMoments`] of the two samples with [`Self::update`], as often as desired.
+/// It compares the Normal likelihood of the estimated difference under the null hypothesis with its likelihood
+/// under a Normal mixture of alternatives centered at `delta0` with standard deviation `mixing_sd`.
+/// The variance of the estimated difference is estimated from the samples, as in Welch's t-test, so the guarantees
+/// are asymptotic, and they are accurate when both samples have more than a few dozen observations.
+///
+/// The choice of `mixing_sd` does not affect validity, but the test is most powerful for differences of about that
+/// size.
+///
+/// Reference: R. Johari, P. Koomen, L. Pekelis, and D. Walsh, "Always Valid Inference: Continuous Monitoring of
+/// A/B Tests", Operations Research 70, 2022.
+#[derive(Debug, Clone)]
+pub struct MixtureSprt {
+    delta0: f64,
+    mixing_var: f64,
+    alpha: f64,
+    n_x: u64,
+    n_y: u64,
+    p: f64,
+    ci: Ci,
+}
+
+impl MixtureSprt {
+    /// Creates a test that has not yet seen any data, with p-value `1` and an unbounded confidence interval.
+    ///
+    /// Arguments:
+    /// - `delta0`: difference of means under the null hypothesis.
+    /// - `mixing_sd`: standard deviation of the mixture of alternatives, i.e., the typical size of the differences
+    ///   to detect.
+    /// - `alpha`: confidence level = `1 - alpha`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in any of these conditions:
+    /// - `delta0` is not finite.
+    /// - `mixing_sd` is not finite and positive.
+    /// - `alpha` is not in interval `(0, 1)`.
+    pub fn new(delta0: f64, mixing_sd: f64, alpha: f64) -> StatsResult<Self> {
+        if !delta0.is_finite() {
+            return Err(StatsError("arg `delta0` must be finite"));
+        }
+        if !(mixing_sd.is_finite() && mixing_sd > 0.) {
+            return Err(StatsError("arg `mixing_sd` must be finite and positive"));
+        }
+        check_alpha_in_open_0_1(alpha)?;
+
+        Ok(Self {
+            delta0,
+            mixing_var: mixing_sd * mixing_sd,
+            alpha,
+            n_x: 0,
+            n_y: 0,
+            p: 1.,
+            ci: Ci(-f64::INFINITY, f64::INFINITY),
+        })
+    }
+
+    /// Updates the test with the cumulative moments of the two samples observed so far and returns the resulting
+    /// always-valid test result.
+    ///
+    /// The always-valid p-value is the minimum over all updates of the inverse of the mixture likelihood ratio, and
+    /// the confidence sequence is the intersection of the confidence intervals of all updates.
+    ///
+    /// Arguments:
+    /// - `moments_x`: first sample's moments struct.
+    /// - `moments_y`: second sample's moments struct.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in any of these conditions, in which case `self` is not modified:
+    /// - `moments_x.n() <= 1`.
+    /// - `moments_y.n() <= 1`.
+    /// - `moments_x.n()` or `moments_y.n()` is less than in the previous successful update, i.e., the moments are
+    ///   not cumulative.
+    /// - `moments_x.stdev() == 0` AND `moments_y.stdev() == 0`.
+    pub fn update(
+        &mut self,
+        moments_x: &SampleMoments,
+        moments_y: &SampleMoments,
+    ) -> StatsResult<HypTestResult> {
+        if moments_x.n() <= 1 || moments_y.n() <= 1 {
+            return Err(StatsError("sample sizes must be greater than 1"));
+        }
+        if moments_x.n() < self.n_x || moments_y.n() < self.n_y {
+            return Err(StatsError(
+                "sample sizes must not decrease between updates: moments must be cumulative",
+            ));
+        }
+        let var = moments_x.var()? / moments_x.nf() + moments_y.var()? / moments_y.nf();
+        if var == 0. {
+            return Err(StatsError(
+                "at least one of the sample standard deviations must be positive",
+            ));
+        }
+        let d_hat = moments_x.mean()? - moments_y.mean()?;
+
+        let tau2 = self.mixing_var;
+        let ln_var_ratio = ((var + tau2) / var).ln();
+        let ln_lr =
+            tau2 * (d_hat - self.delta0).powi(2) / (2. * var * (var + tau2)) - 0.5 * ln_var_ratio;
+        self.p = self.p.min((-ln_lr).exp());
+
+        let half_width =
+            ((2. * (1. / self.alpha).ln() + ln_var_ratio) * var * (var + tau2) / tau2).sqrt();
+        let Ci(lo, hi) = self.ci;
+        self.ci = Ci(lo.max(d_hat - half_width), hi.min(d_hat + half_width));
+        self.n_x = moments_x.n();
+        self.n_y = moments_y.n();
+
+        Ok(self.test_result())
+    }
+
+    /// Always-valid p-value after the updates so far.
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Always-valid confidence interval for the difference of means after the updates so far, i.e., the current
+    /// element of the confidence sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the intersection of the confidence intervals of the updates is empty, i.e., no single
+    /// difference of means is consistent with all the updates. Like the exclusion of the actual difference, this
+    /// happens with probability at most `alpha` if the assumptions of the test hold; otherwise, it suggests that
+    /// the data are not identically distributed over time. Once empty, the confidence sequence remains empty.
+    pub fn ci(&self) -> StatsResult<Ci> {
+        let Ci(lo, hi) = self.ci;
+        if lo > hi {
+            return Err(StatsError(
+                "confidence sequence is empty: no difference of means is consistent with all updates",
+            ));
+        }
+        Ok(self.ci)
+    }
+
+    /// Always-valid test result after the updates so far.
+    pub fn test_result(&self) -> HypTestResult {
+        HypTestResult::new(self.p, self.alpha, AltHyp::Ne)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    //! Expected values were computed from Wald's approximation formulas for the SPRT, and from the mixture
+    //! likelihood ratio and confidence interval formulas of Johari et al. for the mSPRT, with high precision (using
+    //! the `mpmath` Python library).
+
+    use super::*;
+    use crate::{core::Hyp, dev_utils::ApproxEq};
+
+    const EPSILON: f64 = 1e-8;
+
+    #[test]
+    fn test_push() {
+        let mut sprt = BernoulliSprt::new(0.1, 0.3, 0.05, 0.1).unwrap();
+        let (lo, hi) = sprt.llr_bounds();
+        assert!((-2.2512917986).approx_eq(lo, EPSILON), "lo={lo}");
+        assert!(2.8903717579.approx_eq(hi, EPSILON), "hi={hi}");
+
+        // Each success adds ln(3) and each failure adds ln(7 / 9).
+        assert_eq!(SprtDecision::Continue, sprt.push_iter([true, false, true]));
+        assert_eq!((3, 2), (sprt.n(), sprt.n_s()));
+        assert!(
+            1.9459101491.approx_eq(sprt.llr(), EPSILON),
+            "llr={}",
+            sprt.llr()
+        );
+        assert_eq!(SprtDecision::AcceptAlt, sprt.push(true));
+        assert_eq!(SprtDecision::AcceptAlt, sprt.push(false));
+        assert_eq!((4, 3), (sprt.n(), sprt.n_s()));
+
+        let mut sprt = BernoulliSprt::new(0.1, 0.3, 0.05, 0.1).unwrap();
+        let mut outcomes = [false; 20].into_iter();
+        assert_eq!(SprtDecision::AcceptNull, sprt.push_iter(&mut outcomes));
+        // ceil(ln(0.1 / 0.95) / ln(7 / 9)) failures are needed.
+        assert_eq!((9, 0), (sprt.n(), sprt.n_s()));
+        assert_eq!(11, outcomes.count());
+    }
+
+    #[test]
+    fn test_oc_asn() {
+        const EPSILON: f64 = 1e-6;
+        let sprt = BernoulliSprt::new(0.1, 0.3, 0.05, 0.1).unwrap();
+        let cases = [
+            (0., 1., 8.958068241),
+            (0.05, 0.9962153445, 12.14152722),
+            (0.1, 0.95, 17.14390050),
+            (0.2, 0.4777271402, 23.24789805),
+            (0.3, 0.1, 15.46368565),
+            (0.5, 0.002574337647, 6.791319828),
+            (1., 0., 2.630929754),
+            // Probability of success for which the expected log-likelihood ratio of an outcome is zero.
+            (0.1861689417, 0.5621471973, 23.56804827),
+        ];
+        for (p, exp_oc, exp_asn) in cases {
+            let oc = sprt.oc(p).unwrap();
+            let asn = sprt.asn(p).unwrap();
+            assert!(
+                exp_oc.approx_eq(oc, EPSILON),
+                "p={p} -> exp_oc={exp_oc}, oc={oc}"
+            );
+            assert!(
+                exp_asn.approx_eq(asn, EPSILON),
+                "p={p} -> exp_asn={exp_asn}, asn={asn}"
+            );
+        }
+
+        // Alternative hypothesis below the null hypothesis.
+        let sprt = BernoulliSprt::new(0.5, 0.4, 0.1, 0.1).unwrap();
+        assert!(0.9.approx_eq(sprt.oc(0.5).unwrap(), EPSILON));
+        assert!(0.1.approx_eq(sprt.oc(0.4).unwrap(), EPSILON));
+    }
+
+    fn check_msprt(
+        msprt: &mut MixtureSprt,
+        (n_x, sum_x, sum2_x): (u64, f64, f64),
+        (n_y, sum_y, sum2_y): (u64, f64, f64),
+        exp_p: f64,
+        exp_ci: Ci,
+        exp_accept_hyp: Hyp,
+    ) {
+        let moments_x = SampleMoments::new(n_x, sum_x, sum2_x);
+        let moments_y = SampleMoments::new(n_y, sum_y, sum2_y);
+        let res = msprt.update(&moments_x, &moments_y).unwrap();
+        let p = res.p();
+        let ci = msprt.ci().unwrap();
+        let ctx = format!("n_x={n_x}, n_y={n_y}");
+
+        assert_eq!(p, msprt.p(), "{ctx} -> p");
+        assert_eq!(res, msprt.test_result(), "{ctx} -> test_result");
+        assert!(
+            exp_p.approx_eq(p, 1e-9 + exp_p * 1e-8),
+            "{ctx} -> exp_p={exp_p}, p={p}"
+        );
+        assert!(
+            exp_ci.0.approx_eq(ci.0, 1e-8),
+            "{ctx} -> exp_ci.0={}, ci.0={}",
+            exp_ci.0,
+            ci.0
+        );
+        assert!(
+            exp_ci.1.approx_eq(ci.1, 1e-8),
+            "{ctx} -> exp_ci.1={}, ci.1={}",
+            exp_ci.1,
+            ci.1
+        );
+        assert_eq!(AltHyp::Ne, res.alt_hyp(), "{ctx} -> res.alt_hyp");
+        assert_eq!(exp_accept_hyp, res.accepted(), "{ctx} -> res.accepted");
+    }
+
+    #[test]
+    fn test_msprt() {
+        // The p-value is the running minimum and the confidence interval the running intersection.
+        let mut msprt = MixtureSprt::new(0., 1., 0.05).unwrap();
+        assert_eq!(1., msprt.p());
+        assert_eq!(Ci(-f64::INFINITY, f64::INFINITY), msprt.ci().unwrap());
+
+        let updates = [
+            (
+                (20, 210., 2300.),
+                (20, 200., 2100.),
+                1.,
+                Ci(-1.84349696627, 2.84349696627),
+            ),
+            (
+                (60, 660., 7600.),
+                (60, 600., 6300.),
+                0.245641812874,
+                Ci(-0.296011267593, 2.29601126759),
+            ),
+            (
+                (100, 1080., 12400.),
+                (100, 1000., 10500.),
+                0.245641812874,
+                Ci(-0.272443528169, 1.87244352817),
+            ),
+            (
+                (150, 1575., 17500.),
+                (150, 1500., 15800.),
+                0.245641812874,
+                Ci(-0.272443528169, 1.35574762874),
+            ),
+        ];
+        for (x, y, exp_p, exp_ci) in updates {
+            check_msprt(&mut msprt, x, y, exp_p, exp_ci, Hyp::Null);
+        }
+        check_msprt(
+            &mut msprt,
+            (200, 2300., 27500.),
+            (200, 2000., 20800.),
+            4.28549516873e-10,
+            Ci(0.834475406589, 1.35574762874),
+            Hyp::Alt(AltHyp::Ne),
+        );
+
+        let mut msprt = MixtureSprt::new(0.5, 0.2, 0.1).unwrap();
+        let updates = [
+            (
+                (20, 210., 2300.),
+                (20, 200., 2100.),
+                1.,
+                Ci(-5.2630670353, 6.2630670353),
+            ),
+            (
+                (60, 660., 7600.),
+                (60, 600., 6300.),
+                0.974995501945,
+                Ci(-1.18976615621, 3.18976615621),
+            ),
+            (
+                (150, 1575., 17500.),
+                (150, 1500., 15800.),
+                0.974995501945,
+                Ci(-0.58409351485, 1.58409351485),
+            ),
+        ];
+        for (x, y, exp_p, exp_ci) in updates {
+            check_msprt(&mut msprt, x, y, exp_p, exp_ci, Hyp::Null);
+        }
+
+        // Invalid updates leave the state unchanged.
+        let (p, ci) = (msprt.p(), msprt.ci().unwrap());
+        let moments_1 = SampleMoments::new(1, 1., 1.);
+        let moments_20 = SampleMoments::new(20, 200., 2100.);
+        assert!(msprt.update(&moments_1, &moments_20).is_err());
+        assert_eq!((p, ci), (msprt.p(), msprt.ci().unwrap()));
+        // Non-cumulative moments: sample sizes smaller than in the last update.
+        assert!(msprt.update(&moments_20, &moments_20).is_err());
+        assert_eq!((p, ci), (msprt.p(), msprt.ci().unwrap()));
+    }
+
+    #[test]
+    fn test_msprt_empty_ci() {
+        // Samples with small variances whose difference of means jumps from `0` to `1`, so that the confidence
+        // intervals of the two updates don't overlap.
+        let mut msprt = MixtureSprt::new(0., 1., 0.05).unwrap();
+        let moments_x = SampleMoments::new(20, 200., 2000.2);
+        let moments_y = SampleMoments::new(20, 200., 2000.2);
+        msprt.update(&moments_x, &moments_y).unwrap();
+        let Ci(lo, hi) = msprt.ci().unwrap();
+        assert!(lo < 0. && 0. < hi, "lo={lo}, hi={hi}");
+        assert!(hi < 1., "hi={hi}");
+
+        let moments_x = SampleMoments::new(40, 440., 4840.4);
+        let moments_y = SampleMoments::new(40, 400., 4000.4);
+        let res = msprt.update(&moments_x, &moments_y).unwrap();
+        assert!(msprt.ci().is_err());
+        assert_eq!(Hyp::Alt(AltHyp::Ne), res.accepted());
+
+        // The confidence sequence remains empty.
+        let moments_x = SampleMoments::new(60, 600., 6000.6);
+        let moments_y = SampleMoments::new(60, 600., 6000.6);
+        msprt.update(&moments_x, &moments_y).unwrap();
+        assert!(msprt.ci().is_err());
+    }
+}
@@ -21,5 +21,44 @@ cargo nextest run --lib --bins --tests --no-default-features --features binomial
 echo "***** --features wilcoxon"
 cargo nextest run --lib --bins --tests --no-default-features --features wilcoxon --target-dir target/test-target
 
+echo "***** --features combine"
+cargo nextest run --lib --bins --tests --no-default-features --features combine --target-dir target/test-target
+
+echo "***** --features contingency"
+cargo nextest run --lib --bins --tests --no-default-features --features contingency --target-dir target/test-target
+
+echo "***** --features multinomial"
+cargo nextest run --lib --bins --tests --no-default-features --features multinomial --target-dir target/test-target
+
+echo "***** --features poisson"
+cargo nextest run --lib --bins --tests --no-default-features --features poisson --target-dir target/test-target
+
+echo "***** --features sequential"
+cargo nextest run --lib --bins --tests --no-default-features --features sequential --target-dir target/test-target
+
+echo "***** --features bayes"
+cargo nextest run --lib --bins --tests --no-default-features --features bayes --target-dir target/test-target
+
+echo "***** --features normality"
+cargo nextest run --lib --bins --tests --no-default-features --features normality --target-dir target/test-target
+
+echo "***** --features ks"
+cargo nextest run --lib --bins --tests --no-default-features --features ks --target-dir target/test-target
+
+echo "***** --features bootstrap"
+cargo nextest run --lib --bins --tests --no-default-features --features bootstrap --target-dir target/test-target
+
+echo "***** --features permutation"
+cargo nextest run --lib --bins --tests --no-default-features --features permutation --target-dir target/test-target
+
+echo "***** --features jackknife"
+cargo nextest run --lib --bins --tests --no-default-features --features jackknife --target-dir target/test-target
+
+echo "***** --features robust"
+cargo nextest run --lib --bins --tests --no-default-features --features robust --target-dir target/test-target
+
+echo "***** --features outliers"
+cargo nextest run --lib --bins --tests --no-default-features --features outliers --target-dir target/test-target
+
 echo "***** doc"
 cargo test --doc
@@ -0,0 +1,181 @@
+#![cfg(feature = "bayes")]
+
+mod nocover;
+
+use basic_stats::{
+    bayes::{BetaDist, JZS_R_MEDIUM, NormalInvGammaDist, jzs_1samp_bf, jzs_2samp_bf},
+    core::SampleMoments,
+};
+use nocover::nocover;
+
+#[test]
+fn test_beta_dist_new() {
+    // Returns an error if `a` or `b` is not finite and positive.
+    assert!(BetaDist::new(0., 1.).is_err());
+    assert!(BetaDist::new(1., -1.).is_err());
+    assert!(BetaDist::new(f64::INFINITY, 1.).is_err());
+    assert!(BetaDist::new(1., f64::NAN).is_err());
+    if nocover() {
+        assert!(BetaDist::new(0.001, 1000.).is_ok());
+    }
+}
+
+#[test]
+fn test_beta_dist_posterior() {
+    // Returns an error if `n < n_s`.
+    assert!(BetaDist::UNIFORM.posterior(3, 4).is_err());
+    if nocover() {
+        assert!(BetaDist::UNIFORM.posterior(0, 0).is_ok());
+    }
+}
+
+#[test]
+fn test_beta_dist_mode() {
+    // Returns an error if the mode is not unique, i.e., `a <= 1` and `b <= 1`.
+    assert!(BetaDist::UNIFORM.mode().is_err());
+    assert!(BetaDist::JEFFREYS.mode().is_err());
+    if nocover() {
+        assert!(BetaDist::new(1., 1.001).unwrap().mode().is_ok());
+    }
+}
+
+#[test]
+fn test_beta_dist_quantile() {
+    // Returns an error if `q` is not in interval `[0, 1]`.
+    assert!(BetaDist::UNIFORM.quantile(-0.1).is_err());
+    assert!(BetaDist::UNIFORM.quantile(1.1).is_err());
+    if nocover() {
+        assert!(BetaDist::UNIFORM.quantile(0.).is_ok());
+        assert!(BetaDist::UNIFORM.quantile(1.).is_ok());
+    }
+}
+
+#[test]
+fn test_beta_dist_equal_tailed_ci() {
+    // Returns an error if `alpha` is not in interval `(0, 1)`.
+    assert!(BetaDist::UNIFORM.equal_tailed_ci(0.).is_err());
+    assert!(BetaDist::UNIFORM.equal_tailed_ci(1.).is_err());
+    if nocover() {
+        assert!(BetaDist::UNIFORM.equal_tailed_ci(0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_beta_dist_hpd_ci() {
+    // Returns an error in any of these conditions:
+    // - `alpha` is not in interval `(0, 1)`.
+    // - `a < 1` and `b < 1`.
+    let dist = BetaDist::new(3., 5.).unwrap();
+    assert!(dist.hpd_ci(0.).is_err());
+    assert!(dist.hpd_ci(1.).is_err());
+    assert!(BetaDist::JEFFREYS.hpd_ci(0.05).is_err());
+    if nocover() {
+        assert!(dist.hpd_ci(0.5).is_ok());
+        assert!(BetaDist::new(0.5, 1.).unwrap().hpd_ci(0.05).is_ok());
+    }
+}
+
+#[test]
+fn test_normal_inv_gamma_dist_new() {
+    // Returns an error in any of these conditions:
+    // - `mu0` is not finite.
+    // - `kappa`, `a`, or `b` is not finite and positive.
+    assert!(NormalInvGammaDist::new(f64::NAN, 1., 1., 1.).is_err());
+    assert!(NormalInvGammaDist::new(0., 0., 1., 1.).is_err());
+    assert!(NormalInvGammaDist::new(0., 1., -1., 1.).is_err());
+    assert!(NormalInvGammaDist::new(0., 1., 1., f64::INFINITY).is_err());
+    if nocover() {
+        assert!(NormalInvGammaDist::new(-5., 0.01, 0.01, 0.01).is_ok());
+    }
+}
+
+#[test]
+fn test_normal_inv_gamma_dist_reference_posterior() {
+    // Returns an error in any of these conditions:
+    // - `moments.n() <= 1`.
+    // - `moments.stdev() == 0`.
+    assert!(NormalInvGammaDist::reference_posterior(&SampleMoments::from_slice(&[1.])).is_err());
+    assert!(
+        NormalInvGammaDist::reference_posterior(&SampleMoments::from_slice(&[1., 1.])).is_err()
+    );
+    if nocover() {
+        assert!(
+            NormalInvGammaDist::reference_posterior(&SampleMoments::from_slice(&[1., 2.])).is_ok()
+        );
+    }
+}
+
+#[test]
+fn test_normal_inv_gamma_dist_var_hat() {
+    // Returns an error if `a <= 1`.
+    assert!(
+        NormalInvGammaDist::new(0., 1., 1., 1.)
+            .unwrap()
+            .var_hat()
+            .is_err()
+    );
+    if nocover() {
+        assert!(
+            NormalInvGammaDist::new(0., 1., 1.01, 1.)
+                .unwrap()
+                .var_hat()
+                .is_ok()
+        );
+    }
+}
+
+#[test]
+fn test_normal_inv_gamma_dist_cis() {
+    // Returns an error if `alpha` is not in interval `(0, 1)`.
+    let dist = NormalInvGammaDist::new(0., 1., 2., 2.).unwrap();
+    assert!(dist.mean_ci(0.).is_err());
+    assert!(dist.mean_ci(1.).is_err());
+    assert!(dist.var_ci(0.).is_err());
+    assert!(dist.var_ci(1.).is_err());
+    if nocover() {
+        assert!(dist.mean_ci(0.5).is_ok());
+        assert!(dist.var_ci(0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_jzs_1samp_bf() {
+    // Returns an error in any of these conditions:
+    // - `moments.n() <= 1`.
+    // - `moments.stdev() == 0`.
+    // - `r` is not finite and positive.
+    let moments = SampleMoments::from_slice(&[1., 2., 4.]);
+    assert!(jzs_1samp_bf(&SampleMoments::from_slice(&[1.]), 0., JZS_R_MEDIUM).is_err());
+    assert!(jzs_1samp_bf(&SampleMoments::from_slice(&[1., 1.]), 0., JZS_R_MEDIUM).is_err());
+    assert!(jzs_1samp_bf(&moments, 0., 0.).is_err());
+    assert!(jzs_1samp_bf(&moments, 0., f64::INFINITY).is_err());
+    if nocover() {
+        assert!(jzs_1samp_bf(&moments, 0., JZS_R_MEDIUM).is_ok());
+    }
+}
+
+#[test]
+fn test_jzs_2samp_bf() {
+    // Returns an error in any of these conditions:
+    // - `moments_x.n() <= 1`.
+    // - `moments_y.n() <= 1`.
+    // - `moments_x.stdev() == 0` AND `moments_y.stdev() == 0`.
+    // - `r` is not finite and positive.
+    let moments = SampleMoments::from_slice(&[1., 2., 4.]);
+    let single = SampleMoments::from_slice(&[1.]);
+    let constant = SampleMoments::from_slice(&[1., 1.]);
+    assert!(jzs_2samp_bf(&single, &moments, JZS_R_MEDIUM).is_err());
+    assert!(jzs_2samp_bf(&moments, &single, JZS_R_MEDIUM).is_err());
+    assert!(
+        jzs_2samp_bf(
+            &constant,
+            &SampleMoments::from_slice(&[2., 2.]),
+            JZS_R_MEDIUM
+        )
+        .is_err()
+    );
+    assert!(jzs_2samp_bf(&moments, &moments, -1.).is_err());
+    if nocover() {
+        assert!(jzs_2samp_bf(&constant, &moments, JZS_R_MEDIUM).is_ok());
+    }
+}
@@ -102,6 +102,225 @@ fn test_binomial_cp_ci() {
     assert!(binomial_cp_ci(2, 1, 1.).is_err());
 }
 
+#[test]
+fn test_binomial_ac_alt_hyp_ci() {
+    // binomial_ac_alt_hyp_ci(n, n_s, alt_hyp, alpha) covered by binomial_ac_ci.
+}
+
+#[test]
+fn test_binomial_ac_ci() {
+    // Returns an error in any of these conditions:
+    // - `n == 0` or `n < n_s`.
+    // - `alpha` is not in interval `(0, 1)`.
+
+    assert!(binomial_ac_ci(0, 0, 0.5).is_err());
+    assert!(binomial_ac_ci(2, 3, 0.5).is_err());
+    assert!(binomial_ac_ci(2, 1, 0.).is_err());
+    assert!(binomial_ac_ci(2, 1, 1.).is_err());
+    assert!(binomial_ac_ci(2, 1, f64::NAN).is_err());
+
+    if nocover() {
+        for n_s in 0..=2 {
+            let Ci(lo, hi) = binomial_ac_ci(2, n_s, 0.5).unwrap();
+            assert!(lo.is_finite());
+            assert!(hi.is_finite());
+        }
+    }
+}
+
+#[test]
+fn test_binomial_jeffreys_alt_hyp_ci() {
+    // binomial_jeffreys_alt_hyp_ci(n, n_s, alt_hyp, alpha) covered by binomial_jeffreys_ci.
+}
+
+#[test]
+fn test_binomial_jeffreys_ci() {
+    // Returns an error in any of these conditions:
+    // - `n == 0` or `n < n_s`.
+    // - `alpha` is not in interval `(0, 1)`.
+
+    assert!(binomial_jeffreys_ci(0, 0, 0.5).is_err());
+    assert!(binomial_jeffreys_ci(2, 3, 0.5).is_err());
+    assert!(binomial_jeffreys_ci(2, 1, 0.).is_err());
+    assert!(binomial_jeffreys_ci(2, 1, 1.).is_err());
+    assert!(binomial_jeffreys_ci(2, 1, f64::NAN).is_err());
+
+    if nocover() {
+        for n_s in 0..=2 {
+            let Ci(lo, hi) = binomial_jeffreys_ci(2, n_s, 0.5).unwrap();
+            assert!(lo.is_finite());
+            assert!(hi.is_finite());
+        }
+    }
+}
+
+#[test]
+fn test_binomial_wald_alt_hyp_ci() {
+    // binomial_wald_alt_hyp_ci(n, n_s, alt_hyp, alpha) covered by binomial_wald_ci.
+}
+
+#[test]
+fn test_binomial_wald_ci() {
+    // Returns an error in any of these conditions:
+    // - `n == 0` or `n < n_s`.
+    // - `alpha` is not in interval `(0, 1)`.
+
+    assert!(binomial_wald_ci(0, 0, 0.5).is_err());
+    assert!(binomial_wald_ci(2, 3, 0.5).is_err());
+    assert!(binomial_wald_ci(2, 1, 0.).is_err());
+    assert!(binomial_wald_ci(2, 1, 1.).is_err());
+    assert!(binomial_wald_ci(2, 1, f64::NAN).is_err());
+
+    if nocover() {
+        for n_s in 0..=2 {
+            let Ci(lo, hi) = binomial_wald_ci(2, n_s, 0.5).unwrap();
+            assert!(lo.is_finite());
+            assert!(hi.is_finite());
+        }
+    }
+}
+
+#[test]
+fn test_binomial_arcsine_alt_hyp_ci() {
+    // binomial_arcsine_alt_hyp_ci(n, n_s, alt_hyp, alpha) covered by binomial_arcsine_ci.
+}
+
+#[test]
+fn test_binomial_arcsine_ci() {
+    // Returns an error in any of these conditions:
+    // - `n == 0` or `n < n_s`.
+    // - `alpha` is not in interval `(0, 1)`.
+
+    assert!(binomial_arcsine_ci(0, 0, 0.5).is_err());
+    assert!(binomial_arcsine_ci(2, 3, 0.5).is_err());
+    assert!(binomial_arcsine_ci(2, 1, 0.).is_err());
+    assert!(binomial_arcsine_ci(2, 1, 1.).is_err());
+    assert!(binomial_arcsine_ci(2, 1, f64::NAN).is_err());
+
+    if nocover() {
+        for n_s in 0..=2 {
+            let Ci(lo, hi) = binomial_arcsine_ci(2, n_s, 0.5).unwrap();
+            assert!(lo.is_finite());
+            assert!(hi.is_finite());
+        }
+    }
+}
+
+#[test]
+fn test_binomial_lr_alt_hyp_ci() {
+    // binomial_lr_alt_hyp_ci(n, n_s, alt_hyp, alpha) covered by binomial_lr_ci.
+}
+
+#[test]
+fn test_binomial_lr_ci() {
+    // Returns an error in any of these conditions:
+    // - `n == 0` or `n < n_s`.
+    // - `alpha` is not in interval `(0, 1)`.
+
+    assert!(binomial_lr_ci(0, 0, 0.5).is_err());
+    assert!(binomial_lr_ci(2, 3, 0.5).is_err());
+    assert!(binomial_lr_ci(2, 1, 0.).is_err());
+    assert!(binomial_lr_ci(2, 1, 1.).is_err());
+    assert!(binomial_lr_ci(2, 1, f64::NAN).is_err());
+
+    if nocover() {
+        for n_s in 0..=2 {
+            let Ci(lo, hi) = binomial_lr_ci(2, n_s, 0.5).unwrap();
+            assert!(lo.is_finite());
+            assert!(hi.is_finite());
+        }
+    }
+}
+
+#[test]
+fn test_binomial_midp_alt_hyp_ci() {
+    // binomial_midp_alt_hyp_ci(n, n_s, alt_hyp, alpha) covered by binomial_midp_ci.
+}
+
+#[test]
+fn test_binomial_midp_ci() {
+    // Returns an error in any of these conditions:
+    // - `n == 0` or `n < n_s`.
+    // - `alpha` is not in interval `(0, 1)`.
+
+    assert!(binomial_midp_ci(0, 0, 0.5).is_err());
+    assert!(binomial_midp_ci(2, 3, 0.5).is_err());
+    assert!(binomial_midp_ci(2, 1, 0.).is_err());
+    assert!(binomial_midp_ci(2, 1, 1.).is_err());
+    assert!(binomial_midp_ci(2, 1, f64::NAN).is_err());
+
+    if nocover() {
+        for n_s in 0..=2 {
+            let Ci(lo, hi) = binomial_midp_ci(2, n_s, 0.5).unwrap();
+            assert!(lo.is_finite());
+            assert!(hi.is_finite());
+        }
+    }
+}
+
+#[test]
+fn test_binomial_blaker_alt_hyp_ci() {
+    // binomial_blaker_alt_hyp_ci(n, n_s, alt_hyp, alpha) covered by binomial_blaker_ci.
+}
+
+#[test]
+fn test_binomial_blaker_ci() {
+    // Returns an error in any of these conditions:
+    // - `n == 0` or `n < n_s`.
+    // - `alpha` is not in interval `(0, 1)`.
+
+    assert!(binomial_blaker_ci(0, 0, 0.5).is_err());
+    assert!(binomial_blaker_ci(2, 3, 0.5).is_err());
+    assert!(binomial_blaker_ci(2, 1, 0.).is_err());
+    assert!(binomial_blaker_ci(2, 1, 1.).is_err());
+    assert!(binomial_blaker_ci(2, 1, f64::NAN).is_err());
+
+    if nocover() {
+        for n_s in 0..=2 {
+            let Ci(lo, hi) = binomial_blaker_ci(2, n_s, 0.5).unwrap();
+            assert!(lo.is_finite());
+            assert!(hi.is_finite());
+        }
+    }
+}
+
+#[test]
+fn test_binomial_alt_hyp_ci() {
+    // binomial_alt_hyp_ci(n, n_s, method, alt_hyp, alpha) covered by binomial_ci.
+}
+
+#[test]
+fn test_binomial_ci() {
+    // Returns an error in any of these conditions:
+    // - `n == 0` or `n < n_s`.
+    // - `alpha` is not in interval `(0, 1)`.
+    use BinomialCiMethod::*;
+
+    for method in [
+        WilsonScore,
+        ClopperPearson,
+        AgrestiCoull,
+        Jeffreys,
+        Wald,
+        Arcsine,
+        LikelihoodRatio,
+        MidP,
+        Blaker,
+    ] {
+        assert!(binomial_ci(0, 0, method, 0.5).is_err());
+        assert!(binomial_ci(2, 3, method, 0.5).is_err());
+        assert!(binomial_ci(2, 1, method, 0.).is_err());
+        assert!(binomial_alt_hyp_ci(2, 1, method, AltHyp::Lt, 1.).is_err());
+        assert!(binomial_alt_hyp_ci(2, 1, method, AltHyp::Gt, 1.).is_err());
+
+        if nocover() {
+            let Ci(lo, hi) = binomial_ci(2, 1, method, 0.5).unwrap();
+            assert!(lo.is_finite());
+            assert!(hi.is_finite());
+        }
+    }
+}
+
 #[test]
 fn test_exact_binomial_p() {
     // Returns an error in any of these conditions:
@@ -143,3 +362,373 @@ fn test_exact_binomial_test() {
         assert!(exact_binomial_test(1, 1, 1., AltHyp::Ne, 0.5).is_ok());
     }
 }
+
+#[test]
+fn test_exact_binomial_method_p() {
+    // Returns an error in any of these conditions:
+    // - `n == 0` or `n < n_s`.
+    // - `p0` is not in interval `[0, 1]`.
+    use ExactBinomialMethod::*;
+
+    for method in [Standard, MidP, Blaker] {
+        for alt_hyp in [AltHyp::Lt, AltHyp::Ne, AltHyp::Gt] {
+            assert!(exact_binomial_method_p(0, 0, 0.5, method, alt_hyp).is_err());
+            assert!(exact_binomial_method_p(2, 3, 0.5, method, alt_hyp).is_err());
+            assert!(exact_binomial_method_p(2, 1, -1., method, alt_hyp).is_err());
+            assert!(exact_binomial_method_p(2, 1, 2., method, alt_hyp).is_err());
+            assert!(exact_binomial_method_p(2, 1, f64::NAN, method, alt_hyp).is_err());
+
+            if nocover() {
+                for (n_s, p0) in [(0, 0.), (0, 1.), (1, 0.), (1, 1.)] {
+                    let p = exact_binomial_method_p(1, n_s, p0, method, alt_hyp).unwrap();
+                    assert!(p.is_finite());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_exact_binomial_method_test() {
+    // Returns an error in any of these conditions:
+    // - `n == 0` or `n < n_s`.
+    // - `p0` is not in interval `[0, 1]`.
+    // - `alpha` is not in interval `(0, 1)`.
+    use ExactBinomialMethod::*;
+
+    for method in [Standard, MidP, Blaker] {
+        assert!(exact_binomial_method_test(0, 0, 0.5, method, AltHyp::Ne, 0.5).is_err());
+        assert!(exact_binomial_method_test(2, 1, 2., method, AltHyp::Ne, 0.5).is_err());
+        assert!(exact_binomial_method_test(2, 1, 0.5, method, AltHyp::Ne, 0.).is_err());
+        assert!(exact_binomial_method_test(2, 1, 0.5, method, AltHyp::Lt, 1.).is_err());
+
+        if nocover() {
+            assert!(exact_binomial_method_test(2, 1, 0.5, method, AltHyp::Gt, 0.5).is_ok());
+        }
+    }
+}
+
+#[test]
+fn test_exact_binomial_method_alt_hyp_ci() {
+    // exact_binomial_method_alt_hyp_ci(n, n_s, method, alt_hyp, alpha) covered by exact_binomial_method_ci.
+}
+
+#[test]
+fn test_exact_binomial_method_ci() {
+    // Returns an error in any of these conditions:
+    // - `n == 0` or `n < n_s`.
+    // - `alpha` is not in interval `(0, 1)`.
+    use ExactBinomialMethod::*;
+
+    for method in [Standard, MidP, Blaker] {
+        assert!(exact_binomial_method_ci(0, 0, method, 0.5).is_err());
+        assert!(exact_binomial_method_ci(2, 3, method, 0.5).is_err());
+        assert!(exact_binomial_method_ci(2, 1, method, 0.).is_err());
+        assert!(exact_binomial_method_alt_hyp_ci(2, 1, method, AltHyp::Gt, 1.).is_err());
+
+        if nocover() {
+            let Ci(lo, hi) = exact_binomial_method_ci(2, 1, method, 0.5).unwrap();
+            assert!(lo.is_finite());
+            assert!(hi.is_finite());
+        }
+    }
+}
+
+#[test]
+fn test_two_proportion_z() {
+    // Returns an error in any of these conditions:
+    // - `n_x == 0` or `n_x < n_s_x`.
+    // - `n_y == 0` or `n_y < n_s_y`.
+    // - All trials in both samples are successes or all trials in both samples are failures.
+    assert!(two_proportion_z(0, 0, 2, 1, false).is_err());
+    assert!(two_proportion_z(2, 3, 2, 1, false).is_err());
+    assert!(two_proportion_z(2, 1, 0, 0, false).is_err());
+    assert!(two_proportion_z(2, 1, 2, 3, false).is_err());
+    assert!(two_proportion_z(2, 0, 3, 0, true).is_err());
+    assert!(two_proportion_z(2, 2, 3, 3, true).is_err());
+    if nocover() {
+        assert!(two_proportion_z(2, 2, 3, 0, true).unwrap().is_finite());
+        assert!(two_proportion_z(2, 1, 2, 1, true).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_two_proportion_z_p() {
+    // two_proportion_z_p(n_x, n_s_x, n_y, n_s_y, continuity_correction, alt_hyp) covered by two_proportion_z.
+}
+
+#[test]
+fn test_two_proportion_z_test() {
+    // Returns an error in any of these conditions:
+    // - `n_x == 0` or `n_x < n_s_x`.
+    // - `n_y == 0` or `n_y < n_s_y`.
+    // - All trials in both samples are successes or all trials in both samples are failures.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(two_proportion_z_test(0, 0, 2, 1, false, AltHyp::Ne, 0.5).is_err());
+    assert!(two_proportion_z_test(2, 1, 2, 3, false, AltHyp::Ne, 0.5).is_err());
+    assert!(two_proportion_z_test(2, 0, 3, 0, false, AltHyp::Ne, 0.5).is_err());
+    assert!(two_proportion_z_test(2, 1, 2, 1, false, AltHyp::Ne, 0.).is_err());
+    assert!(two_proportion_z_test(2, 1, 2, 1, false, AltHyp::Ne, 1.).is_err());
+    if nocover() {
+        assert!(two_proportion_z_test(2, 1, 2, 1, false, AltHyp::Ne, 0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_two_proportion_wald_ci() {
+    // Returns an error in any of these conditions:
+    // - `n_x == 0` or `n_x < n_s_x`.
+    // - `n_y == 0` or `n_y < n_s_y`.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(two_proportion_wald_ci(0, 0, 2, 1, false, 0.5).is_err());
+    assert!(two_proportion_wald_ci(2, 1, 2, 3, false, 0.5).is_err());
+    assert!(two_proportion_wald_ci(2, 1, 2, 1, false, 0.).is_err());
+    assert!(two_proportion_wald_ci(2, 1, 2, 1, false, 1.).is_err());
+    if nocover() {
+        let Ci(lo, hi) = two_proportion_wald_ci(2, 0, 3, 0, true, 0.5).unwrap();
+        assert!(lo.is_finite());
+        assert!(hi.is_finite());
+    }
+}
+
+#[test]
+fn test_two_proportion_newcombe_ci() {
+    // Returns an error in any of these conditions:
+    // - `n_x == 0` or `n_x < n_s_x`.
+    // - `n_y == 0` or `n_y < n_s_y`.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(two_proportion_newcombe_ci(0, 0, 2, 1, 0.5).is_err());
+    assert!(two_proportion_newcombe_ci(2, 1, 2, 3, 0.5).is_err());
+    assert!(two_proportion_newcombe_ci(2, 1, 2, 1, 0.).is_err());
+    assert!(two_proportion_newcombe_alt_hyp_ci(2, 1, 2, 1, AltHyp::Lt, 1.).is_err());
+    if nocover() {
+        let Ci(lo, hi) = two_proportion_newcombe_ci(2, 2, 3, 3, 0.5).unwrap();
+        assert!(lo.is_finite());
+        assert!(hi.is_finite());
+    }
+}
+
+#[test]
+fn test_two_proportion_ac_ci() {
+    // Returns an error in any of these conditions:
+    // - `n_x == 0` or `n_x < n_s_x`.
+    // - `n_y == 0` or `n_y < n_s_y`.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(two_proportion_ac_ci(0, 0, 2, 1, 0.5).is_err());
+    assert!(two_proportion_ac_ci(2, 3, 2, 1, 0.5).is_err());
+    assert!(two_proportion_ac_ci(2, 1, 2, 1, 0.).is_err());
+    assert!(two_proportion_ac_alt_hyp_ci(2, 1, 2, 1, AltHyp::Gt, 1.).is_err());
+    if nocover() {
+        let Ci(lo, hi) = two_proportion_ac_ci(2, 0, 3, 3, 0.5).unwrap();
+        assert!(lo.is_finite());
+        assert!(hi.is_finite());
+    }
+}
+
+#[test]
+fn test_mcnemar_z() {
+    // Returns an error if `n_sf + n_fs == 0`.
+    assert!(mcnemar_z(0, 0, false).is_err());
+    assert!(mcnemar_z(0, 0, true).is_err());
+    if nocover() {
+        assert!(mcnemar_z(1, 0, true).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_mcnemar_z_p() {
+    // Returns an error if `n_sf + n_fs == 0`.
+    assert!(mcnemar_z_p(0, 0, false, AltHyp::Ne).is_err());
+    if nocover() {
+        assert!(mcnemar_z_p(0, 1, false, AltHyp::Ne).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_mcnemar_z_test() {
+    // Returns an error in any of these conditions:
+    // - `n_sf + n_fs == 0`.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(mcnemar_z_test(0, 0, true, AltHyp::Ne, 0.5).is_err());
+    assert!(mcnemar_z_test(1, 2, true, AltHyp::Ne, 0.).is_err());
+    assert!(mcnemar_z_test(1, 2, true, AltHyp::Ne, 1.).is_err());
+    if nocover() {
+        assert!(mcnemar_z_test(1, 2, true, AltHyp::Ne, 0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_exact_mcnemar_p() {
+    // Returns an error if `n_sf + n_fs == 0`.
+    assert!(exact_mcnemar_p(0, 0, AltHyp::Ne).is_err());
+    if nocover() {
+        assert!(exact_mcnemar_p(0, 1, AltHyp::Lt).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_exact_mcnemar_test() {
+    // Returns an error in any of these conditions:
+    // - `n_sf + n_fs == 0`.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(exact_mcnemar_test(0, 0, AltHyp::Ne, 0.5).is_err());
+    assert!(exact_mcnemar_test(1, 2, AltHyp::Ne, 0.).is_err());
+    assert!(exact_mcnemar_test(1, 2, AltHyp::Ne, 1.).is_err());
+    if nocover() {
+        assert!(exact_mcnemar_test(1, 2, AltHyp::Gt, 0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_paired_proportion_wald_ci() {
+    // Returns an error in any of these conditions:
+    // - `n == 0` or `n < n_sf + n_fs`.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(paired_proportion_wald_ci(0, 0, 0, 0.5).is_err());
+    assert!(paired_proportion_wald_ci(2, 2, 1, 0.5).is_err());
+    assert!(paired_proportion_wald_ci(3, 2, 1, 0.).is_err());
+    assert!(paired_proportion_wald_alt_hyp_ci(3, 2, 1, AltHyp::Lt, 1.).is_err());
+    if nocover() {
+        let Ci(lo, hi) = paired_proportion_wald_ci(3, 0, 0, 0.5).unwrap();
+        assert!(lo.is_finite());
+        assert!(hi.is_finite());
+    }
+}
+
+#[test]
+fn test_paired_proportion_am_ci() {
+    // Returns an error in any of these conditions:
+    // - `n == 0` or `n < n_sf + n_fs`.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(paired_proportion_am_ci(0, 0, 0, 0.5).is_err());
+    assert!(paired_proportion_am_ci(2, 1, 2, 0.5).is_err());
+    assert!(paired_proportion_am_ci(3, 2, 1, 0.).is_err());
+    assert!(paired_proportion_am_alt_hyp_ci(3, 2, 1, AltHyp::Gt, 1.).is_err());
+    if nocover() {
+        let Ci(lo, hi) = paired_proportion_am_ci(3, 3, 0, 0.5).unwrap();
+        assert!(lo.is_finite());
+        assert!(hi.is_finite());
+    }
+}
+
+#[test]
+fn test_one_proportion_z_power() {
+    // Returns an error in any of these conditions:
+    // - `n == 0`.
+    // - `p0` or `p1` is not in interval `(0, 1)`.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(one_proportion_z_power(0, 0.5, 0.6, AltHyp::Ne, 0.5).is_err());
+    assert!(one_proportion_z_power(10, 0., 0.6, AltHyp::Ne, 0.5).is_err());
+    assert!(one_proportion_z_power(10, 0.5, 1., AltHyp::Ne, 0.5).is_err());
+    assert!(one_proportion_z_power(10, 0.5, 0.6, AltHyp::Ne, 0.).is_err());
+    assert!(one_proportion_z_power(10, 0.5, 0.6, AltHyp::Gt, 1.).is_err());
+    if nocover() {
+        assert!(
+            one_proportion_z_power(10, 0.5, 0.6, AltHyp::Ne, 0.5)
+                .unwrap()
+                .is_finite()
+        );
+    }
+}
+
+#[test]
+fn test_one_proportion_z_sample_size() {
+    // Returns an error in any of these conditions:
+    // - `p0` or `p1` is not in interval `(0, 1)`.
+    // - `alpha` is not in interval `(0, 1)`.
+    // - `power` is not in interval `(0, 1)`.
+    // - The target is unattainable: `p1 >= p0` for `AltHyp::Lt`, `p1 <= p0` for `AltHyp::Gt`, or `p1 == p0`
+    //   for `AltHyp::Ne`.
+    assert!(one_proportion_z_sample_size(1., 0.6, AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(one_proportion_z_sample_size(0.5, 0., AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(one_proportion_z_sample_size(0.5, 0.6, AltHyp::Ne, 0., 0.8).is_err());
+    assert!(one_proportion_z_sample_size(0.5, 0.6, AltHyp::Ne, 0.5, 1.).is_err());
+    assert!(one_proportion_z_sample_size(0.5, 0.6, AltHyp::Ne, 0.5, 0.).is_err());
+    assert!(one_proportion_z_sample_size(0.5, 0.5, AltHyp::Lt, 0.5, 0.8).is_err());
+    assert!(one_proportion_z_sample_size(0.5, 0.5, AltHyp::Gt, 0.5, 0.8).is_err());
+    assert!(one_proportion_z_sample_size(0.5, 0.5, AltHyp::Ne, 0.5, 0.8).is_err());
+    if nocover() {
+        assert!(one_proportion_z_sample_size(0.5, 0.6, AltHyp::Ne, 0.5, 0.8).is_ok());
+    }
+}
+
+#[test]
+fn test_exact_binomial_power() {
+    // Returns an error in any of these conditions:
+    // - `n == 0`.
+    // - `p0` or `p1` is not in interval `(0, 1)`.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(exact_binomial_power(0, 0.5, 0.6, AltHyp::Ne, 0.5).is_err());
+    assert!(exact_binomial_power(10, 1., 0.6, AltHyp::Ne, 0.5).is_err());
+    assert!(exact_binomial_power(10, 0.5, 0., AltHyp::Ne, 0.5).is_err());
+    assert!(exact_binomial_power(10, 0.5, 0.6, AltHyp::Ne, 0.).is_err());
+    assert!(exact_binomial_power(10, 0.5, 0.6, AltHyp::Lt, 1.).is_err());
+    if nocover() {
+        assert!(
+            exact_binomial_power(10, 0.5, 0.6, AltHyp::Ne, 0.5)
+                .unwrap()
+                .is_finite()
+        );
+    }
+}
+
+#[test]
+fn test_exact_binomial_sample_size() {
+    // Returns an error in any of these conditions:
+    // - `p0` or `p1` is not in interval `(0, 1)`.
+    // - `alpha` is not in interval `(0, 1)`.
+    // - `power` is not in interval `(0, 1)`.
+    // - The target is unattainable: `p1 >= p0` for `AltHyp::Lt`, `p1 <= p0` for `AltHyp::Gt`, or `p1 == p0`
+    //   for `AltHyp::Ne`.
+    assert!(exact_binomial_sample_size(0., 0.6, AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(exact_binomial_sample_size(0.5, 1., AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(exact_binomial_sample_size(0.5, 0.6, AltHyp::Ne, 1., 0.8).is_err());
+    assert!(exact_binomial_sample_size(0.5, 0.6, AltHyp::Ne, 0.5, 1.).is_err());
+    assert!(exact_binomial_sample_size(0.5, 0.6, AltHyp::Lt, 0.5, 0.8).is_err());
+    assert!(exact_binomial_sample_size(0.5, 0.4, AltHyp::Gt, 0.5, 0.8).is_err());
+    assert!(exact_binomial_sample_size(0.5, 0.5, AltHyp::Ne, 0.5, 0.8).is_err());
+    if nocover() {
+        assert!(exact_binomial_sample_size(0.5, 0.6, AltHyp::Ne, 0.5, 0.8).is_ok());
+    }
+}
+
+#[test]
+fn test_two_proportion_z_power() {
+    // Returns an error in any of these conditions:
+    // - `n_x == 0` or `n_y == 0`.
+    // - `p_x` or `p_y` is not in interval `(0, 1)`.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(two_proportion_z_power(0, 10, 0.5, 0.6, AltHyp::Ne, 0.5).is_err());
+    assert!(two_proportion_z_power(10, 0, 0.5, 0.6, AltHyp::Ne, 0.5).is_err());
+    assert!(two_proportion_z_power(10, 10, 0., 0.6, AltHyp::Ne, 0.5).is_err());
+    assert!(two_proportion_z_power(10, 10, 0.5, 1., AltHyp::Ne, 0.5).is_err());
+    assert!(two_proportion_z_power(10, 10, 0.5, 0.6, AltHyp::Ne, 0.).is_err());
+    assert!(two_proportion_z_power(10, 10, 0.5, 0.6, AltHyp::Gt, 1.).is_err());
+    if nocover() {
+        assert!(
+            two_proportion_z_power(10, 10, 0.5, 0.6, AltHyp::Ne, 0.5)
+                .unwrap()
+                .is_finite()
+        );
+    }
+}
+
+#[test]
+fn test_two_proportion_z_sample_size() {
+    // Returns an error in any of these conditions:
+    // - `p_x` or `p_y` is not in interval `(0, 1)`.
+    // - `alpha` is not in interval `(0, 1)`.
+    // - `power` is not in interval `(0, 1)`.
+    // - The target is unattainable: `p_x >= p_y` for `AltHyp::Lt`, `p_x <= p_y` for `AltHyp::Gt`, or
+    //   `p_x == p_y` for `AltHyp::Ne`.
+    assert!(two_proportion_z_sample_size(0., 0.6, AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(two_proportion_z_sample_size(0.5, 1., AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(two_proportion_z_sample_size(0.5, 0.6, AltHyp::Ne, 0., 0.8).is_err());
+    assert!(two_proportion_z_sample_size(0.5, 0.6, AltHyp::Ne, 0.5, 0.).is_err());
+    assert!(two_proportion_z_sample_size(0.6, 0.5, AltHyp::Lt, 0.5, 0.8).is_err());
+    assert!(two_proportion_z_sample_size(0.5, 0.6, AltHyp::Gt, 0.5, 0.8).is_err());
+    assert!(two_proportion_z_sample_size(0.5, 0.5, AltHyp::Ne, 0.5, 0.8).is_err());
+    if nocover() {
+        assert!(two_proportion_z_sample_size(0.5, 0.6, AltHyp::Ne, 0.5, 0.8).is_ok());
+    }
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "bootstrap")]
+
+mod nocover;
+
+use basic_stats::bootstrap::*;
+use nocover::nocover;
+
+const ALPHA: f64 = 0.05;
+
+fn mean(s: &[f64]) -> f64 {
+    s.iter().sum::<f64>() / s.len() as f64
+}
+
+fn diff_means(x: &[f64], y: &[f64]) -> f64 {
+    mean(x) - mean(y)
+}
+
+const METHODS: [BootstrapCiMethod; 4] = [
+    BootstrapCiMethod::Percentile,
+    BootstrapCiMethod::Basic,
+    BootstrapCiMethod::Studentized { n_inner: 10 },
+    BootstrapCiMethod::Bca,
+];
+
+#[test]
+fn test_replicates() {
+    // Returns an error if `dataset` is empty.
+    assert!(bootstrap_replicates(&[], mean, 10, 1).is_err());
+    if nocover() {
+        assert!(bootstrap_replicates(&[1.], mean, 10, 1).is_ok());
+    }
+}
+
+#[test]
+fn test_2samp_replicates() {
+    // Returns an error if `x` or `y` is empty.
+    assert!(bootstrap_2samp_replicates(&[], &[1.], diff_means, 10, 1).is_err());
+    assert!(bootstrap_2samp_replicates(&[1.], &[], diff_means, 10, 1).is_err());
+    if nocover() {
+        assert!(bootstrap_2samp_replicates(&[1.], &[2.], diff_means, 10, 1).is_ok());
+    }
+}
+
+#[test]
+fn test_ci() {
+    // Returns an error in any of these conditions:
+    // - `alpha` not in open interval `(0, 1)`.
+    // - `dataset` is empty, or has fewer than `2` items and `method` is `Bca`.
+    // - `n_resamples < 2`, or `n_inner < 2` for `Studentized`.
+    // - `stat` returns a non-finite value for `dataset` or for any resample.
+    // - For `Studentized`, a nested bootstrap standard error is zero.
+    // - For `Bca`, all replicates are on the same side of the statistic's value.
+    let dat = [1., 4., 2., 8., 5., 7.];
+    for method in METHODS {
+        assert!(bootstrap_ci(&dat, mean, method, 20, 1, 0.).is_err());
+        assert!(bootstrap_ci(&dat, mean, method, 20, 1, 1.).is_err());
+        assert!(bootstrap_ci(&[], mean, method, 20, 1, ALPHA).is_err());
+        assert!(bootstrap_ci(&dat, mean, method, 1, 1, ALPHA).is_err());
+        assert!(bootstrap_ci(&dat, |_| f64::NAN, method, 20, 1, ALPHA).is_err());
+        assert!(bootstrap_ci(&dat, |s| 1. / (s[0] - 1.), method, 20, 1, ALPHA).is_err());
+        if nocover() {
+            assert!(bootstrap_ci(&dat, mean, method, 20, 1, ALPHA).is_ok());
+        }
+    }
+    let studentized_1 = BootstrapCiMethod::Studentized { n_inner: 1 };
+    assert!(bootstrap_ci(&dat, mean, studentized_1, 20, 1, ALPHA).is_err());
+    assert!(bootstrap_ci(&[1.], mean, BootstrapCiMethod::Bca, 20, 1, ALPHA).is_err());
+    let studentized = BootstrapCiMethod::Studentized { n_inner: 10 };
+    assert!(bootstrap_ci(&[1., 2.], |_| 0., studentized, 20, 1, ALPHA).is_err());
+    assert!(bootstrap_ci(&dat, |_| 0., BootstrapCiMethod::Bca, 20, 1, ALPHA).is_err());
+}
+
+#[test]
+fn test_2samp_ci() {
+    // Returns an error in any of these conditions:
+    // - `alpha` not in open interval `(0, 1)`.
+    // - `x` or `y` is empty, or has fewer than `2` items and `method` is `Bca`.
+    // - `n_resamples < 2`, or `n_inner < 2` for `Studentized`.
+    // - `stat` returns a non-finite value for the samples or for any resample.
+    // - For `Studentized`, a nested bootstrap standard error is zero.
+    // - For `Bca`, all replicates are on the same side of the statistic's value.
+    let x = [1., 4., 2., 8., 5., 7.];
+    let y = [3., 2., 6., 1., 2.];
+    for method in METHODS {
+        assert!(bootstrap_2samp_ci(&x, &y, diff_means, method, 20, 1, 0.).is_err());
+        assert!(bootstrap_2samp_ci(&x, &y, diff_means, method, 20, 1, 1.).is_err());
+        assert!(bootstrap_2samp_ci(&[], &y, diff_means, method, 20, 1, ALPHA).is_err());
+        assert!(bootstrap_2samp_ci(&x, &[], diff_means, method, 20, 1, ALPHA).is_err());
+        assert!(bootstrap_2samp_ci(&x, &y, diff_means, method, 1, 1, ALPHA).is_err());
+        assert!(bootstrap_2samp_ci(&x, &y, |_, _| f64::NAN, method, 20, 1, ALPHA).is_err());
+        if nocover() {
+            assert!(bootstrap_2samp_ci(&x, &y, diff_means, method, 20, 1, ALPHA).is_ok());
+        }
+    }
+    let studentized_1 = BootstrapCiMethod::Studentized { n_inner: 1 };
+    assert!(bootstrap_2samp_ci(&x, &y, diff_means, studentized_1, 20, 1, ALPHA).is_err());
+    let bca = BootstrapCiMethod::Bca;
+    assert!(bootstrap_2samp_ci(&x, &[1.], diff_means, bca, 20, 1, ALPHA).is_err());
+    assert!(bootstrap_2samp_ci(&x, &y, |_, _| 0., bca, 20, 1, ALPHA).is_err());
+}
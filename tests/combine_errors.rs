@@ -0,0 +1,129 @@
+#![cfg(feature = "combine")]
+
+mod nocover;
+
+use basic_stats::combine::*;
+use nocover::nocover;
+
+#[test]
+fn test_fisher_combined_chi2() {
+    // Returns an error in any of these conditions:
+    // - `p_values` is empty.
+    // - Any item of `p_values` is not in interval `(0, 1]`.
+    assert!(fisher_combined_chi2(&[]).is_err());
+    assert!(fisher_combined_chi2(&[0.5, 0.]).is_err());
+    assert!(fisher_combined_chi2(&[0.5, 1.1]).is_err());
+    assert!(fisher_combined_chi2(&[0.5, f64::NAN]).is_err());
+    if nocover() {
+        assert!(fisher_combined_chi2(&[0.5, 1.]).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_fisher_combined_df() {
+    // Returns an error if `p_values` is empty.
+    assert!(fisher_combined_df(&[]).is_err());
+    if nocover() {
+        assert!(fisher_combined_df(&[0.5]).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_fisher_combined_test() {
+    // Returns an error in any of these conditions:
+    // - `p_values` is empty.
+    // - Any item of `p_values` is not in interval `(0, 1]`.
+    // - `alpha` not in interval `(0, 1)`.
+    assert!(fisher_combined_test(&[], 0.5).is_err());
+    assert!(fisher_combined_test(&[0.], 0.5).is_err());
+    assert!(fisher_combined_test(&[0.5], 0.).is_err());
+    assert!(fisher_combined_test(&[0.5], 1.).is_err());
+    if nocover() {
+        assert!(fisher_combined_test(&[1.], 0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_stouffer_weighted_z() {
+    // Returns an error in any of these conditions:
+    // - `p_values` is empty.
+    // - Any item of `p_values` is not in interval `(0, 1)`.
+    // - `p_values` and `weights` have different lengths.
+    // - Any item of `weights` is not finite and positive.
+    assert!(stouffer_weighted_z(&[], &[]).is_err());
+    assert!(stouffer_weighted_z(&[0.], &[1.]).is_err());
+    assert!(stouffer_weighted_z(&[1.], &[1.]).is_err());
+    assert!(stouffer_weighted_z(&[0.5], &[1., 1.]).is_err());
+    assert!(stouffer_weighted_z(&[0.5], &[0.]).is_err());
+    assert!(stouffer_weighted_z(&[0.5], &[f64::INFINITY]).is_err());
+    if nocover() {
+        assert!(stouffer_weighted_z(&[0.5], &[2.]).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_stouffer_combined_test() {
+    // Returns an error in any of these conditions:
+    // - `p_values` is empty.
+    // - Any item of `p_values` is not in interval `(0, 1)`.
+    // - `alpha` not in interval `(0, 1)`.
+    assert!(stouffer_combined_test(&[], 0.5).is_err());
+    assert!(stouffer_combined_test(&[1.], 0.5).is_err());
+    assert!(stouffer_combined_test(&[0.5], 0.).is_err());
+    if nocover() {
+        assert!(stouffer_combined_test(&[0.5], 0.5).is_ok());
+        assert!(stouffer_weighted_combined_test(&[0.5], &[1.], 0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_tippett_combined_test() {
+    // Returns an error in any of these conditions:
+    // - `p_values` is empty.
+    // - Any item of `p_values` is not in interval `[0, 1]`.
+    // - `alpha` not in interval `(0, 1)`.
+    assert!(tippett_combined_test(&[], 0.5).is_err());
+    assert!(tippett_combined_test(&[-0.1], 0.5).is_err());
+    assert!(tippett_combined_test(&[1.1], 0.5).is_err());
+    assert!(tippett_combined_test(&[0.5], 1.).is_err());
+    if nocover() {
+        assert!(tippett_combined_test(&[0., 1.], 0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_harmonic_mean_weighted_p() {
+    // Returns an error in any of these conditions:
+    // - `p_values` is empty.
+    // - Any item of `p_values` is not in interval `(0, 1]`.
+    // - `p_values` and `weights` have different lengths.
+    // - Any item of `weights` is not finite and positive.
+    assert!(harmonic_mean_weighted_p(&[], &[]).is_err());
+    assert!(harmonic_mean_weighted_p(&[0.], &[1.]).is_err());
+    assert!(harmonic_mean_weighted_p(&[0.5], &[]).is_err());
+    assert!(harmonic_mean_weighted_p(&[0.5], &[-1.]).is_err());
+    if nocover() {
+        assert!(harmonic_mean_weighted_p(&[1.], &[1.]).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_hmp_combined_test() {
+    // Returns an error in any of these conditions:
+    // - `p_values` is empty.
+    // - Any item of `p_values` is not in interval `(0, 1]`.
+    // - `alpha` not in interval `(0, 1)`.
+    assert!(hmp_combined_test(&[], 0.5).is_err());
+    assert!(hmp_combined_test(&[0.], 0.5).is_err());
+    assert!(hmp_combined_test(&[0.5], 0.).is_err());
+    assert!(hmp_weighted_combined_test(&[0.5], &[1., 2.], 0.5).is_err());
+    if nocover() {
+        assert!(
+            hmp_combined_test(&[1e-300, 1.], 0.5)
+                .unwrap()
+                .p()
+                .is_finite()
+        );
+        assert!(hmp_combined_test(&[1., 1.], 0.5).unwrap().p().is_finite());
+    }
+}
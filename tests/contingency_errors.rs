@@ -0,0 +1,144 @@
+#![cfg(feature = "contingency")]
+
+mod nocover;
+
+use basic_stats::{
+    contingency::*,
+    core::{AltHyp, Ci},
+};
+use nocover::nocover;
+
+#[test]
+fn test_fisher_exact_p() {
+    // Returns an error if any row sum or column sum of `table` is zero.
+    assert!(fisher_exact_p([[0, 0], [1, 2]], AltHyp::Ne).is_err());
+    assert!(fisher_exact_p([[1, 2], [0, 0]], AltHyp::Ne).is_err());
+    assert!(fisher_exact_p([[0, 1], [0, 2]], AltHyp::Ne).is_err());
+    assert!(fisher_exact_p([[1, 0], [2, 0]], AltHyp::Ne).is_err());
+    if nocover() {
+        assert!(
+            fisher_exact_p([[1, 0], [0, 1]], AltHyp::Ne)
+                .unwrap()
+                .is_finite()
+        );
+        assert!(
+            fisher_exact_p([[1, 0], [0, 1]], AltHyp::Lt)
+                .unwrap()
+                .is_finite()
+        );
+        assert!(
+            fisher_exact_p([[1, 0], [0, 1]], AltHyp::Gt)
+                .unwrap()
+                .is_finite()
+        );
+    }
+}
+
+#[test]
+fn test_fisher_exact_test() {
+    // Returns an error in any of these conditions:
+    // - Any row sum or column sum of `table` is zero.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(fisher_exact_test([[0, 0], [1, 2]], AltHyp::Ne, 0.5).is_err());
+    assert!(fisher_exact_test([[1, 2], [3, 4]], AltHyp::Ne, 0.).is_err());
+    assert!(fisher_exact_test([[1, 2], [3, 4]], AltHyp::Ne, 1.).is_err());
+    if nocover() {
+        assert!(fisher_exact_test([[1, 2], [3, 4]], AltHyp::Ne, 0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_fisher_exact_odds_ratio() {
+    // Returns an error if any row sum or column sum of `table` is zero.
+    assert!(fisher_exact_odds_ratio([[0, 0], [1, 2]]).is_err());
+    if nocover() {
+        assert!(
+            fisher_exact_odds_ratio([[1, 2], [3, 4]])
+                .unwrap()
+                .is_finite()
+        );
+    }
+}
+
+#[test]
+fn test_fisher_exact_alt_hyp_ci() {
+    // fisher_exact_alt_hyp_ci(table, alt_hyp, alpha) covered by fisher_exact_ci.
+}
+
+#[test]
+fn test_fisher_exact_ci() {
+    // Returns an error in any of these conditions:
+    // - Any row sum or column sum of `table` is zero.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(fisher_exact_ci([[0, 0], [1, 2]], 0.5).is_err());
+    assert!(fisher_exact_ci([[1, 2], [3, 4]], 0.).is_err());
+    assert!(fisher_exact_ci([[1, 2], [3, 4]], 1.).is_err());
+    if nocover() {
+        let Ci(lo, hi) = fisher_exact_ci([[1, 2], [3, 4]], 0.5).unwrap();
+        assert!(lo.is_finite());
+        assert!(hi.is_finite());
+    }
+}
+
+#[test]
+fn test_contingency_table_from_counts() {
+    // Returns an error in any of these conditions:
+    // - There are fewer than `2` rows or fewer than `2` columns.
+    // - The rows do not all have the same length.
+    // - Any row sum or column sum is zero.
+    assert!(ContingencyTable::from_counts::<[u64; 2]>(&[]).is_err());
+    assert!(ContingencyTable::from_counts(&[[1, 2]]).is_err());
+    assert!(ContingencyTable::from_counts(&[[1], [2]]).is_err());
+    assert!(ContingencyTable::from_counts(&[vec![1, 2], vec![3]]).is_err());
+    assert!(ContingencyTable::from_counts(&[[0, 0], [1, 2]]).is_err());
+    assert!(ContingencyTable::from_counts(&[[0, 1], [0, 2]]).is_err());
+    if nocover() {
+        assert!(ContingencyTable::from_counts(&[[1, 0], [0, 1]]).is_ok());
+    }
+}
+
+#[test]
+fn test_contingency_table_from_paired_iterators() {
+    // Returns an error in any of these conditions:
+    // - The iterators do not have the same number of items.
+    // - There are fewer than `2` distinct row categories or fewer than `2` distinct column categories.
+    assert!(
+        ContingencyTable::from_paired_iterators([1, 2, 1].into_iter(), [1, 2].into_iter()).is_err()
+    );
+    assert!(
+        ContingencyTable::from_paired_iterators([1, 2].into_iter(), [1, 2, 1].into_iter()).is_err()
+    );
+    assert!(
+        ContingencyTable::from_paired_iterators([1, 1].into_iter(), [1, 2].into_iter()).is_err()
+    );
+    assert!(
+        ContingencyTable::from_paired_iterators([1, 2].into_iter(), [1, 1].into_iter()).is_err()
+    );
+    if nocover() {
+        assert!(
+            ContingencyTable::from_paired_iterators([1, 2].into_iter(), [1, 2].into_iter()).is_ok()
+        );
+    }
+}
+
+#[test]
+fn test_contingency_table_chi2_test() {
+    // Returns an error if `alpha` not in interval `(0, 1)`.
+    let table = ContingencyTable::from_counts(&[[1, 2], [3, 4]]).unwrap();
+    assert!(table.chi2_test(false, 0.).is_err());
+    assert!(table.chi2_test(true, 1.).is_err());
+    if nocover() {
+        assert!(table.chi2_test(true, 0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_contingency_table_g_test() {
+    // Returns an error if `alpha` not in interval `(0, 1)`.
+    let table = ContingencyTable::from_counts(&[[1, 2], [3, 4]]).unwrap();
+    assert!(table.g_test(0.).is_err());
+    assert!(table.g_test(1.).is_err());
+    if nocover() {
+        assert!(table.g_test(0.5).is_ok());
+    }
+}
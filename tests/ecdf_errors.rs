@@ -0,0 +1,58 @@
+mod nocover;
+
+use basic_stats::core::Ecdf;
+use nocover::nocover;
+
+#[test]
+fn test_from_iter_with_counts() {
+    // Returns an error in any of these conditions:
+    // - The iterator does not yield data values in strictly increasing order.
+    // - The sample is empty.
+    assert!(Ecdf::from_iter_with_counts([(1., 1), (1., 2)].into_iter()).is_err());
+    assert!(Ecdf::from_iter_with_counts([(2., 1), (1., 2)].into_iter()).is_err());
+    assert!(Ecdf::from_iter_with_counts([(f64::NAN, 1)].into_iter()).is_err());
+    assert!(Ecdf::from_iter_with_counts([].into_iter()).is_err());
+    assert!(Ecdf::from_iter_with_counts([(1., 0), (2., 0)].into_iter()).is_err());
+    if nocover() {
+        assert!(Ecdf::from_iter_with_counts([(1., 0), (2., 1)].into_iter()).is_ok());
+    }
+}
+
+#[test]
+fn test_from_slice() {
+    // Returns an error in any of these conditions:
+    // - `dataset` is empty.
+    // - `dataset` contains a NaN value.
+    assert!(Ecdf::from_slice(&[]).is_err());
+    assert!(Ecdf::from_slice(&[1., f64::NAN, 2.]).is_err());
+    if nocover() {
+        assert!(Ecdf::from_slice(&[2., 1., 2.]).is_ok());
+    }
+}
+
+#[test]
+fn test_quantile() {
+    // Returns an error if `q` is not in the interval `[0, 1]`.
+    let ecdf = Ecdf::from_slice(&[1., 2., 3.]).unwrap();
+    assert!(ecdf.quantile(-0.1).is_err());
+    assert!(ecdf.quantile(1.1).is_err());
+    assert!(ecdf.quantile(f64::NAN).is_err());
+    if nocover() {
+        assert!(ecdf.quantile(0.).is_ok());
+        assert!(ecdf.quantile(1.).is_ok());
+    }
+}
+
+#[test]
+fn test_dkw() {
+    // Returns an error if `alpha` not in open interval `(0, 1)`.
+    let ecdf = Ecdf::from_slice(&[1., 2., 3.]).unwrap();
+    assert!(ecdf.dkw_epsilon(0.).is_err());
+    assert!(ecdf.dkw_epsilon(1.).is_err());
+    assert!(ecdf.dkw_band(2., 0.).is_err());
+    assert!(ecdf.dkw_band(2., 1.).is_err());
+    if nocover() {
+        assert!(ecdf.dkw_epsilon(0.05).is_ok());
+        assert!(ecdf.dkw_band(2., 0.05).is_ok());
+    }
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "jackknife")]
+
+mod nocover;
+
+use basic_stats::{core::SampleMoments, jackknife::*};
+use nocover::nocover;
+
+fn mean(s: &[f64]) -> f64 {
+    s.iter().sum::<f64>() / s.len() as f64
+}
+
+#[test]
+fn test_jackknife_values() {
+    // Returns an error if `dataset.len() < 2`.
+    assert!(jackknife_values(&[], mean).is_err());
+    assert!(jackknife_values(&[1.], mean).is_err());
+    if nocover() {
+        assert!(jackknife_values(&[1., 2.], mean).is_ok());
+    }
+}
+
+#[test]
+fn test_jackknife() {
+    // Returns an error in any of these conditions:
+    // - `dataset.len() < 2`.
+    // - `stat` returns a non-finite value for `dataset` or for any subsample.
+    assert!(jackknife(&[1.], mean).is_err());
+    assert!(jackknife(&[1., 2., 3.], |_| f64::NAN).is_err());
+    assert!(jackknife(&[1., 2., 3.], |s| 1. / (s[0] - 2.)).is_err());
+    if nocover() {
+        assert!(jackknife(&[1., 2., 3.], mean).is_ok());
+    }
+}
+
+#[test]
+fn test_jackknife_moments() {
+    // Returns an error in any of these conditions:
+    // - `dataset.len() < 2`.
+    // - `stat` returns a non-finite value for `dataset` or for any subsample.
+    let mean_m = |m: &SampleMoments| m.mean().unwrap_or(f64::NAN);
+    let var_m = |m: &SampleMoments| m.var().unwrap_or(f64::NAN);
+    assert!(jackknife_moments(&[1.], mean_m).is_err());
+    assert!(jackknife_moments(&[1., 2.], var_m).is_err());
+    if nocover() {
+        assert!(jackknife_moments(&[1., 2.], mean_m).is_ok());
+        assert!(jackknife_moments(&[1., 2., 3.], var_m).is_ok());
+    }
+}
+
+#[test]
+fn test_jackknife_delete_d() {
+    // Returns an error in any of these conditions:
+    // - `d == 0` or `d >= dataset.len()`.
+    // - `max_subsets < 2`.
+    // - `stat` returns a non-finite value for `dataset` or for any subsample.
+    let dat = [1., 4., 2., 8., 5.];
+    assert!(jackknife_delete_d(&dat, mean, 0, 100, 1).is_err());
+    assert!(jackknife_delete_d(&dat, mean, 5, 100, 1).is_err());
+    assert!(jackknife_delete_d(&dat, mean, 2, 1, 1).is_err());
+    assert!(jackknife_delete_d(&dat, |_| f64::INFINITY, 2, 100, 1).is_err());
+    if nocover() {
+        assert!(jackknife_delete_d(&dat, mean, 4, 100, 1).is_ok());
+        assert!(jackknife_delete_d(&dat, mean, 2, 2, 1).is_ok());
+    }
+}
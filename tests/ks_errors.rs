@@ -0,0 +1,77 @@
+#![cfg(feature = "ks")]
+
+mod nocover;
+
+use basic_stats::{core::AltHyp, ks::*};
+use nocover::nocover;
+
+// #[test]
+// fn test_from_iters_with_counts() {
+//     // KsTwoSample::from_iters_with_counts covered by KsTwoSample::from_iters.
+// }
+
+// #[test]
+// fn test_from_iters() {
+//     // KsTwoSample::from_iters(it_x, it_y) covered by KsTwoSample::from_slices
+// }
+
+#[test]
+fn test_from_slices() {
+    // Returns an error if a slice is not sorted in non-decreasing order.
+    let good = [1., 2., 2.];
+    let bad = [1., 2., 1.];
+    assert!(KsTwoSample::from_slices(&bad, &good).is_err());
+    assert!(KsTwoSample::from_slices(&good, &bad).is_err());
+    if nocover() {
+        assert!(KsTwoSample::from_slices(&good, &good).is_ok());
+        assert!(KsTwoSample::from_slices(&[], &[]).is_ok());
+    }
+}
+
+#[test]
+fn test_two_sample_stats() {
+    // Returns an error in any of these conditions:
+    // - `self.n_x == 0` or `self.n_y == 0`.
+    // - `alpha` not in interval `(0, 1)`.
+    let empty_x = KsTwoSample::from_slices(&[], &[1., 2.]).unwrap();
+    let empty_y = KsTwoSample::from_slices(&[1., 2.], &[]).unwrap();
+    let ks = KsTwoSample::from_slices(&[1., 2.], &[1.5]).unwrap();
+    for alt_hyp in [AltHyp::Lt, AltHyp::Gt, AltHyp::Ne] {
+        for bad in [&empty_x, &empty_y] {
+            assert!(bad.d(alt_hyp).is_err());
+            assert!(bad.exact_p(alt_hyp).is_err());
+            assert!(bad.asymptotic_p(alt_hyp).is_err());
+            assert!(bad.p(alt_hyp).is_err());
+            assert!(bad.test(alt_hyp, 0.05).is_err());
+        }
+        assert!(ks.test(alt_hyp, 0.).is_err());
+        assert!(ks.test(alt_hyp, 1.).is_err());
+        if nocover() {
+            assert!(ks.test(alt_hyp, 0.05).is_ok());
+        }
+    }
+}
+
+#[test]
+fn test_one_sample() {
+    // Returns an error in any of these conditions:
+    // - `dataset` is empty.
+    // - `dataset` contains `NaN`.
+    // - `cdf` returns a value that is not in interval `[0, 1]` at a value of `dataset`.
+    // - `alpha` not in interval `(0, 1)`.
+    let cdf = |v: f64| v;
+    let dataset = [0.2, 0.5, 0.9];
+    let alt_hyp = AltHyp::Ne;
+    for bad in [&[][..], &[0.2, f64::NAN], &[0.2, 1.5]] {
+        assert!(ks_1samp_d(bad, cdf, alt_hyp).is_err());
+        assert!(ks_1samp_exact_p(bad, cdf, alt_hyp).is_err());
+        assert!(ks_1samp_asymptotic_p(bad, cdf, alt_hyp).is_err());
+        assert!(ks_1samp_p(bad, cdf, alt_hyp).is_err());
+        assert!(ks_1samp_test(bad, cdf, alt_hyp, 0.05).is_err());
+    }
+    assert!(ks_1samp_test(&dataset, cdf, alt_hyp, 0.).is_err());
+    assert!(ks_1samp_test(&dataset, cdf, alt_hyp, 1.).is_err());
+    if nocover() {
+        assert!(ks_1samp_test(&[0., 1.], cdf, alt_hyp, 0.05).is_ok());
+    }
+}
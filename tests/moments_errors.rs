@@ -1,6 +1,6 @@
 mod nocover;
 
-use basic_stats::core::SampleMoments;
+use basic_stats::core::{HigherMoments, SampleMoments};
 use nocover::nocover;
 
 #[test]
@@ -68,3 +68,27 @@ pub fn test_from_paired_slices() {
         assert!(m11.is_ok());
     }
 }
+
+#[test]
+pub fn test_higher_moments() {
+    let m0 = HigherMoments::new_empty();
+    let m1 = HigherMoments::from_slice(&[1.]);
+    let m_const = HigherMoments::from_slice(&[1., 1., 1.]);
+    let m2 = HigherMoments::from_slice(&[1., 2.]);
+
+    // mean: Returns an error if `n == 0`.
+    assert!(m0.mean().is_err());
+    // var: Returns an error if `n <= 1`.
+    assert!(m1.var().is_err());
+    // skewness, excess_kurtosis: Returns an error if the sample is empty or all its values are equal.
+    assert!(m0.skewness().is_err());
+    assert!(m_const.skewness().is_err());
+    assert!(m0.excess_kurtosis().is_err());
+    assert!(m_const.excess_kurtosis().is_err());
+    if nocover() {
+        assert!(m1.mean().unwrap().is_finite());
+        assert!(m2.var().unwrap().is_finite());
+        assert!(m2.skewness().unwrap().is_finite());
+        assert!(m2.excess_kurtosis().unwrap().is_finite());
+    }
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "multinomial")]
+
+mod nocover;
+
+use basic_stats::multinomial::*;
+use nocover::nocover;
+
+const PROBS: [f64; 3] = [0.2, 0.3, 0.5];
+
+#[test]
+fn test_multinomial_chi2() {
+    // Returns an error in any of these conditions:
+    // - `counts` has fewer than `2` items or its items sum to `0`.
+    // - `counts` and `probs` have different lengths.
+    // - Any item of `probs` is not in interval `(0, 1]`.
+    // - The items of `probs` do not sum to `1`.
+    assert!(multinomial_chi2(&[], &[]).is_err());
+    assert!(multinomial_chi2(&[3], &[1.]).is_err());
+    assert!(multinomial_chi2(&[0, 0, 0], &PROBS).is_err());
+    assert!(multinomial_chi2(&[1, 2], &PROBS).is_err());
+    assert!(multinomial_chi2(&[1, 2, 3], &[0., 0.5, 0.5]).is_err());
+    assert!(multinomial_chi2(&[1, 2, 3], &[-0.1, 0.6, 0.5]).is_err());
+    assert!(multinomial_chi2(&[1, 2, 3], &[0.2, 0.3, 0.6]).is_err());
+    assert!(multinomial_chi2(&[1, 2, 3], &[0.2, 0.3, f64::NAN]).is_err());
+    if nocover() {
+        assert!(multinomial_chi2(&[1, 2, 3], &PROBS).is_ok());
+    }
+}
+
+#[test]
+fn test_multinomial_chi2_df() {
+    // Returns an error if `counts` has fewer than `2` items or its items sum to `0`.
+    assert!(multinomial_chi2_df(&[3]).is_err());
+    assert!(multinomial_chi2_df(&[0, 0]).is_err());
+    if nocover() {
+        assert!(multinomial_chi2_df(&[1, 2, 3]).is_ok());
+    }
+}
+
+#[test]
+fn test_multinomial_chi2_p() {
+    // multinomial_chi2_p(counts, probs) covered by multinomial_chi2.
+}
+
+#[test]
+fn test_multinomial_chi2_test() {
+    // Returns an error in any of these conditions:
+    // - Any of the conditions for `multinomial_chi2`.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(multinomial_chi2_test(&[1, 2], &PROBS, 0.5).is_err());
+    assert!(multinomial_chi2_test(&[1, 2, 3], &PROBS, 0.).is_err());
+    assert!(multinomial_chi2_test(&[1, 2, 3], &PROBS, 1.).is_err());
+    if nocover() {
+        assert!(multinomial_chi2_test(&[1, 2, 3], &PROBS, 0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_exact_multinomial_p() {
+    // Returns an error in any of these conditions:
+    // - `counts` has fewer than `2` items or its items sum to `0`.
+    // - `counts` and `probs` have different lengths.
+    // - Any item of `probs` is not in interval `[0, 1]`.
+    // - The items of `probs` do not sum to `1`.
+    // - The number of outcomes exceeds `EXACT_MULTINOMIAL_MAX_OUTCOMES`.
+    assert!(exact_multinomial_p(&[3], &[1.]).is_err());
+    assert!(exact_multinomial_p(&[0, 0, 0], &PROBS).is_err());
+    assert!(exact_multinomial_p(&[1, 2], &PROBS).is_err());
+    assert!(exact_multinomial_p(&[1, 2, 3], &[-0.1, 0.6, 0.5]).is_err());
+    assert!(exact_multinomial_p(&[1, 2, 3], &[0.2, 0.3, 0.6]).is_err());
+    assert!(exact_multinomial_p(&[100; 6], &[1. / 6.; 6]).is_err());
+    assert!(exact_multinomial_p(&[u64::MAX / 2, 1], &[0.5, 0.5]).is_err());
+    if nocover() {
+        assert!(exact_multinomial_p(&[30, 30, 30], &[1. / 3.; 3]).is_ok());
+        assert!(exact_multinomial_p(&[u64::MAX / 2, 0], &[1., 0.]).is_ok());
+        assert!(exact_multinomial_p(&[1, 2, 3], &[0., 0.5, 0.5]).is_ok());
+        assert!(exact_multinomial_p(&[1, 2, 3], &[0., 0., 1.]).is_ok());
+    }
+}
+
+#[test]
+fn test_exact_multinomial_test() {
+    // Returns an error in any of these conditions:
+    // - Any of the conditions for `exact_multinomial_p`.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(exact_multinomial_test(&[1, 2], &PROBS, 0.5).is_err());
+    assert!(exact_multinomial_test(&[1, 2, 3], &PROBS, 0.).is_err());
+    assert!(exact_multinomial_test(&[1, 2, 3], &PROBS, 1.).is_err());
+    if nocover() {
+        assert!(exact_multinomial_test(&[1, 2, 3], &PROBS, 0.5).is_ok());
+    }
+}
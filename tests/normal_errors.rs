@@ -0,0 +1,129 @@
+#![cfg(feature = "normal")]
+
+mod nocover;
+
+use basic_stats::{
+    core::AltHyp,
+    normal::{TTestDesign, t_test_max_sd, t_test_min_delta, t_test_power, t_test_sample_size},
+};
+use nocover::nocover;
+
+const BAD_WELCH: TTestDesign = TTestDesign::Welch { sd_ratio: 0. };
+
+#[test]
+fn test_t_test_power() {
+    // Returns an error in any of these conditions:
+    // - `design` is `TTestDesign::Welch` with `sd_ratio` not finite and positive.
+    // - `n < 2`.
+    // - `delta` is not finite.
+    // - `sd` is not finite and positive.
+    // - `alpha` is not in interval `(0, 1)`.
+    use TTestDesign::*;
+    assert!(t_test_power(BAD_WELCH, 10, 1., 1., AltHyp::Ne, 0.5).is_err());
+    assert!(
+        t_test_power(
+            Welch {
+                sd_ratio: f64::INFINITY
+            },
+            10,
+            1.,
+            1.,
+            AltHyp::Ne,
+            0.5
+        )
+        .is_err()
+    );
+    assert!(t_test_power(OneSample, 1, 1., 1., AltHyp::Ne, 0.5).is_err());
+    assert!(t_test_power(OneSample, 10, f64::NAN, 1., AltHyp::Ne, 0.5).is_err());
+    assert!(t_test_power(OneSample, 10, 1., 0., AltHyp::Ne, 0.5).is_err());
+    assert!(t_test_power(OneSample, 10, 1., 1., AltHyp::Ne, 0.).is_err());
+    assert!(t_test_power(OneSample, 10, 1., 1., AltHyp::Gt, 1.).is_err());
+    if nocover() {
+        assert!(
+            t_test_power(Welch { sd_ratio: 1. }, 2, 0., 1., AltHyp::Ne, 0.5)
+                .unwrap()
+                .is_finite()
+        );
+    }
+}
+
+#[test]
+fn test_t_test_sample_size() {
+    // Returns an error in any of these conditions:
+    // - `design` is `TTestDesign::Welch` with `sd_ratio` not finite and positive.
+    // - `delta` is not finite.
+    // - `sd` is not finite and positive.
+    // - `alpha` is not in interval `(0, 1)`.
+    // - `power` is not in interval `(0, 1)`.
+    // - The target is unattainable: `delta >= 0` for `AltHyp::Lt`, `delta <= 0` for `AltHyp::Gt`, or
+    //   `delta == 0` for `AltHyp::Ne`.
+    // - The required sample size exceeds `u64::MAX`.
+    use TTestDesign::*;
+    assert!(t_test_sample_size(BAD_WELCH, 1., 1., AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(t_test_sample_size(Pooled, f64::INFINITY, 1., AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(t_test_sample_size(Pooled, 1., -1., AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(t_test_sample_size(Pooled, 1., 1., AltHyp::Ne, 1., 0.8).is_err());
+    assert!(t_test_sample_size(Pooled, 1., 1., AltHyp::Ne, 0.5, 0.).is_err());
+    assert!(t_test_sample_size(Pooled, 1., 1., AltHyp::Ne, 0.5, 1.).is_err());
+    assert!(t_test_sample_size(Pooled, 0., 1., AltHyp::Lt, 0.5, 0.8).is_err());
+    assert!(t_test_sample_size(Pooled, 0., 1., AltHyp::Gt, 0.5, 0.8).is_err());
+    assert!(t_test_sample_size(Pooled, 0., 1., AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(t_test_sample_size(Pooled, 1e-12, 1., AltHyp::Ne, 0.5, 0.8).is_err());
+    if nocover() {
+        assert!(t_test_sample_size(Pooled, 1., 1., AltHyp::Ne, 0.5, 0.8).is_ok());
+    }
+}
+
+#[test]
+fn test_t_test_min_delta() {
+    // Returns an error in any of these conditions:
+    // - `design` is `TTestDesign::Welch` with `sd_ratio` not finite and positive.
+    // - `n < 2`.
+    // - `sd` is not finite and positive.
+    // - `alpha` is not in interval `(0, 1)`.
+    // - `power` is not in interval `(0, 1)`.
+    // - The target is unattainable: `power <= alpha`.
+    use TTestDesign::*;
+    assert!(t_test_min_delta(BAD_WELCH, 10, 1., AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(t_test_min_delta(Paired, 0, 1., AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(t_test_min_delta(Paired, 10, f64::NAN, AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(t_test_min_delta(Paired, 10, 1., AltHyp::Ne, 0., 0.8).is_err());
+    assert!(t_test_min_delta(Paired, 10, 1., AltHyp::Ne, 0.5, 1.).is_err());
+    assert!(t_test_min_delta(Paired, 10, 1., AltHyp::Ne, 0.5, 0.5).is_err());
+    if nocover() {
+        assert!(
+            t_test_min_delta(Paired, 10, 1., AltHyp::Ne, 0.5, 0.8)
+                .unwrap()
+                .is_finite()
+        );
+    }
+}
+
+#[test]
+fn test_t_test_max_sd() {
+    // Returns an error in any of these conditions:
+    // - `design` is `TTestDesign::Welch` with `sd_ratio` not finite and positive.
+    // - `n < 2`.
+    // - `delta` is not finite.
+    // - `alpha` is not in interval `(0, 1)`.
+    // - `power` is not in interval `(0, 1)`.
+    // - The target is unattainable: `power <= alpha`, `delta >= 0` for `AltHyp::Lt`, `delta <= 0` for
+    //   `AltHyp::Gt`, or `delta == 0` for `AltHyp::Ne`.
+    use TTestDesign::*;
+    assert!(t_test_max_sd(BAD_WELCH, 10, 1., AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(t_test_max_sd(OneSample, 1, 1., AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(t_test_max_sd(OneSample, 10, f64::NAN, AltHyp::Ne, 0.5, 0.8).is_err());
+    assert!(t_test_max_sd(OneSample, 10, 1., AltHyp::Ne, 1., 0.8).is_err());
+    assert!(t_test_max_sd(OneSample, 10, 1., AltHyp::Ne, 0.5, 0.).is_err());
+    assert!(t_test_max_sd(OneSample, 10, 1., AltHyp::Ne, 0.5, 0.4).is_err());
+    assert!(t_test_max_sd(OneSample, 10, 1., AltHyp::Lt, 0.5, 0.8).is_err());
+    assert!(t_test_max_sd(OneSample, 10, -1., AltHyp::Gt, 0.5, 0.8).is_err());
+    assert!(t_test_max_sd(OneSample, 10, 0., AltHyp::Ne, 0.5, 0.8).is_err());
+    if nocover() {
+        assert!(
+            t_test_max_sd(OneSample, 10, 1., AltHyp::Ne, 0.5, 0.8)
+                .unwrap()
+                .is_finite()
+        );
+    }
+}
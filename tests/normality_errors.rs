@@ -0,0 +1,84 @@
+#![cfg(feature = "normality")]
+
+mod nocover;
+
+use basic_stats::{core::HigherMoments, normality::*};
+use nocover::nocover;
+
+#[test]
+fn test_shapiro_wilk() {
+    // Returns an error in any of these conditions:
+    // - `dataset.len()` is not in interval `[3, 5000]`.
+    // - `dataset` contains a value that is not finite.
+    // - All values of `dataset` are identical.
+    // - `alpha` not in interval `(0, 1)`.
+    let dataset = [1., 2., 4., 8.];
+    let large: Vec<f64> = (0..5001).map(|i| i as f64).collect();
+    assert!(shapiro_wilk_w(&[1., 2.]).is_err());
+    assert!(shapiro_wilk_w(&large).is_err());
+    assert!(shapiro_wilk_w(&[1., 2., f64::NAN]).is_err());
+    assert!(shapiro_wilk_w(&[1., f64::INFINITY, 3.]).is_err());
+    assert!(shapiro_wilk_w(&[2., 2., 2.]).is_err());
+    assert!(shapiro_wilk_p(&[1., 2.]).is_err());
+    assert!(shapiro_wilk_p(&[2., 2., 2.]).is_err());
+    assert!(shapiro_wilk_test(&[1., 2.], 0.05).is_err());
+    assert!(shapiro_wilk_test(&dataset, 0.).is_err());
+    assert!(shapiro_wilk_test(&dataset, 1.).is_err());
+    if nocover() {
+        assert!(shapiro_wilk_w(&[1., 2., 2.]).is_ok());
+        assert!(shapiro_wilk_w(&large[..5000]).is_ok());
+        assert!(shapiro_wilk_test(&dataset, 0.05).is_ok());
+    }
+}
+
+#[test]
+fn test_anderson_darling_and_cramer_von_mises() {
+    // Returns an error in any of these conditions:
+    // - `dataset.len() < 8`.
+    // - `dataset` contains a value that is not finite.
+    // - All values of `dataset` are identical.
+    // - `alpha` not in interval `(0, 1)`.
+    let dataset = [1., 2., 4., 8., 3., 5., 7., 6.];
+    let bads: [&[f64]; 3] = [
+        &[1., 2., 4., 8., 3., 5., 7.],
+        &[1., 2., 4., 8., 3., 5., 7., f64::NAN],
+        &[2.; 8],
+    ];
+    for bad in bads {
+        assert!(anderson_darling_a2(bad).is_err());
+        assert!(anderson_darling_p(bad).is_err());
+        assert!(anderson_darling_test(bad, 0.05).is_err());
+        assert!(cramer_von_mises_w2(bad).is_err());
+        assert!(cramer_von_mises_p(bad).is_err());
+        assert!(cramer_von_mises_test(bad, 0.05).is_err());
+    }
+    assert!(anderson_darling_test(&dataset, 0.).is_err());
+    assert!(anderson_darling_test(&dataset, 1.).is_err());
+    assert!(cramer_von_mises_test(&dataset, 0.).is_err());
+    assert!(cramer_von_mises_test(&dataset, 1.).is_err());
+    if nocover() {
+        assert!(anderson_darling_test(&dataset, 0.05).is_ok());
+        assert!(cramer_von_mises_test(&dataset, 0.05).is_ok());
+    }
+}
+
+#[test]
+fn test_jarque_bera() {
+    // Returns an error in any of these conditions:
+    // - The sample is empty or all its values are equal.
+    // - `alpha` not in interval `(0, 1)`.
+    let moments = HigherMoments::from_slice(&[1., 2., 4.]);
+    for bad in [
+        HigherMoments::new_empty(),
+        HigherMoments::from_slice(&[3., 3.]),
+    ] {
+        assert!(jarque_bera_jb(&bad).is_err());
+        assert!(jarque_bera_p(&bad).is_err());
+        assert!(jarque_bera_test(&bad, 0.05).is_err());
+    }
+    assert!(jarque_bera_test(&moments, 0.).is_err());
+    assert!(jarque_bera_test(&moments, 1.).is_err());
+    if nocover() {
+        assert!(jarque_bera_test(&HigherMoments::from_slice(&[1., 2.]), 0.05).is_ok());
+    }
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "outliers")]
+
+mod nocover;
+
+use basic_stats::{core::AltHyp, outliers::*};
+use nocover::nocover;
+
+const ALPHA: f64 = 0.05;
+
+const DAT: [f64; 8] = [3., 1., 4., 1., 5., 9., 2., 6.];
+
+#[test]
+fn test_tukey() {
+    // Returns an error in any of these conditions:
+    // - `dataset` is empty.
+    // - `dataset` contains a `NaN` value.
+    // - `k` is negative or `NaN`.
+    for (dataset, k) in [
+        (&[][..], TUKEY_K_OUTSIDE),
+        (&[1., f64::NAN][..], TUKEY_K_OUTSIDE),
+        (&DAT[..], -1.),
+        (&DAT[..], f64::NAN),
+    ] {
+        assert!(tukey_fences(dataset, k).is_err());
+        assert!(tukey_outliers(dataset, k).is_err());
+    }
+    if nocover() {
+        assert!(tukey_fences(&[1.], 0.).is_ok());
+        assert!(tukey_outliers(&[1.], 0.).is_ok());
+    }
+}
+
+#[test]
+fn test_modified_z() {
+    // Returns an error in any of these conditions:
+    // - `dataset` is empty.
+    // - `dataset` contains a `NaN` value.
+    // - The median absolute deviation is zero.
+    // - `threshold` is negative or `NaN` (for `modified_z_outliers`).
+    for dataset in [&[][..], &[1., f64::NAN, 3.][..], &[1., 1., 1., 5.][..]] {
+        assert!(modified_z_scores(dataset).is_err());
+        assert!(modified_z_outliers(dataset, MODIFIED_Z_THRESHOLD).is_err());
+    }
+    assert!(modified_z_outliers(&DAT, -1.).is_err());
+    assert!(modified_z_outliers(&DAT, f64::NAN).is_err());
+    if nocover() {
+        assert!(modified_z_scores(&DAT).is_ok());
+        assert!(modified_z_outliers(&DAT, 0.).is_ok());
+    }
+}
+
+#[test]
+fn test_grubbs() {
+    // Returns an error in any of these conditions:
+    // - `dataset.len() < 3` (`n < 3` for `grubbs_g_crit`).
+    // - `dataset` contains a `NaN` value.
+    // - The sample variance is zero.
+    // - `alpha` not in open interval `(0, 1)` (for `grubbs_g_crit`, `grubbs_test`, and `grubbs_outliers`).
+    // - `max_outliers` not in interval `[1, dataset.len() - 2]` (for `grubbs_outliers`).
+    for alt_hyp in [AltHyp::Lt, AltHyp::Gt, AltHyp::Ne] {
+        for dataset in [&[1., 2.][..], &[1., f64::NAN, 3.][..], &[2., 2., 2.][..]] {
+            assert!(grubbs_g(dataset, alt_hyp).is_err());
+            assert!(grubbs_p(dataset, alt_hyp).is_err());
+            assert!(grubbs_test(dataset, alt_hyp, ALPHA).is_err());
+            assert!(grubbs_outliers(dataset, alt_hyp, ALPHA, 1).is_err());
+        }
+        assert!(grubbs_g_crit(2, alt_hyp, ALPHA).is_err());
+        for alpha in [0., 1.] {
+            assert!(grubbs_g_crit(8, alt_hyp, alpha).is_err());
+            assert!(grubbs_test(&DAT, alt_hyp, alpha).is_err());
+            assert!(grubbs_outliers(&DAT, alt_hyp, alpha, 1).is_err());
+        }
+        assert!(grubbs_outliers(&DAT, alt_hyp, ALPHA, 0).is_err());
+        assert!(grubbs_outliers(&DAT, alt_hyp, ALPHA, 7).is_err());
+    }
+    if nocover() {
+        assert!(grubbs_g(&[1., 2., 3.], AltHyp::Ne).is_ok());
+        assert!(grubbs_g_crit(3, AltHyp::Ne, ALPHA).is_ok());
+        assert!(grubbs_test(&DAT, AltHyp::Gt, ALPHA).is_ok());
+        assert!(grubbs_outliers(&DAT, AltHyp::Ne, ALPHA, 6).is_ok());
+    }
+}
+
+#[test]
+fn test_generalized_esd() {
+    // Returns an error in any of these conditions:
+    // - `dataset.len() < 3`.
+    // - `dataset` contains a `NaN` value.
+    // - The sample variance is zero.
+    // - `alpha` not in open interval `(0, 1)`.
+    // - `max_outliers` not in interval `[1, dataset.len() - 2]`.
+    assert!(generalized_esd_outliers(&[1., 2.], ALPHA, 1).is_err());
+    assert!(generalized_esd_outliers(&[1., f64::NAN, 3.], ALPHA, 1).is_err());
+    assert!(generalized_esd_outliers(&[2., 2., 2.], ALPHA, 1).is_err());
+    assert!(generalized_esd_outliers(&DAT, 0., 1).is_err());
+    assert!(generalized_esd_outliers(&DAT, 1., 1).is_err());
+    assert!(generalized_esd_outliers(&DAT, ALPHA, 0).is_err());
+    assert!(generalized_esd_outliers(&DAT, ALPHA, 7).is_err());
+    if nocover() {
+        assert!(generalized_esd_outliers(&[1., 2., 3.], ALPHA, 1).is_ok());
+        assert!(generalized_esd_outliers(&DAT, ALPHA, 6).is_ok());
+    }
+}
+
+#[test]
+fn test_dixon() {
+    // Returns an error in any of these conditions:
+    // - `dataset.len()` not in interval `[3, 10]` (`n` for `dixon_q_crit`).
+    // - `dataset` contains a `NaN` value.
+    // - The sample range is zero.
+    // - `alpha` is not `0.1`, `0.05`, or `0.01` (for `dixon_q_crit` and `dixon_outliers`).
+    let long = [1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11.];
+    for dataset in [
+        &[1., 2.][..],
+        &long[..],
+        &[1., f64::NAN, 3.][..],
+        &[2., 2., 2.][..],
+    ] {
+        assert!(dixon_q(dataset).is_err());
+        assert!(dixon_outliers(dataset, ALPHA).is_err());
+    }
+    assert!(dixon_q_crit(2, ALPHA).is_err());
+    assert!(dixon_q_crit(11, ALPHA).is_err());
+    assert!(dixon_q_crit(5, 0.2).is_err());
+    assert!(dixon_outliers(&DAT, 0.2).is_err());
+    if nocover() {
+        assert!(dixon_q(&[1., 2., 3.]).is_ok());
+        assert!(dixon_q_crit(10, 0.01).is_ok());
+        assert!(dixon_outliers(&long[..10], 0.1).is_ok());
+    }
+}
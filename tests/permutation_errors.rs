@@ -0,0 +1,50 @@
+#![cfg(feature = "permutation")]
+
+mod nocover;
+
+use basic_stats::{core::AltHyp, permutation::*};
+use nocover::nocover;
+
+const ALPHA: f64 = 0.05;
+
+#[test]
+fn test_permutation_p() {
+    // Returns an error in any of these conditions:
+    // - `x` or `y` is empty.
+    // - `n_resamples == 0`.
+    // - `stat` returns NaN for the samples.
+    let x = [1., 4., 2., 8.];
+    let y = [3., 2., 6.];
+    for alt_hyp in [AltHyp::Lt, AltHyp::Gt, AltHyp::Ne] {
+        assert!(permutation_p(&[], &y, mean_diff, alt_hyp, 100, 1).is_err());
+        assert!(permutation_p(&x, &[], mean_diff, alt_hyp, 100, 1).is_err());
+        assert!(permutation_p(&x, &y, mean_diff, alt_hyp, 0, 1).is_err());
+        assert!(permutation_p(&x, &y, |_, _| f64::NAN, alt_hyp, 100, 1).is_err());
+        if nocover() {
+            assert!(permutation_p(&x, &y, mean_diff, alt_hyp, 100, 1).is_ok());
+            assert!(permutation_p(&x, &y, mean_diff, alt_hyp, 10, 1).is_ok());
+        }
+    }
+}
+
+#[test]
+fn test_permutation_test() {
+    // Returns an error in any of these conditions:
+    // - `alpha` not in open interval `(0, 1)`.
+    // - `x` or `y` is empty.
+    // - `n_resamples == 0`.
+    // - `stat` returns NaN for the samples.
+    let x = [1., 4., 2., 8.];
+    let y = [3., 2., 6.];
+    for alt_hyp in [AltHyp::Lt, AltHyp::Gt, AltHyp::Ne] {
+        assert!(permutation_test(&x, &y, mean_diff, alt_hyp, 100, 1, 0.).is_err());
+        assert!(permutation_test(&x, &y, mean_diff, alt_hyp, 100, 1, 1.).is_err());
+        assert!(permutation_test(&[], &y, mean_diff, alt_hyp, 100, 1, ALPHA).is_err());
+        assert!(permutation_test(&x, &[], mean_diff, alt_hyp, 100, 1, ALPHA).is_err());
+        assert!(permutation_test(&x, &y, mean_diff, alt_hyp, 0, 1, ALPHA).is_err());
+        assert!(permutation_test(&x, &y, |_, _| f64::NAN, alt_hyp, 100, 1, ALPHA).is_err());
+        if nocover() {
+            assert!(permutation_test(&x, &y, mean_diff, alt_hyp, 100, 1, ALPHA).is_ok());
+        }
+    }
+}
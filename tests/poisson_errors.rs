@@ -0,0 +1,189 @@
+#![cfg(feature = "poisson")]
+
+mod nocover;
+
+use basic_stats::{
+    core::{AltHyp, Ci},
+    poisson::*,
+};
+use nocover::nocover;
+
+#[test]
+fn test_poisson_rate_hat() {
+    // Returns an error if `exposure` is not finite and positive.
+    assert!(poisson_rate_hat(3, 0.).is_err());
+    assert!(poisson_rate_hat(3, -1.).is_err());
+    assert!(poisson_rate_hat(3, f64::INFINITY).is_err());
+    assert!(poisson_rate_hat(3, f64::NAN).is_err());
+    if nocover() {
+        assert!(poisson_rate_hat(3, 1.).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_poisson_garwood_alt_hyp_ci() {
+    // poisson_garwood_alt_hyp_ci(count, exposure, alt_hyp, alpha) covered by poisson_garwood_ci.
+}
+
+#[test]
+fn test_poisson_garwood_ci() {
+    // Returns an error in any of these conditions:
+    // - `exposure` is not finite and positive.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(poisson_garwood_ci(3, 0., 0.5).is_err());
+    assert!(poisson_garwood_ci(3, f64::INFINITY, 0.5).is_err());
+    assert!(poisson_garwood_ci(3, 1., 0.).is_err());
+    assert!(poisson_garwood_ci(3, 1., 1.).is_err());
+    if nocover() {
+        let Ci(lo, hi) = poisson_garwood_ci(3, 1., 0.5).unwrap();
+        assert!(lo.is_finite());
+        assert!(hi.is_finite());
+    }
+}
+
+#[test]
+fn test_poisson_wald_alt_hyp_ci() {
+    // poisson_wald_alt_hyp_ci(count, exposure, alt_hyp, alpha) covered by poisson_wald_ci.
+}
+
+#[test]
+fn test_poisson_wald_ci() {
+    // Returns an error in any of these conditions:
+    // - `exposure` is not finite and positive.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(poisson_wald_ci(3, 0., 0.5).is_err());
+    assert!(poisson_wald_ci(3, f64::INFINITY, 0.5).is_err());
+    assert!(poisson_wald_ci(3, 1., 0.).is_err());
+    assert!(poisson_wald_ci(3, 1., 1.).is_err());
+    if nocover() {
+        let Ci(lo, hi) = poisson_wald_ci(3, 1., 0.5).unwrap();
+        assert!(lo.is_finite());
+        assert!(hi.is_finite());
+    }
+}
+
+#[test]
+fn test_poisson_score_alt_hyp_ci() {
+    // poisson_score_alt_hyp_ci(count, exposure, alt_hyp, alpha) covered by poisson_score_ci.
+}
+
+#[test]
+fn test_poisson_score_ci() {
+    // Returns an error in any of these conditions:
+    // - `exposure` is not finite and positive.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(poisson_score_ci(3, 0., 0.5).is_err());
+    assert!(poisson_score_ci(3, f64::INFINITY, 0.5).is_err());
+    assert!(poisson_score_ci(3, 1., 0.).is_err());
+    assert!(poisson_score_ci(3, 1., 1.).is_err());
+    if nocover() {
+        let Ci(lo, hi) = poisson_score_ci(3, 1., 0.5).unwrap();
+        assert!(lo.is_finite());
+        assert!(hi.is_finite());
+    }
+}
+
+#[test]
+fn test_exact_poisson_p() {
+    // Returns an error in any of these conditions:
+    // - `exposure` is not finite and positive.
+    // - `rate0` is not finite and positive.
+    assert!(exact_poisson_p(3, 0., 1., AltHyp::Ne).is_err());
+    assert!(exact_poisson_p(3, f64::NAN, 1., AltHyp::Ne).is_err());
+    assert!(exact_poisson_p(3, 1., 0., AltHyp::Ne).is_err());
+    assert!(exact_poisson_p(3, 1., f64::INFINITY, AltHyp::Ne).is_err());
+    if nocover() {
+        assert!(exact_poisson_p(3, 1., 1., AltHyp::Ne).unwrap().is_finite());
+        assert!(exact_poisson_p(0, 1., 1., AltHyp::Lt).unwrap().is_finite());
+        assert!(exact_poisson_p(0, 1., 1., AltHyp::Gt).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_exact_poisson_test() {
+    // Returns an error in any of these conditions:
+    // - `exposure` is not finite and positive.
+    // - `rate0` is not finite and positive.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(exact_poisson_test(3, 0., 1., AltHyp::Ne, 0.5).is_err());
+    assert!(exact_poisson_test(3, 1., -1., AltHyp::Ne, 0.5).is_err());
+    assert!(exact_poisson_test(3, 1., 1., AltHyp::Ne, 0.).is_err());
+    assert!(exact_poisson_test(3, 1., 1., AltHyp::Ne, 1.).is_err());
+    if nocover() {
+        assert!(exact_poisson_test(3, 1., 1., AltHyp::Ne, 0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_poisson_rate_ratio_hat() {
+    // Returns an error in any of these conditions:
+    // - `exposure_x` or `exposure_y` is not finite and positive.
+    // - `count_x + count_y == 0`.
+    assert!(poisson_rate_ratio_hat(3, 0., 4, 1.).is_err());
+    assert!(poisson_rate_ratio_hat(3, 1., 4, f64::INFINITY).is_err());
+    assert!(poisson_rate_ratio_hat(0, 1., 0, 1.).is_err());
+    if nocover() {
+        assert!(poisson_rate_ratio_hat(3, 1., 4, 1.).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_poisson_rate_ratio_p() {
+    // Returns an error in any of these conditions:
+    // - `exposure_x` or `exposure_y` is not finite and positive.
+    // - `count_x + count_y == 0`.
+    // - `ratio0` is not finite and positive.
+    assert!(poisson_rate_ratio_p(3, -1., 4, 1., 1., AltHyp::Ne).is_err());
+    assert!(poisson_rate_ratio_p(3, 1., 4, 0., 1., AltHyp::Ne).is_err());
+    assert!(poisson_rate_ratio_p(0, 1., 0, 1., 1., AltHyp::Ne).is_err());
+    assert!(poisson_rate_ratio_p(3, 1., 4, 1., 0., AltHyp::Ne).is_err());
+    assert!(poisson_rate_ratio_p(3, 1., 4, 1., f64::NAN, AltHyp::Ne).is_err());
+    if nocover() {
+        assert!(
+            poisson_rate_ratio_p(3, 1., 4, 1., 1., AltHyp::Ne)
+                .unwrap()
+                .is_finite()
+        );
+    }
+}
+
+#[test]
+fn test_poisson_rate_ratio_test() {
+    // Returns an error in any of these conditions:
+    // - `exposure_x` or `exposure_y` is not finite and positive.
+    // - `count_x + count_y == 0`.
+    // - `ratio0` is not finite and positive.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(poisson_rate_ratio_test(3, 0., 4, 1., 1., AltHyp::Ne, 0.5).is_err());
+    assert!(poisson_rate_ratio_test(0, 1., 0, 1., 1., AltHyp::Ne, 0.5).is_err());
+    assert!(poisson_rate_ratio_test(3, 1., 4, 1., -1., AltHyp::Ne, 0.5).is_err());
+    assert!(poisson_rate_ratio_test(3, 1., 4, 1., 1., AltHyp::Ne, 0.).is_err());
+    assert!(poisson_rate_ratio_test(3, 1., 4, 1., 1., AltHyp::Ne, 1.).is_err());
+    if nocover() {
+        assert!(poisson_rate_ratio_test(3, 1., 4, 1., 1., AltHyp::Ne, 0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_poisson_rate_ratio_alt_hyp_ci() {
+    // poisson_rate_ratio_alt_hyp_ci(count_x, exposure_x, count_y, exposure_y, alt_hyp, alpha) covered by
+    // poisson_rate_ratio_ci.
+}
+
+#[test]
+fn test_poisson_rate_ratio_ci() {
+    // Returns an error in any of these conditions:
+    // - `exposure_x` or `exposure_y` is not finite and positive.
+    // - `count_x + count_y == 0`.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(poisson_rate_ratio_ci(3, 0., 4, 1., 0.5).is_err());
+    assert!(poisson_rate_ratio_ci(3, 1., 4, f64::NAN, 0.5).is_err());
+    assert!(poisson_rate_ratio_ci(0, 1., 0, 1., 0.5).is_err());
+    assert!(poisson_rate_ratio_ci(3, 1., 4, 1., 0.).is_err());
+    assert!(poisson_rate_ratio_ci(3, 1., 4, 1., 1.).is_err());
+    if nocover() {
+        let Ci(lo, hi) = poisson_rate_ratio_ci(3, 1., 4, 1., 0.5).unwrap();
+        assert!(lo.is_finite());
+        assert!(hi.is_finite());
+    }
+}
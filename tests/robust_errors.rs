@@ -0,0 +1,171 @@
+#![cfg(feature = "robust")]
+
+mod nocover;
+
+use basic_stats::{core::AltHyp, robust::*};
+use nocover::nocover;
+
+const ALPHA: f64 = 0.05;
+
+const DAT: [f64; 8] = [3., 1., 4., 1., 5., 9., 2., 6.];
+
+#[test]
+fn test_median_mad() {
+    // Returns an error in any of these conditions:
+    // - `dataset` is empty.
+    // - `dataset` contains a `NaN` value.
+    assert!(median(&[]).is_err());
+    assert!(median(&[1., f64::NAN]).is_err());
+    assert!(mad(&[], MAD_NORMAL_CONSTANT).is_err());
+    assert!(mad(&[1., f64::NAN], MAD_NORMAL_CONSTANT).is_err());
+    if nocover() {
+        assert!(median(&[1.]).is_ok());
+        assert!(mad(&[1.], MAD_NORMAL_CONSTANT).is_ok());
+    }
+}
+
+#[test]
+fn test_trimmed_winsorized() {
+    // Returns an error in any of these conditions:
+    // - `dataset` is empty (`dataset.len() < 2` for `winsorized_var`).
+    // - `dataset` contains a `NaN` value.
+    // - `trim` not in interval `[0, 0.5)`.
+    for trim in [-0.1, 0.5, f64::NAN] {
+        assert!(trimmed_mean(&DAT, trim).is_err());
+        assert!(winsorized_mean(&DAT, trim).is_err());
+        assert!(winsorized_var(&DAT, trim).is_err());
+    }
+    assert!(trimmed_mean(&[], 0.2).is_err());
+    assert!(winsorized_mean(&[], 0.2).is_err());
+    assert!(winsorized_var(&[1.], 0.2).is_err());
+    assert!(trimmed_mean(&[1., f64::NAN], 0.2).is_err());
+    assert!(winsorized_mean(&[1., f64::NAN], 0.2).is_err());
+    assert!(winsorized_var(&[1., f64::NAN], 0.2).is_err());
+    if nocover() {
+        assert!(trimmed_mean(&[1.], 0.49).is_ok());
+        assert!(winsorized_mean(&[1.], 0.).is_ok());
+        assert!(winsorized_var(&[1., 2.], 0.).is_ok());
+    }
+}
+
+#[test]
+fn test_trimmed_mean_se_ci() {
+    // Returns an error in any of these conditions:
+    // - `dataset` contains a `NaN` value.
+    // - `trim` not in interval `[0, 0.5)`.
+    // - Fewer than `2` items remain after trimming.
+    // - `alpha` not in open interval `(0, 1)` (for `trimmed_mean_ci`).
+    assert!(trimmed_mean_se(&[1., f64::NAN, 3.], 0.2).is_err());
+    assert!(trimmed_mean_se(&DAT, 0.5).is_err());
+    assert!(trimmed_mean_se(&[1., 2., 3.], 0.4).is_err());
+    assert!(trimmed_mean_se(&[1.], 0.).is_err());
+    assert!(trimmed_mean_ci(&[1., f64::NAN, 3.], 0.2, ALPHA).is_err());
+    assert!(trimmed_mean_ci(&DAT, 0.5, ALPHA).is_err());
+    assert!(trimmed_mean_ci(&[1., 2., 3.], 0.4, ALPHA).is_err());
+    assert!(trimmed_mean_ci(&DAT, 0.2, 0.).is_err());
+    assert!(trimmed_mean_ci(&DAT, 0.2, 1.).is_err());
+    if nocover() {
+        assert!(trimmed_mean_se(&[1., 2.], 0.).is_ok());
+        assert!(trimmed_mean_ci(&[1., 2., 3., 4.], 0.25, ALPHA).is_ok());
+    }
+}
+
+#[test]
+fn test_median_ci() {
+    // Returns an error in any of these conditions:
+    // - `dataset` is empty.
+    // - `dataset` contains a `NaN` value.
+    // - `alpha` not in open interval `(0, 1)`.
+    // - The sample is too small for the confidence level.
+    assert!(median_ci(&[], ALPHA).is_err());
+    assert!(median_ci(&[1., f64::NAN, 3., 4., 5., 6.], ALPHA).is_err());
+    assert!(median_ci(&DAT, 0.).is_err());
+    assert!(median_ci(&DAT, 1.).is_err());
+    assert!(median_ci(&[1., 2., 3., 4., 5.], ALPHA).is_err());
+    if nocover() {
+        assert!(median_ci(&[1., 2., 3., 4., 5., 6.], ALPHA).is_ok());
+    }
+}
+
+#[test]
+fn test_qn_sn() {
+    // Returns an error in any of these conditions:
+    // - `dataset.len() < 2`.
+    // - `dataset` contains a `NaN` value.
+    assert!(qn(&[1.]).is_err());
+    assert!(sn(&[1.]).is_err());
+    assert!(qn(&[1., f64::NAN]).is_err());
+    assert!(sn(&[1., f64::NAN]).is_err());
+    if nocover() {
+        assert!(qn(&[1., 2.]).is_ok());
+        assert!(sn(&[1., 2.]).is_ok());
+    }
+}
+
+#[test]
+fn test_huber() {
+    // Returns an error in any of these conditions:
+    // - `dataset` is empty.
+    // - `dataset` contains a `NaN` value.
+    // - `k` is not positive and finite.
+    // - The median absolute deviation of `dataset` is zero.
+    // - `alpha` not in open interval `(0, 1)` (for `huber_location_ci`).
+    assert!(huber_location(&[], HUBER_K_95).is_err());
+    assert!(huber_location(&[1., f64::NAN, 3.], HUBER_K_95).is_err());
+    for k in [0., -1., f64::INFINITY, f64::NAN] {
+        assert!(huber_location(&DAT, k).is_err());
+        assert!(huber_location_ci(&DAT, k, ALPHA).is_err());
+    }
+    assert!(huber_location(&[1., 1., 1., 5.], HUBER_K_95).is_err());
+    assert!(huber_location_ci(&[1., 1., 1., 5.], HUBER_K_95, ALPHA).is_err());
+    assert!(huber_location_ci(&DAT, HUBER_K_95, 0.).is_err());
+    assert!(huber_location_ci(&DAT, HUBER_K_95, 1.).is_err());
+    if nocover() {
+        assert!(huber_location(&DAT, HUBER_K_95).is_ok());
+        assert!(huber_location_ci(&DAT, HUBER_K_95, ALPHA).is_ok());
+    }
+}
+
+#[test]
+fn test_yuen() {
+    // Returns an error in any of the following conditions:
+    // - `x` or `y` contains a `NaN` value.
+    // - `trim` not in interval `[0, 0.5)`.
+    // - Fewer than `2` items remain after trimming `x` or `y`.
+    // - The winsorized variances of `x` and `y` are both zero.
+    // - `alpha` not in interval `(0, 1)` (for `yuen_alt_hyp_ci`, `yuen_ci`, and `yuen_test`).
+    let x = DAT;
+    let y = [2., 7., 1., 8., 2., 8.];
+    let nan = [1., f64::NAN, 3., 4.];
+    let short = [1., 2., 3.];
+    let constant = [1., 1., 1., 1., 1.];
+    let bad_pairs: [(&[f64], &[f64], f64); 7] = [
+        (&nan, &y, 0.2),
+        (&x, &nan, 0.2),
+        (&x, &y, -0.1),
+        (&x, &y, 0.5),
+        (&short, &y, 0.4),
+        (&x, &short, 0.4),
+        (&constant, &constant, 0.2),
+    ];
+    for (x, y, trim) in bad_pairs {
+        assert!(yuen_t(x, y, trim).is_err());
+        assert!(yuen_df(x, y, trim).is_err());
+        for alt_hyp in [AltHyp::Lt, AltHyp::Gt, AltHyp::Ne] {
+            assert!(yuen_p(x, y, trim, alt_hyp).is_err());
+            assert!(yuen_alt_hyp_ci(x, y, trim, alt_hyp, ALPHA).is_err());
+            assert!(yuen_test(x, y, trim, alt_hyp, ALPHA).is_err());
+        }
+        assert!(yuen_ci(x, y, trim, ALPHA).is_err());
+    }
+    for alpha in [0., 1.] {
+        assert!(yuen_ci(&x, &y, 0.2, alpha).is_err());
+        assert!(yuen_alt_hyp_ci(&x, &y, 0.2, AltHyp::Gt, alpha).is_err());
+        assert!(yuen_test(&x, &y, 0.2, AltHyp::Gt, alpha).is_err());
+    }
+    if nocover() {
+        assert!(yuen_t(&x, &constant, 0.2).is_ok());
+        assert!(yuen_ci(&x, &y, 0.2, ALPHA).is_ok());
+        assert!(yuen_test(&x, &y, 0.2, AltHyp::Ne, ALPHA).is_ok());
+    }
+}
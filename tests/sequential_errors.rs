@@ -0,0 +1,114 @@
+#![cfg(feature = "sequential")]
+
+mod nocover;
+
+use basic_stats::{core::SampleMoments, sequential::*};
+use nocover::nocover;
+
+#[test]
+fn test_bernoulli_sprt_new() {
+    // Returns an error in any of these conditions:
+    // - `p0` or `p1` is not in interval `(0, 1)`.
+    // - `p0 == p1`.
+    // - `alpha` or `beta` is not in interval `(0, 1)`.
+    // - `alpha + beta >= 1`.
+    assert!(BernoulliSprt::new(0., 0.5, 0.05, 0.1).is_err());
+    assert!(BernoulliSprt::new(0.1, 1., 0.05, 0.1).is_err());
+    assert!(BernoulliSprt::new(0.1, 0.1, 0.05, 0.1).is_err());
+    assert!(BernoulliSprt::new(0.1, 0.5, 0., 0.1).is_err());
+    assert!(BernoulliSprt::new(0.1, 0.5, 0.05, 1.).is_err());
+    assert!(BernoulliSprt::new(0.1, 0.5, 0.5, 0.5).is_err());
+    if nocover() {
+        assert!(BernoulliSprt::new(0.1, 0.5, 0.49, 0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_bernoulli_sprt_oc() {
+    // Returns an error if `p` is not in interval `[0, 1]`.
+    let sprt = BernoulliSprt::new(0.1, 0.5, 0.05, 0.1).unwrap();
+    assert!(sprt.oc(-0.1).is_err());
+    assert!(sprt.oc(1.1).is_err());
+    assert!(sprt.oc(f64::NAN).is_err());
+    if nocover() {
+        assert!(sprt.oc(0.).unwrap().is_finite());
+        assert!(sprt.oc(1.).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_bernoulli_sprt_asn() {
+    // Returns an error if `p` is not in interval `[0, 1]`.
+    let sprt = BernoulliSprt::new(0.1, 0.5, 0.05, 0.1).unwrap();
+    assert!(sprt.asn(-0.1).is_err());
+    assert!(sprt.asn(1.1).is_err());
+    if nocover() {
+        assert!(sprt.asn(0.).unwrap().is_finite());
+        assert!(sprt.asn(1.).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_mixture_sprt_new() {
+    // Returns an error in any of these conditions:
+    // - `delta0` is not finite.
+    // - `mixing_sd` is not finite and positive.
+    // - `alpha` is not in interval `(0, 1)`.
+    assert!(MixtureSprt::new(f64::NAN, 1., 0.05).is_err());
+    assert!(MixtureSprt::new(0., 0., 0.05).is_err());
+    assert!(MixtureSprt::new(0., f64::INFINITY, 0.05).is_err());
+    assert!(MixtureSprt::new(0., 1., 0.).is_err());
+    assert!(MixtureSprt::new(0., 1., 1.).is_err());
+    if nocover() {
+        assert!(MixtureSprt::new(0., 1., 0.5).is_ok());
+    }
+}
+
+#[test]
+fn test_mixture_sprt_update() {
+    // Returns an error in any of these conditions:
+    // - `moments_x.n() <= 1`.
+    // - `moments_y.n() <= 1`.
+    // - `moments_x.n()` or `moments_y.n()` is less than in the previous successful update, i.e., the moments are
+    //   not cumulative.
+    // - `moments_x.stdev() == 0` AND `moments_y.stdev() == 0`.
+    let m1_1 = SampleMoments::new(1, 0., 1.);
+    let m2_0 = SampleMoments::new(2, 0., 0.);
+    let m2_1 = SampleMoments::new(2, 0., 1.);
+    let m3_1 = SampleMoments::new(3, 0., 1.);
+
+    let mut msprt = MixtureSprt::new(0., 1., 0.05).unwrap();
+    assert!(msprt.update(&m1_1, &m2_1).is_err());
+    assert!(msprt.update(&m2_1, &m1_1).is_err());
+    assert!(msprt.update(&m2_0, &m2_0).is_err());
+    if nocover() {
+        assert!(msprt.update(&m2_0, &m2_1).is_ok());
+        assert!(msprt.update(&m2_1, &m2_0).is_ok());
+    }
+
+    let mut msprt = MixtureSprt::new(0., 1., 0.05).unwrap();
+    msprt.update(&m3_1, &m3_1).unwrap();
+    assert!(msprt.update(&m2_1, &m3_1).is_err());
+    assert!(msprt.update(&m3_1, &m2_1).is_err());
+    if nocover() {
+        assert!(msprt.update(&m3_1, &m3_1).is_ok());
+    }
+}
+
+#[test]
+fn test_mixture_sprt_ci() {
+    // Returns an error if the intersection of the confidence intervals of the updates is empty.
+    let mut msprt = MixtureSprt::new(0., 1., 0.05).unwrap();
+    if nocover() {
+        assert!(msprt.ci().is_ok());
+    }
+    let m20 = SampleMoments::new(20, 200., 2000.2);
+    msprt.update(&m20, &m20).unwrap();
+    if nocover() {
+        assert!(msprt.ci().is_ok());
+    }
+    let m40_x = SampleMoments::new(40, 440., 4840.4);
+    let m40_y = SampleMoments::new(40, 400., 4000.4);
+    msprt.update(&m40_x, &m40_y).unwrap();
+    assert!(msprt.ci().is_err());
+}